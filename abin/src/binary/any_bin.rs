@@ -3,7 +3,12 @@ use std::fmt::{Debug, LowerHex, UpperHex};
 use std::hash::Hash;
 use std::ops::{Deref, RangeBounds};
 
-use crate::{Bin, IntoSync, IntoUnSync, IntoUnSyncView, SBin, UnSyncRef};
+use crate::binary::{base64_encode, base64_encoded_len, find_subslice, hex_encode, rfind_subslice};
+use crate::implementation::build_exact;
+use crate::{
+    Base64Variant, Bin, BinLines, BinSplit, BinSplitN, DefaultSlicePolicy, IntoSync, IntoUnSync,
+    IntoUnSyncView, NewBin, SBin, SliceError, SlicePolicy, StorageKind, Str, UnSyncRef,
+};
 
 /// Common trait implemented by `Bin` and `SBin`.
 pub trait AnyBin:
@@ -74,6 +79,46 @@ pub trait AnyBin:
     where
         TRange: RangeBounds<usize>;
 
+    /// Returns a slice if the given range is within bounds; like `slice` but returns a
+    /// `SliceError` describing why the range is invalid.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, SliceError};
+    ///
+    /// let bin = NewBin::from_static("Hello!".as_bytes());
+    /// assert_eq!("Hel".as_bytes(), bin.try_slice(0..=2).unwrap().as_slice());
+    /// assert_eq!(
+    ///     Err(SliceError::OutOfBounds { len: 6, start: 2, end: 7 }),
+    ///     bin.try_slice(2..7)
+    /// );
+    /// assert_eq!(Err(SliceError::InvertedRange), bin.try_slice(4..3));
+    /// ```
+    fn try_slice<TRange>(&self, range: TRange) -> Result<Self, SliceError>
+    where
+        TRange: RangeBounds<usize>;
+
+    /// Returns a slice if the given range is within bounds (see `slice`); the given policy
+    /// decides whether the slice shares the buffer with `self` (like `slice`) or whether the
+    /// bytes are copied (so the slice does not keep a large buffer alive).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, DefaultSlicePolicy};
+    ///
+    /// let response = NewBin::copy_from_slice(&[7u8; 100_000]);
+    /// // a small view of a large buffer: copied.
+    /// let small = response.slice_with_policy::<DefaultSlicePolicy, _>(10..100).unwrap();
+    /// assert_eq!(Some(1), response.strong_count());
+    /// assert!(small.retained_bytes() < 1000);
+    ///
+    /// // a large view: shared.
+    /// let large = response.slice_with_policy::<DefaultSlicePolicy, _>(10..90_000).unwrap();
+    /// assert_eq!(Some(2), response.strong_count());
+    /// ```
+    fn slice_with_policy<TPolicy, TRange>(&self, range: TRange) -> Option<Self>
+    where
+        TPolicy: SlicePolicy,
+        TRange: RangeBounds<usize>;
+
     /// Tries to re-integrate the given slice into `self`. To some extent (not 100%), this is the
     /// reverse of `as_slice`.
     ///
@@ -109,4 +154,327 @@ pub trait AnyBin:
     /// assert_eq!(None, bin_a.try_to_re_integrate(bin_c_completely_unrelated_slice));
     /// ```
     fn try_to_re_integrate(&self, slice: &[u8]) -> Option<Self>;
+
+    /// Returns a mutable view into this binary if this binary exclusively owns its bytes: This is
+    /// the case for reference-counted binaries without other references to the buffer, for
+    /// binaries backed by a vector and for small binaries stored on the stack. Returns `None`
+    /// otherwise (for example if the buffer is shared or if this is a static binary). Does
+    /// never allocate or copy memory.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let mut bin = NewBin::copy_from_slice("Some binary that's too long for the stack.".as_bytes());
+    /// bin.get_mut().unwrap()[0] = b's';
+    /// assert_eq!("some binary that's too long for the stack.".as_bytes(), bin.as_slice());
+    ///
+    /// // no longer unique
+    /// let clone = bin.clone();
+    /// assert!(bin.get_mut().is_none());
+    /// drop(clone);
+    /// assert!(bin.get_mut().is_some());
+    ///
+    /// // static binaries cannot be mutated
+    /// let mut static_bin = NewBin::from_static("static".as_bytes());
+    /// assert!(static_bin.get_mut().is_none());
+    /// ```
+    fn get_mut(&mut self) -> Option<&mut [u8]>;
+
+    /// Returns a mutable view into this binary. If the binary does not exclusively own its bytes
+    /// (see `get_mut`), the content is copied into a new binary first (so other references to
+    /// the previous buffer are not affected). Like `BinFactory::copy_from_slice`, small
+    /// content is stored on the stack (no allocation).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let original = NewBin::copy_from_slice("Some binary that's too long for the stack.".as_bytes());
+    /// let mut bin = original.clone();
+    /// // this copies, since the buffer is shared.
+    /// bin.make_mut()[0] = b's';
+    /// assert_eq!("some binary that's too long for the stack.".as_bytes(), bin.as_slice());
+    /// assert_eq!("Some binary that's too long for the stack.".as_bytes(), original.as_slice());
+    /// ```
+    fn make_mut(&mut self) -> &mut [u8];
+
+    /// Splits the binary into two at the given index. Afterwards `self` contains the bytes
+    /// `[at, len)`, the returned binary contains the bytes `[0, at)`.
+    ///
+    /// Returns `None` (and leaves `self` unchanged) if `at > len`. Tries to avoid allocations /
+    /// memory copy whenever possible (best effort); for reference-counted binaries this is
+    /// cheap (just increments the reference count).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let mut bin = NewBin::from_static("HeaderPayload".as_bytes());
+    /// let header = bin.split_to(6).unwrap();
+    /// assert_eq!("Header".as_bytes(), header.as_slice());
+    /// assert_eq!("Payload".as_bytes(), bin.as_slice());
+    ///
+    /// // out of bounds
+    /// assert_eq!(None, bin.split_to(8));
+    /// assert_eq!("Payload".as_bytes(), bin.as_slice());
+    /// ```
+    fn split_to(&mut self, at: usize) -> Option<Self>;
+
+    /// Splits the binary into two at the given index. Afterwards `self` contains the bytes
+    /// `[0, at)`, the returned binary contains the bytes `[at, len)`.
+    ///
+    /// Returns `None` (and leaves `self` unchanged) if `at > len`. See also `split_to`.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let mut bin = NewBin::from_static("HeaderPayload".as_bytes());
+    /// let payload = bin.split_off(6).unwrap();
+    /// assert_eq!("Header".as_bytes(), bin.as_slice());
+    /// assert_eq!("Payload".as_bytes(), payload.as_slice());
+    /// ```
+    fn split_off(&mut self, at: usize) -> Option<Self>;
+
+    /// Removes the first `cnt` bytes.
+    ///
+    /// Returns `false` (and leaves `self` unchanged) if `cnt > len`. See also `split_to`.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let mut bin = NewBin::from_static("HeaderPayload".as_bytes());
+    /// assert!(bin.advance(6));
+    /// assert_eq!("Payload".as_bytes(), bin.as_slice());
+    /// assert!(!bin.advance(8));
+    /// ```
+    fn advance(&mut self, cnt: usize) -> bool;
+
+    /// Returns the index of the first occurrence of the given byte sequence; `None` if not
+    /// found. An empty needle is found at index 0.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let bin = NewBin::from_static("key=value=1".as_bytes());
+    /// assert_eq!(Some(3), bin.find(b"="));
+    /// assert_eq!(Some(4), bin.find(b"value"));
+    /// assert_eq!(None, bin.find(b"values"));
+    /// ```
+    fn find(&self, needle: &[u8]) -> Option<usize> {
+        find_subslice(self.as_slice(), needle)
+    }
+
+    /// Returns the index of the last occurrence of the given byte sequence; `None` if not
+    /// found. An empty needle is found at index `len`.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let bin = NewBin::from_static("key=value=1".as_bytes());
+    /// assert_eq!(Some(9), bin.rfind(b"="));
+    /// assert_eq!(None, bin.rfind(b"x"));
+    /// ```
+    fn rfind(&self, needle: &[u8]) -> Option<usize> {
+        rfind_subslice(self.as_slice(), needle)
+    }
+
+    /// `true` if the binary contains the given byte sequence.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let bin = NewBin::from_static("key=value".as_bytes());
+    /// assert!(bin.contains_subslice(b"y=v"));
+    /// assert!(!bin.contains_subslice(b"y=x"));
+    /// ```
+    fn contains_subslice(&self, needle: &[u8]) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Returns an iterator over the parts of this binary, separated by the given delimiter.
+    /// The parts are slices of this binary (see `slice`); so no bytes are copied for
+    /// reference-counted or static binaries. An empty delimiter does not split the binary.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, Bin};
+    ///
+    /// let bin = NewBin::from_static("a,b,,c".as_bytes());
+    /// let parts : Vec<Bin> = bin.split_bin(b",").collect();
+    /// assert_eq!(4, parts.len());
+    /// assert_eq!("a".as_bytes(), parts[0].as_slice());
+    /// assert!(parts[2].is_empty());
+    /// assert_eq!("c".as_bytes(), parts[3].as_slice());
+    /// ```
+    fn split_bin<TDelimiter>(&self, delimiter: TDelimiter) -> BinSplit<Self, TDelimiter>
+    where
+        TDelimiter: AsRef<[u8]>,
+    {
+        BinSplit::new(self.clone(), delimiter)
+    }
+
+    /// Like `split_bin` but returns at most `n` parts; the last part contains the remaining
+    /// binary.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, Bin};
+    ///
+    /// let bin = NewBin::from_static("GET /index.html HTTP/1.1".as_bytes());
+    /// let parts : Vec<Bin> = bin.splitn_bin(2, b" ").collect();
+    /// assert_eq!("GET".as_bytes(), parts[0].as_slice());
+    /// assert_eq!("/index.html HTTP/1.1".as_bytes(), parts[1].as_slice());
+    /// ```
+    fn splitn_bin<TDelimiter>(&self, n: usize, delimiter: TDelimiter) -> BinSplitN<Self, TDelimiter>
+    where
+        TDelimiter: AsRef<[u8]>,
+    {
+        BinSplitN::new(self.clone(), n, delimiter)
+    }
+
+    /// Splits the binary at the first occurrence of the given delimiter; returns the part
+    /// before and the part after the delimiter. Returns `None` if the delimiter is not found.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let bin = NewBin::from_static("Content-Type: text/plain".as_bytes());
+    /// let (name, value) = bin.split_once_bin(b": ").unwrap();
+    /// assert_eq!("Content-Type".as_bytes(), name.as_slice());
+    /// assert_eq!("text/plain".as_bytes(), value.as_slice());
+    /// assert_eq!(None, bin.split_once_bin(b"="));
+    /// ```
+    fn split_once_bin(&self, delimiter: &[u8]) -> Option<(Self, Self)> {
+        let index = self.find(delimiter)?;
+        Some((self.slice(..index)?, self.slice(index + delimiter.len()..)?))
+    }
+
+    /// Returns an iterator over the lines of this binary (separated by `\n` or `\r\n`; the
+    /// final line ending is optional). Like `split_bin`, the lines are slices of this binary.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, Bin};
+    ///
+    /// let bin = NewBin::from_static("first\r\nsecond\n\nlast\n".as_bytes());
+    /// let lines : Vec<Bin> = bin.lines_bin().collect();
+    /// assert_eq!(4, lines.len());
+    /// assert_eq!("first".as_bytes(), lines[0].as_slice());
+    /// assert!(lines[2].is_empty());
+    /// assert_eq!("last".as_bytes(), lines[3].as_slice());
+    /// ```
+    fn lines_bin(&self) -> BinLines<Self> {
+        BinLines::new(self.clone())
+    }
+
+    /// Returns the lowercase hex representation of this binary (see also `LowerHex` and
+    /// `NewBin::from_hex`). The output is sized exactly (small strings are stored on the stack).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    /// let bin = NewBin::from_static(&[0xCA, 0xFE, 0x01]);
+    /// assert_eq!("cafe01", bin.to_hex_str().as_str());
+    /// ```
+    fn to_hex_str(&self) -> Str {
+        let input = self.as_slice();
+        let bin = build_exact::<NewBin, _>(input.len() * 2, |out| hex_encode(input, out));
+        // hex digits are ASCII (valid UTF-8).
+        unsafe { Str::from_utf8_unchecked(bin) }
+    }
+
+    /// Returns the Base64 representation of this binary (see `Base64Variant` and
+    /// `NewBin::from_base64`). The output is sized exactly (small strings are stored on the
+    /// stack).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, Base64Variant};
+    /// let bin = NewBin::from_static(&[0xFB, 0xFF]);
+    /// assert_eq!("+/8=", bin.to_base64_str(Base64Variant::Standard).as_str());
+    /// assert_eq!("-_8", bin.to_base64_str(Base64Variant::UrlSafeNoPad).as_str());
+    /// ```
+    fn to_base64_str(&self, variant: Base64Variant) -> Str {
+        let input = self.as_slice();
+        let len = base64_encoded_len(input.len(), variant);
+        let bin = build_exact::<NewBin, _>(len, |out| base64_encode(input, out, variant));
+        // the Base64 alphabet is ASCII (valid UTF-8).
+        unsafe { Str::from_utf8_unchecked(bin) }
+    }
+
+    /// Returns how the bytes of this binary are stored (stack, static, reference-counted, ...).
+    /// This is mostly useful for diagnostics.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, NewSBin, BinFactory, AnyBin, StorageKind};
+    ///
+    /// assert_eq!(StorageKind::Static, NewBin::from_static("Hello".as_bytes()).storage_kind());
+    /// assert_eq!(StorageKind::Stack, NewBin::copy_from_slice(&[1, 2, 3]).storage_kind());
+    /// assert_eq!(StorageKind::Arc, NewSBin::copy_from_slice(&[0u8; 100]).storage_kind());
+    /// ```
+    fn storage_kind(&self) -> StorageKind;
+
+    /// Returns the number of references to the shared buffer (including this one) for
+    /// reference-counted binaries; returns `None` if the binary is not reference-counted.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let bin = NewBin::copy_from_slice(&[0u8; 100]);
+    /// assert_eq!(Some(1), bin.strong_count());
+    /// let slice = bin.slice(10..20).unwrap();
+    /// assert_eq!(Some(2), bin.strong_count());
+    /// assert_eq!(None, NewBin::from_static(&[1, 2]).strong_count());
+    /// ```
+    fn strong_count(&self) -> Option<usize>;
+
+    /// Returns the number of (heap) bytes kept alive by this binary. This can be much more
+    /// than the length of the binary: a small slice of a large reference-counted binary keeps
+    /// the entire buffer alive. Returns 0 if the binary does not hold a buffer (for example
+    /// if it's stored on the stack or if it's static).
+    ///
+    /// Note: The buffer is shared among all clones/slices, so it's not correct to sum up the
+    /// retained bytes of multiple binaries.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let bin = NewBin::copy_from_slice(&[0u8; 1000]);
+    /// let slice = bin.slice(10..40).unwrap();
+    /// drop(bin);
+    /// assert_eq!(30, slice.len());
+    /// assert!(slice.retained_bytes() >= 1000);
+    /// ```
+    fn retained_bytes(&self) -> usize;
+
+    /// Copies the content to a new exact-fit buffer if this binary keeps a buffer alive that
+    /// is much larger than its length (see `retained_bytes` and `DefaultSlicePolicy`); so the
+    /// large buffer can be freed (once there are no other references). Returns `true` if the
+    /// content has been copied; `false` if the binary is unchanged.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin};
+    ///
+    /// let response = NewBin::copy_from_slice(&[7u8; 100_000]);
+    /// let mut small = response.slice(10..100).unwrap();
+    /// drop(response);
+    /// assert!(small.retained_bytes() >= 100_000);
+    /// assert!(small.compact());
+    /// assert!(small.retained_bytes() < 1000);
+    /// assert_eq!(&[7u8; 90][..], small.as_slice());
+    /// // nothing to do
+    /// assert!(!small.compact());
+    /// ```
+    #[inline]
+    fn compact(&mut self) -> bool {
+        self.compact_with_policy::<DefaultSlicePolicy>()
+    }
+
+    /// Like `compact` but the given policy decides whether the content is copied (the policy is
+    /// consulted as if the entire binary was sliced; see `SlicePolicy`).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, AlwaysCopy, AlwaysShare};
+    ///
+    /// let response = NewBin::copy_from_slice(&[7u8; 1000]);
+    /// let mut slice = response.slice(10..900).unwrap();
+    /// assert!(!slice.compact_with_policy::<AlwaysShare>());
+    /// assert_eq!(Some(2), response.strong_count());
+    /// assert!(slice.compact_with_policy::<AlwaysCopy>());
+    /// assert_eq!(Some(1), response.strong_count());
+    /// ```
+    fn compact_with_policy<TPolicy: SlicePolicy>(&mut self) -> bool;
 }
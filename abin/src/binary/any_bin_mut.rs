@@ -0,0 +1,415 @@
+use core::fmt;
+use std::borrow::{Borrow, BorrowMut};
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+#[cfg(feature = "bytes")]
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+use crate::AnyBin;
+
+/// A mutable, growable binary; see `BinMut` and `SBinMut`.
+///
+/// It's backed by a `Vec<u8>` that always keeps enough excess capacity to store the
+/// reference-count metadata (unless it has no capacity at all). So `freeze` (converting it into an immutable binary) is cheap: it
+/// does neither copy memory nor re-allocate, the buffer is just handed over to the
+/// reference-counted binary.
+///
+/// ```rust
+/// use abin::{BinMut, Bin, AnyBin};
+///
+/// let mut bin_mut = BinMut::new();
+/// bin_mut.extend_from_slice("Hello, ".as_bytes());
+/// bin_mut.extend_from_slice("World!".as_bytes());
+/// bin_mut[0] = b'J';
+/// let bin: Bin = bin_mut.freeze();
+/// assert_eq!("Jello, World!".as_bytes(), bin.as_slice());
+/// ```
+pub struct AnyBinMut<TBin> {
+    /// Invariant: Either no capacity at all or an excess capacity of at least
+    /// `IntoBinMut::mut_overhead_bytes`.
+    vec: Vec<u8>,
+    _phantom: PhantomData<TBin>,
+}
+
+/// Implemented for `Bin` and `SBin`; the binary types `AnyBinMut` can be frozen into.
+pub trait IntoBinMut: AnyBin {
+    /// Converts this binary into a mutable binary - but only if this is possible without
+    /// copying memory. This is the case:
+    ///
+    ///  * For reference-counted binaries if there are no other references to the buffer.
+    ///  * For binaries backed by a vector.
+    ///  * For empty binaries.
+    ///
+    /// Returns `Err` with the unchanged binary otherwise (for example if the binary is
+    /// shared, if it's a static binary or if it's stored on the stack).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, AnyBin, IntoBinMut};
+    ///
+    /// let bin = NewBin::copy_from_slice("Some binary that's too long for the stack.".as_bytes());
+    /// let mut bin_mut = bin.try_into_mut().unwrap();
+    /// bin_mut[0] = b's';
+    /// assert_eq!("some binary that's too long for the stack.".as_bytes(),
+    ///     bin_mut.freeze().as_slice());
+    ///
+    /// // shared binaries cannot be converted without copying
+    /// let bin = NewBin::copy_from_slice("Some binary that's too long for the stack.".as_bytes());
+    /// let _clone = bin.clone();
+    /// assert!(bin.try_into_mut().is_err());
+    /// ```
+    fn try_into_mut(self) -> Result<AnyBinMut<Self>, Self>;
+
+    /// The number of bytes (excess capacity) the vector of `AnyBinMut` always keeps in order
+    /// to be frozen without re-allocation.
+    fn mut_overhead_bytes() -> usize;
+
+    /// Converts the given vector into a binary. Does neither copy nor re-allocate if there's
+    /// an excess capacity of at least `mut_overhead_bytes`. This is used by `AnyBinMut::freeze`.
+    fn freeze_vec(vec: Vec<u8>) -> Self;
+}
+
+impl<TBin> AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    /// A new empty mutable binary. Does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            vec: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// A new empty mutable binary that can hold at least `capacity` bytes without
+    /// re-allocation.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_vec_with_capacity(Vec::with_capacity(
+            capacity.checked_add(TBin::mut_overhead_bytes()).unwrap(),
+        ))
+    }
+
+    /// A new mutable binary with the content copied from the given slice.
+    #[inline]
+    pub fn copy_from_slice(slice: &[u8]) -> Self {
+        let mut this = Self::with_capacity(slice.len());
+        this.vec.extend_from_slice(slice);
+        this
+    }
+
+    /// Creates a mutable binary from given vector. Note: This re-allocates if the given vector
+    /// does not have enough excess capacity (see `IntoBinMut::mut_overhead_bytes`); a vector
+    /// without capacity is taken as-is (does not allocate).
+    #[inline]
+    pub fn from_given_vec(mut vec: Vec<u8>) -> Self {
+        if vec.capacity() > 0 {
+            vec.reserve(TBin::mut_overhead_bytes());
+        }
+        Self::from_vec_with_capacity(vec)
+    }
+
+    /// The vector must have enough excess capacity.
+    #[inline]
+    fn from_vec_with_capacity(vec: Vec<u8>) -> Self {
+        Self {
+            vec,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Converts this into an immutable binary. Does neither copy memory nor re-allocate
+    /// (except for the empty binary; in this case the buffer, if there's any, is freed).
+    ///
+    /// ```rust
+    /// use abin::{SBinMut, SBin, AnyBin};
+    ///
+    /// let mut bin_mut = SBinMut::with_capacity(64);
+    /// bin_mut.extend_from_slice(&[1, 2, 3]);
+    /// let bin: SBin = bin_mut.freeze();
+    /// assert_eq!(&[1u8, 2, 3], bin.as_slice());
+    /// ```
+    #[inline]
+    pub fn freeze(self) -> TBin {
+        TBin::freeze_vec(self.vec)
+    }
+
+    /// Converts this into a vector; does not allocate.
+    #[inline]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.vec
+    }
+
+    /// The number of bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// `true` if there are no bytes in this binary.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// The number of bytes this binary can hold without re-allocation.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec
+            .capacity()
+            .saturating_sub(TBin::mut_overhead_bytes())
+    }
+
+    /// Reserves capacity for at least `additional` more bytes.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec
+            .reserve(additional.checked_add(TBin::mut_overhead_bytes()).unwrap());
+    }
+
+    /// Returns a view into this binary.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.vec.as_slice()
+    }
+
+    /// Returns a mutable view into this binary.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.vec.as_mut_slice()
+    }
+
+    /// Appends the given slice.
+    #[inline]
+    pub fn extend_from_slice(&mut self, slice: &[u8]) {
+        self.reserve(slice.len());
+        self.vec.extend_from_slice(slice);
+    }
+
+    /// Appends a single byte.
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        self.reserve(1);
+        self.vec.push(byte);
+    }
+
+    /// Resizes this binary; fills new bytes with `value`. See `Vec::resize`.
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: u8) {
+        let len = self.vec.len();
+        if new_len > len {
+            self.reserve(new_len - len);
+        }
+        self.vec.resize(new_len, value);
+    }
+
+    /// Shortens this binary. Has no effect if `len` is greater than the current length.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+    }
+
+    /// Removes all bytes (keeps the capacity).
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// The excess capacity (without the capacity required for the reference-count metadata);
+    /// reserves at least `additional` bytes first if required. See `set_len`.
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub(crate) fn spare_capacity_mut(&mut self, additional: usize) -> &mut [MaybeUninit<u8>] {
+        if self.capacity() - self.len() < additional {
+            self.reserve(additional);
+        }
+        let available = self.capacity() - self.len();
+        &mut self.vec.spare_capacity_mut()[..available]
+    }
+
+    /// Sets the length; see `Vec::set_len`. Panics if `new_len` exceeds the capacity (so the
+    /// capacity required for the reference-count metadata is never used).
+    ///
+    /// Safety: The bytes up to `new_len` must be initialized (see `spare_capacity_mut`).
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub(crate) unsafe fn set_len(&mut self, new_len: usize) {
+        assert!(
+            new_len <= self.capacity(),
+            "Length exceeds the capacity (capacity {}; length {}).",
+            self.capacity(),
+            new_len
+        );
+        self.vec.set_len(new_len);
+    }
+}
+
+impl<TBin> Default for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TBin> Clone for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    fn clone(&self) -> Self {
+        Self::copy_from_slice(self.as_slice())
+    }
+}
+
+impl<TBin> Debug for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<TBin> Eq for AnyBinMut<TBin> where TBin: IntoBinMut {}
+
+impl<TBin> PartialEq for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<TBin> Hash for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<TBin> Deref for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<TBin> DerefMut for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<TBin> AsRef<[u8]> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<TBin> AsMut<[u8]> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl<TBin> Borrow<[u8]> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<TBin> BorrowMut<[u8]> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl<TBin> Extend<u8> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+        for byte in iter {
+            self.push(byte);
+        }
+    }
+}
+
+impl<'a, TBin> Extend<&'a u8> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+/// See `AnyBinMut::from_given_vec`.
+impl<TBin> From<Vec<u8>> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn from(vec: Vec<u8>) -> Self {
+        Self::from_given_vec(vec)
+    }
+}
+
+/// See `AnyBinMut::copy_from_slice`.
+impl<TBin> From<&[u8]> for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn from(slice: &[u8]) -> Self {
+        Self::copy_from_slice(slice)
+    }
+}
+
+/// See `AnyBinMut::into_vec`.
+impl<TBin> From<AnyBinMut<TBin>> for Vec<u8>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn from(bin_mut: AnyBinMut<TBin>) -> Self {
+        bin_mut.into_vec()
+    }
+}
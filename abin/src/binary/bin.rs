@@ -4,10 +4,14 @@ use std::fmt;
 use std::fmt::{Debug, Formatter, LowerHex, UpperHex};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use std::ops::{Bound, Deref, RangeBounds};
+use std::ops::{Deref, RangeBounds};
 
+use crate::binary::normalize_range;
 use crate::spi::{BinData, FnTable, UnsafeBin};
-use crate::{AnyBin, IntoIter, IntoSync, IntoUnSync, IntoUnSyncView, SBin, UnSyncRef};
+use crate::{
+    AnyBin, AnyRc, BinFactory, BinMut, IntoBinMut, IntoIter, IntoSync, IntoUnSync, IntoUnSyncView,
+    NewBin, RcBin, SBin, SliceError, SlicePolicy, StorageKind, UnSyncRef,
+};
 
 /// A binary that does not implement `Send + Sync`. See `AnyBin` for documentation; see `SBin`
 /// if you need `Send + Sync`. See `BinFactory` on how to create binaries.
@@ -59,17 +63,34 @@ impl AnyBin for Bin {
     where
         TRange: RangeBounds<usize>,
     {
-        let start = match range.start_bound() {
-            Bound::Included(start) => *start,
-            Bound::Excluded(start) => *start + 1,
-            Bound::Unbounded => 0,
-        };
-        let end_excluded = match range.end_bound() {
-            Bound::Included(end) => *end - 1,
-            Bound::Excluded(end) => *end,
-            Bound::Unbounded => self.len(),
-        };
-        (self.fn_table.slice)(self, start, end_excluded)
+        self.try_slice(range).ok()
+    }
+
+    fn try_slice<TRange>(&self, range: TRange) -> Result<Self, SliceError>
+    where
+        TRange: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let range = normalize_range(&range, len)?;
+        (self.fn_table.slice)(self, range.start, range.end).ok_or(SliceError::OutOfBounds {
+            len,
+            start: range.start,
+            end: range.end,
+        })
+    }
+
+    fn slice_with_policy<TPolicy, TRange>(&self, range: TRange) -> Option<Self>
+    where
+        TPolicy: SlicePolicy,
+        TRange: RangeBounds<usize>,
+    {
+        let range = normalize_range(&range, self.len()).ok()?;
+        if TPolicy::copy(self.storage_kind(), range.len(), self.retained_bytes()) {
+            // copy the range directly (slicing first might already copy; e.g. `Vec` backend).
+            Some(NewBin::copy_from_slice(&self.as_slice()[range]))
+        } else {
+            self.slice(range)
+        }
     }
 
     #[inline]
@@ -80,6 +101,93 @@ impl AnyBin for Bin {
             None
         }
     }
+
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut [u8]> {
+        if let Some(get_mut_fn) = self.fn_table.get_mut {
+            (get_mut_fn)(self)
+        } else {
+            None
+        }
+    }
+
+    fn make_mut(&mut self) -> &mut [u8] {
+        if self.get_mut().is_none() {
+            *self = NewBin::copy_from_slice(self.as_slice());
+        }
+        self.get_mut()
+            .expect("There's an implementation error: A newly created binary must be mutable.")
+    }
+
+    #[inline]
+    fn split_to(&mut self, at: usize) -> Option<Self> {
+        if let Some(split_to_fn) = self.fn_table.split_to {
+            (split_to_fn)(self, at)
+        } else {
+            let head = self.slice(..at)?;
+            let tail = self.slice(at..)?;
+            *self = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn split_off(&mut self, at: usize) -> Option<Self> {
+        if let Some(split_off_fn) = self.fn_table.split_off {
+            (split_off_fn)(self, at)
+        } else {
+            let tail = self.slice(at..)?;
+            let head = self.slice(..at)?;
+            *self = head;
+            Some(tail)
+        }
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) -> bool {
+        if let Some(advance_fn) = self.fn_table.advance {
+            (advance_fn)(self, cnt)
+        } else if let Some(tail) = self.slice(cnt..) {
+            *self = tail;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn storage_kind(&self) -> StorageKind {
+        if let Some(storage_kind_fn) = self.fn_table.storage_kind {
+            (storage_kind_fn)(self)
+        } else {
+            StorageKind::Custom
+        }
+    }
+
+    #[inline]
+    fn strong_count(&self) -> Option<usize> {
+        self.fn_table
+            .strong_count
+            .map(|strong_count_fn| (strong_count_fn)(self))
+    }
+
+    #[inline]
+    fn retained_bytes(&self) -> usize {
+        if let Some(retained_bytes_fn) = self.fn_table.retained_bytes {
+            (retained_bytes_fn)(self)
+        } else {
+            self.len()
+        }
+    }
+
+    fn compact_with_policy<TPolicy: SlicePolicy>(&mut self) -> bool {
+        if TPolicy::copy(self.storage_kind(), self.len(), self.retained_bytes()) {
+            *self = NewBin::copy_from_slice(self.as_slice());
+            true
+        } else {
+            false
+        }
+    }
 }
 
 /// This does nothing, since `Bin` is already un-synchronized (view). Just returns itself.
@@ -127,7 +235,7 @@ impl UnSyncRef for Bin {
 
     #[inline]
     fn un_sync_ref(&self) -> &Self::Target {
-        &self
+        self
     }
 }
 
@@ -172,7 +280,7 @@ impl Ord for Bin {
 impl PartialOrd for Bin {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+        Some(self.cmp(other))
     }
 }
 
@@ -244,14 +352,46 @@ impl IntoIterator for Bin {
     }
 }
 
-impl Into<Vec<u8>> for Bin {
+impl From<Bin> for Vec<u8> {
+    #[inline]
+    fn from(bin: Bin) -> Self {
+        bin.into_vec()
+    }
+}
+
+impl IntoBinMut for Bin {
+    #[inline]
+    fn try_into_mut(self) -> Result<BinMut, Self> {
+        self.try_into_unique_vec().map(BinMut::from_given_vec)
+    }
+
+    #[inline]
+    fn mut_overhead_bytes() -> usize {
+        RcBin::overhead_bytes()
+    }
+
     #[inline]
-    fn into(self) -> Vec<u8> {
-        self.into_vec()
+    fn freeze_vec(vec: Vec<u8>) -> Self {
+        if vec.is_empty() {
+            NewBin::empty()
+        } else {
+            RcBin::from_vec(vec)
+        }
     }
 }
 
 impl Bin {
+    /// Converts this binary into a vector if this is possible without copying memory. See
+    /// `FnTable::try_into_unique_vec`.
+    #[inline]
+    pub(crate) fn try_into_unique_vec(self) -> Result<Vec<u8>, Self> {
+        if let Some(try_into_unique_vec_fn) = self.fn_table.try_into_unique_vec {
+            (try_into_unique_vec_fn)(self)
+        } else {
+            Err(self)
+        }
+    }
+
     /// This is required since we can't use `unsafe` in const fn but we need const new
     /// for the empty bin.
     pub(crate) const fn _const_new(data: BinData, fn_table: &'static FnTable) -> Self {
@@ -0,0 +1,242 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// The Base64 variant (RFC 4648) used by `AnyBin::to_base64_str` and `NewBin::from_base64`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Base64Variant {
+    /// Standard alphabet (`+`, `/`) with padding (`=`).
+    Standard,
+    /// Standard alphabet (`+`, `/`) without padding.
+    StandardNoPad,
+    /// URL-safe alphabet (`-`, `_`) with padding (`=`).
+    UrlSafe,
+    /// URL-safe alphabet (`-`, `_`) without padding.
+    UrlSafeNoPad,
+}
+
+impl Base64Variant {
+    #[inline]
+    fn alphabet(self) -> &'static [u8; 64] {
+        match self {
+            Base64Variant::Standard | Base64Variant::StandardNoPad => STANDARD_ALPHABET,
+            Base64Variant::UrlSafe | Base64Variant::UrlSafeNoPad => URL_SAFE_ALPHABET,
+        }
+    }
+
+    #[inline]
+    fn padded(self) -> bool {
+        match self {
+            Base64Variant::Standard | Base64Variant::UrlSafe => true,
+            Base64Variant::StandardNoPad | Base64Variant::UrlSafeNoPad => false,
+        }
+    }
+
+    #[inline]
+    fn decode_symbol(self, symbol: u8) -> Option<u8> {
+        match symbol {
+            b'A'..=b'Z' => Some(symbol - b'A'),
+            b'a'..=b'z' => Some(symbol - b'a' + 26),
+            b'0'..=b'9' => Some(symbol - b'0' + 52),
+            _ => {
+                let alphabet = self.alphabet();
+                if symbol == alphabet[62] {
+                    Some(62)
+                } else if symbol == alphabet[63] {
+                    Some(63)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Error returned when decoding hex or Base64 (see `NewBin::from_hex` and
+/// `NewBin::from_base64`). Offsets are byte offsets into the input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DecodeError {
+    /// The byte at `offset` is not a valid symbol.
+    InvalidByte { offset: usize, byte: u8 },
+    /// The input has an invalid length (hex: odd length; Base64: the last group only has one
+    /// symbol).
+    InvalidLength { len: usize },
+    /// The padding starting at `offset` is missing, incomplete or not allowed (depends on the
+    /// Base64 variant).
+    InvalidPadding { offset: usize },
+    /// The last symbol (at `offset`) has non-zero trailing bits (Base64 only; the input is not
+    /// in canonical form).
+    InvalidTrailingBits { offset: usize },
+}
+
+impl Error for DecodeError {}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidByte { offset, byte } => {
+                write!(f, "Invalid byte 0x{:02x} at offset {}.", byte, offset)
+            }
+            DecodeError::InvalidLength { len } => write!(f, "Invalid input length {}.", len),
+            DecodeError::InvalidPadding { offset } => {
+                write!(f, "Invalid padding at offset {}.", offset)
+            }
+            DecodeError::InvalidTrailingBits { offset } => {
+                write!(
+                    f,
+                    "Non-zero trailing bits in the symbol at offset {}.",
+                    offset
+                )
+            }
+        }
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Writes the lowercase hex representation of `input` to `out` (must be `2 * input.len()` long).
+pub(crate) fn hex_encode(input: &[u8], out: &mut [u8]) {
+    for (byte, out) in input.iter().zip(out.chunks_exact_mut(2)) {
+        out[0] = HEX_DIGITS[usize::from(byte >> 4)];
+        out[1] = HEX_DIGITS[usize::from(byte & 0x0F)];
+    }
+}
+
+/// Validates the hex input (upper- and lowercase digits); returns the decoded length.
+// note: `usize::is_multiple_of` requires Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn hex_decoded_len(input: &[u8]) -> Result<usize, DecodeError> {
+    if let Some(offset) = input.iter().position(|byte| hex_value(*byte).is_none()) {
+        Err(DecodeError::InvalidByte {
+            offset,
+            byte: input[offset],
+        })
+    } else if input.len() % 2 != 0 {
+        Err(DecodeError::InvalidLength { len: input.len() })
+    } else {
+        Ok(input.len() / 2)
+    }
+}
+
+/// Decodes the hex input (must have been validated using `hex_decoded_len`).
+pub(crate) fn hex_decode(input: &[u8], out: &mut [u8]) {
+    for (pair, out) in input.chunks_exact(2).zip(out.iter_mut()) {
+        *out = (hex_value(pair[0]).unwrap_or(0) << 4) | hex_value(pair[1]).unwrap_or(0);
+    }
+}
+
+#[inline]
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// The length of the Base64 representation of `len` bytes.
+pub(crate) fn base64_encoded_len(len: usize, variant: Base64Variant) -> usize {
+    let complete = len / 3 * 4;
+    match (len % 3, variant.padded()) {
+        (0, _) => complete,
+        (_, true) => complete + 4,
+        (remainder, false) => complete + remainder + 1,
+    }
+}
+
+/// Writes the Base64 representation of `input` to `out` (see `base64_encoded_len`).
+pub(crate) fn base64_encode(input: &[u8], out: &mut [u8], variant: Base64Variant) {
+    let alphabet = variant.alphabet();
+    let mut out_chunks = out.chunks_mut(4);
+    for group in input.chunks(3) {
+        let out = out_chunks
+            .next()
+            .expect("There's an implementation error: The output is too short.");
+        let bits = (u32::from(group[0]) << 16)
+            | (u32::from(*group.get(1).unwrap_or(&0)) << 8)
+            | u32::from(*group.get(2).unwrap_or(&0));
+        for (index, out) in out.iter_mut().enumerate() {
+            *out = if index <= group.len() {
+                alphabet[((bits >> (18 - 6 * index)) & 0x3F) as usize]
+            } else {
+                b'='
+            };
+        }
+    }
+}
+
+/// Validates the Base64 input; returns the number of symbols (excluding padding) and the decoded
+/// length.
+pub(crate) fn base64_validate(
+    input: &[u8],
+    variant: Base64Variant,
+) -> Result<(usize, usize), DecodeError> {
+    let symbols = input
+        .iter()
+        .position(|byte| *byte == b'=')
+        .unwrap_or(input.len());
+    if let Some(offset) = input[..symbols]
+        .iter()
+        .position(|byte| variant.decode_symbol(*byte).is_none())
+    {
+        return Err(DecodeError::InvalidByte {
+            offset,
+            byte: input[offset],
+        });
+    }
+    let remainder = symbols % 4;
+    if remainder == 1 {
+        return Err(DecodeError::InvalidLength { len: input.len() });
+    }
+
+    let padding = &input[symbols..];
+    if let Some(index) = padding.iter().position(|byte| *byte != b'=') {
+        let offset = symbols + index;
+        return Err(DecodeError::InvalidByte {
+            offset,
+            byte: input[offset],
+        });
+    }
+    let expected_padding = if variant.padded() && remainder != 0 {
+        4 - remainder
+    } else {
+        0
+    };
+    if padding.len() != expected_padding {
+        return Err(DecodeError::InvalidPadding { offset: symbols });
+    }
+
+    if remainder != 0 {
+        // the unused bits of the last symbol must be zero (canonical form).
+        let last = variant.decode_symbol(input[symbols - 1]).unwrap_or(0);
+        let mask = if remainder == 2 { 0x0F } else { 0x03 };
+        if last & mask != 0 {
+            return Err(DecodeError::InvalidTrailingBits {
+                offset: symbols - 1,
+            });
+        }
+    }
+
+    let decoded_len = symbols / 4 * 3 + remainder.saturating_sub(1);
+    Ok((symbols, decoded_len))
+}
+
+/// Decodes the Base64 symbols (padding excluded; must have been validated using
+/// `base64_validate`).
+pub(crate) fn base64_decode(symbols: &[u8], out: &mut [u8], variant: Base64Variant) {
+    for (group, out) in symbols.chunks(4).zip(out.chunks_mut(3)) {
+        let mut bits = 0u32;
+        for (index, symbol) in group.iter().enumerate() {
+            let value = variant.decode_symbol(*symbol).unwrap_or(0);
+            bits |= u32::from(value) << (18 - 6 * index);
+        }
+        for (index, out) in out.iter_mut().enumerate() {
+            *out = (bits >> (16 - 8 * index)) as u8;
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{AnyBinMut, Bin};
+
+/// A mutable binary that can be frozen into a `Bin` (not `Sync + Send`); see `SBinMut` if you
+/// need `Sync + Send`. See `AnyBinMut` for documentation.
+///
+/// ```rust
+/// use abin::{BinMut, Bin, AnyBin};
+/// let mut bin_mut = BinMut::new();
+/// bin_mut.extend_from_slice("Hello".as_bytes());
+/// let bin: Bin = bin_mut.freeze();
+/// assert_eq!("Hello".as_bytes(), bin.as_slice());
+/// ```
+pub type BinMut = AnyBinMut<Bin>;
@@ -0,0 +1,184 @@
+use std::convert::TryFrom;
+use std::io;
+use std::io::{BufRead, Read, Seek, SeekFrom};
+
+use crate::AnyBin;
+
+/// A cursor over a binary (`Bin` or `SBin`); similar to `std::io::Cursor`. The reader owns
+/// the binary. Implements `Read`, `BufRead` and `Seek`; see also `read_bin` (zero-copy).
+///
+/// ```rust
+/// use abin::{NewBin, BinFactory, BinReader};
+///
+/// let mut reader = BinReader::new(NewBin::from_static(&[1, 2, 3, 4]));
+/// reader.set_position(1);
+/// assert_eq!(&[2u8, 3, 4], reader.remaining_slice());
+/// assert_eq!(3, reader.remaining());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BinReader<T> {
+    inner: T,
+    position: usize,
+}
+
+impl<T: AnyBin> BinReader<T> {
+    /// Creates a new reader; the position is initially `0`.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self { inner, position: 0 }
+    }
+
+    /// Consumes this reader, returning the underlying binary.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns a reference to the underlying binary.
+    #[inline]
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// The current position of this reader.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Sets the position of this reader. Note: It's valid to set the position beyond the end
+    /// of the binary (there's just nothing left to read).
+    #[inline]
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// The number of bytes left to read.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.inner.len().saturating_sub(self.position)
+    }
+
+    /// The bytes left to read (empty if the position is beyond the end).
+    #[inline]
+    pub fn remaining_slice(&self) -> &[u8] {
+        let slice = self.inner.as_slice();
+        slice.get(self.position..).unwrap_or(&[])
+    }
+
+    /// Reads up to `n` bytes (fewer if there are not enough bytes left) and returns them as
+    /// binary. Does not copy: The returned binary is a slice of the underlying binary (see
+    /// `AnyBin::slice`).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, BinReader, AnyBin};
+    ///
+    /// let mut reader = BinReader::new(NewBin::from_static("HeaderPayload".as_bytes()));
+    /// assert_eq!("Header".as_bytes(), reader.read_bin(6).as_slice());
+    /// assert_eq!("Payload".as_bytes(), reader.read_bin(100).as_slice());
+    /// assert!(reader.read_bin(1).is_empty());
+    /// ```
+    pub fn read_bin(&mut self, n: usize) -> T {
+        let len = self.inner.len();
+        let start = self.position.min(len);
+        let end = start.saturating_add(n).min(len);
+        let bin = self
+            .inner
+            .slice(start..end)
+            .expect("There's an implementation error: The range must always be within bounds.");
+        // note: the position might be beyond the end; in this case it's not changed.
+        self.position += end - start;
+        bin
+    }
+
+    /// Reads exactly `n` bytes and returns them as binary (see `read_bin`). Returns an error
+    /// of kind `UnexpectedEof` if there are not enough bytes left; the position is not changed
+    /// in that case.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, BinReader, AnyBin};
+    /// use std::io::ErrorKind;
+    ///
+    /// let mut reader = BinReader::new(NewBin::from_static(&[3, 1, 2, 3, 8, 1]));
+    /// let len = reader.read_bin_exact(1).unwrap().as_slice()[0] as usize;
+    /// assert_eq!(&[1u8, 2, 3], reader.read_bin_exact(len).unwrap().as_slice());
+    /// let len = reader.read_bin_exact(1).unwrap().as_slice()[0] as usize;
+    /// assert_eq!(ErrorKind::UnexpectedEof, reader.read_bin_exact(len).unwrap_err().kind());
+    /// ```
+    pub fn read_bin_exact(&mut self, n: usize) -> io::Result<T> {
+        if self.remaining() < n {
+            Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Not enough bytes left in binary.",
+            ))
+        } else {
+            Ok(self.read_bin(n))
+        }
+    }
+}
+
+impl<T: AnyBin> Read for BinReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining_slice();
+        let number_of_bytes = remaining.len().min(buf.len());
+        buf[..number_of_bytes].copy_from_slice(&remaining[..number_of_bytes]);
+        self.position += number_of_bytes;
+        Ok(number_of_bytes)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        let remaining = self.remaining_slice();
+        if remaining.len() < buf.len() {
+            Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Not enough bytes left in binary.",
+            ))
+        } else {
+            buf.copy_from_slice(&remaining[..buf.len()]);
+            self.position += buf.len();
+            Ok(())
+        }
+    }
+}
+
+impl<T: AnyBin> BufRead for BinReader<T> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.position = self.position.saturating_add(amt);
+    }
+}
+
+/// Same as `std::io::Cursor`: It's valid to seek beyond the end; it's an error to seek to a
+/// negative position.
+impl<T: AnyBin> Seek for BinReader<T> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(position) => (0, i128::from(position)),
+            SeekFrom::End(offset) => (self.inner.len(), i128::from(offset)),
+            SeekFrom::Current(offset) => (self.position, i128::from(offset)),
+        };
+        let new_position = i128::try_from(base)
+            .ok()
+            .and_then(|base| base.checked_add(offset))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "Seek position overflow.")
+            })?;
+        if new_position < 0 {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid seek to a negative position.",
+            ))
+        } else {
+            let new_position = usize::try_from(new_position).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidInput, "Seek position overflow.")
+            })?;
+            self.position = new_position;
+            Ok(new_position as u64)
+        }
+    }
+}
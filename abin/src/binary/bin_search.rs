@@ -0,0 +1,41 @@
+/// Returns the index of the first occurrence of `needle` in `haystack`. An empty needle is
+/// found at index 0.
+pub(crate) fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let (first, needle_len) = match needle.first() {
+        Some(first) => (*first, needle.len()),
+        None => return Some(0),
+    };
+    let last_start = haystack.len().checked_sub(needle_len)?;
+    let mut pos = 0;
+    while pos <= last_start {
+        // first find the first byte, then compare the rest
+        let start = pos
+            + haystack[pos..=last_start]
+                .iter()
+                .position(|byte| *byte == first)?;
+        if &haystack[start..start + needle_len] == needle {
+            return Some(start);
+        }
+        pos = start + 1;
+    }
+    None
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`. An empty needle is
+/// found at index `haystack.len()`.
+pub(crate) fn rfind_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let (first, needle_len) = match needle.first() {
+        Some(first) => (*first, needle.len()),
+        None => return Some(haystack.len()),
+    };
+    let mut last_start = haystack.len().checked_sub(needle_len)?;
+    loop {
+        let start = haystack[..=last_start]
+            .iter()
+            .rposition(|byte| *byte == first)?;
+        if &haystack[start..start + needle_len] == needle {
+            return Some(start);
+        }
+        last_start = start.checked_sub(1)?;
+    }
+}
@@ -44,8 +44,8 @@ where
 {
     pub fn as_slice(&self) -> &[u8] {
         match self {
-            BinSegment::Slice(slice) => *slice,
-            BinSegment::Static(slice) => *slice,
+            BinSegment::Slice(slice) => slice,
+            BinSegment::Static(slice) => slice,
             BinSegment::Bin(bin) => bin.as_slice(),
             BinSegment::GivenVec(vec) => vec.as_slice(),
             BinSegment::Bytes128(bytes) => bytes.as_slice(),
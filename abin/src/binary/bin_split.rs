@@ -0,0 +1,169 @@
+use crate::binary::find_subslice;
+use crate::AnyBin;
+
+/// Iterator over the parts of a binary separated by a delimiter; see `AnyBin::split_bin`.
+///
+/// The parts are slices of the binary (see `AnyBin::slice`), so no bytes are copied for
+/// reference-counted or static binaries.
+#[derive(Debug, Clone)]
+pub struct BinSplit<TBin, TDelimiter> {
+    bin: TBin,
+    delimiter: TDelimiter,
+    pos: usize,
+    finished: bool,
+}
+
+impl<TBin, TDelimiter> BinSplit<TBin, TDelimiter>
+where
+    TBin: AnyBin,
+    TDelimiter: AsRef<[u8]>,
+{
+    pub(crate) fn new(bin: TBin, delimiter: TDelimiter) -> Self {
+        Self {
+            bin,
+            delimiter,
+            pos: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the remaining part of the binary (the part that has not been returned yet)
+    /// and finishes the iterator.
+    fn remainder(&mut self) -> Option<TBin> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(slice(&self.bin, self.pos, self.bin.len()))
+        }
+    }
+}
+
+impl<TBin, TDelimiter> Iterator for BinSplit<TBin, TDelimiter>
+where
+    TBin: AnyBin,
+    TDelimiter: AsRef<[u8]>,
+{
+    type Item = TBin;
+
+    fn next(&mut self) -> Option<TBin> {
+        if self.finished {
+            return None;
+        }
+        let delimiter = self.delimiter.as_ref();
+        if delimiter.is_empty() {
+            // an empty delimiter never splits.
+            return self.remainder();
+        }
+        match find_subslice(&self.bin.as_slice()[self.pos..], delimiter) {
+            Some(index) => {
+                let start = self.pos;
+                let end = start + index;
+                self.pos = end + delimiter.len();
+                Some(slice(&self.bin, start, end))
+            }
+            None => self.remainder(),
+        }
+    }
+}
+
+/// Iterator over at most `n` parts of a binary separated by a delimiter; the last part
+/// contains the remaining binary. See `AnyBin::splitn_bin`.
+#[derive(Debug, Clone)]
+pub struct BinSplitN<TBin, TDelimiter> {
+    split: BinSplit<TBin, TDelimiter>,
+    remaining: usize,
+}
+
+impl<TBin, TDelimiter> BinSplitN<TBin, TDelimiter>
+where
+    TBin: AnyBin,
+    TDelimiter: AsRef<[u8]>,
+{
+    pub(crate) fn new(bin: TBin, n: usize, delimiter: TDelimiter) -> Self {
+        Self {
+            split: BinSplit::new(bin, delimiter),
+            remaining: n,
+        }
+    }
+}
+
+impl<TBin, TDelimiter> Iterator for BinSplitN<TBin, TDelimiter>
+where
+    TBin: AnyBin,
+    TDelimiter: AsRef<[u8]>,
+{
+    type Item = TBin;
+
+    fn next(&mut self) -> Option<TBin> {
+        match self.remaining {
+            0 => None,
+            1 => {
+                self.remaining = 0;
+                self.split.remainder()
+            }
+            _ => {
+                self.remaining -= 1;
+                self.split.next()
+            }
+        }
+    }
+}
+
+/// Iterator over the lines of a binary; see `AnyBin::lines_bin`.
+///
+/// Lines are separated by `\n` or `\r\n`; the line ending is not part of the returned line.
+/// The final line ending is optional (like `str::lines`).
+#[derive(Debug, Clone)]
+pub struct BinLines<TBin> {
+    bin: TBin,
+    pos: usize,
+}
+
+impl<TBin> BinLines<TBin>
+where
+    TBin: AnyBin,
+{
+    pub(crate) fn new(bin: TBin) -> Self {
+        Self { bin, pos: 0 }
+    }
+}
+
+impl<TBin> Iterator for BinLines<TBin>
+where
+    TBin: AnyBin,
+{
+    type Item = TBin;
+
+    fn next(&mut self) -> Option<TBin> {
+        let bytes = self.bin.as_slice();
+        let start = self.pos;
+        if start >= bytes.len() {
+            return None;
+        }
+        let end = match bytes[start..].iter().position(|byte| *byte == b'\n') {
+            Some(index) => {
+                let new_line = start + index;
+                self.pos = new_line + 1;
+                // `\r\n`
+                if new_line > start && bytes[new_line - 1] == b'\r' {
+                    new_line - 1
+                } else {
+                    new_line
+                }
+            }
+            None => {
+                self.pos = bytes.len();
+                bytes.len()
+            }
+        };
+        Some(slice(&self.bin, start, end))
+    }
+}
+
+#[inline]
+fn slice<TBin: AnyBin>(bin: &TBin, start: usize, end: usize) -> TBin {
+    bin.slice(start..end).expect(
+        "There's an implementation error: The range must be within the bounds of the binary.",
+    )
+}
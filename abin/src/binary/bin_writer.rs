@@ -0,0 +1,161 @@
+use std::io;
+use std::mem;
+#[cfg(feature = "bytes")]
+use std::mem::MaybeUninit;
+
+use crate::{BinBuilder, Bytes128, IntoBinMut};
+
+/// Adapter to use a `BinBuilder` as `std::io::Write`.
+///
+/// Every write is pushed to the builder (so the builder decides how to store the output; small
+/// outputs stay on the stack): Small writes are pushed as `Bytes128` (no allocation); larger
+/// writes are copied to a vector that also reserves the excess capacity required for
+/// reference-counting (so a single large write does not need to be copied again when
+/// building).
+///
+/// ```rust
+/// use std::io::Write;
+/// use abin::{NewBin, Bin, AnyBin, BinWriter};
+///
+/// let mut writer = BinWriter::new(NewBin::builder());
+/// write!(writer, "Hello, {}!", "World").unwrap();
+/// let bin : Bin = writer.build();
+/// assert_eq!("Hello, World!".as_bytes(), bin.as_slice());
+/// ```
+///
+/// Also implements `bytes::BufMut` (cargo feature `bytes`).
+pub struct BinWriter<TBuilder> {
+    builder: TBuilder,
+    /// number of bytes pushed to the builder.
+    len: usize,
+    /// Bytes written using `bytes::BufMut::chunk_mut` (not yet pushed to the builder). Either no
+    /// capacity at all or an excess capacity of at least `mut_overhead_bytes`.
+    pending: Vec<u8>,
+}
+
+impl<'a, TBuilder> BinWriter<TBuilder>
+where
+    TBuilder: BinBuilder<'a>,
+    TBuilder::T: IntoBinMut,
+{
+    /// Creates a new writer; note: the builder should be empty.
+    #[inline]
+    pub fn new(builder: TBuilder) -> Self {
+        Self {
+            builder,
+            len: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// The number of bytes written so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len + self.pending.len()
+    }
+
+    /// `true` if nothing has been written so far.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends the given slice.
+    pub fn write_slice(&mut self, slice: &[u8]) {
+        self.push_pending();
+        if let Some(bytes) = Bytes128::try_new(slice) {
+            self.builder.push(bytes);
+        } else {
+            let overhead = <TBuilder::T as IntoBinMut>::mut_overhead_bytes();
+            let mut vec = Vec::with_capacity(slice.len().checked_add(overhead).unwrap());
+            vec.extend_from_slice(slice);
+            self.builder.push_given_vec(vec);
+        }
+        self.len = self.len.checked_add(slice.len()).unwrap();
+    }
+
+    /// Builds the binary. Note: After calling this method, the writer will be empty again and
+    /// can be re-used (see `BinBuilder::build`).
+    pub fn build(&mut self) -> TBuilder::T {
+        self.push_pending();
+        self.len = 0;
+        self.builder.build()
+    }
+
+    /// The excess capacity of the pending vector (without the capacity required for the
+    /// reference-count metadata); reserves at least `additional` bytes first if required. See
+    /// `set_pending_len`.
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub(crate) fn pending_spare_capacity_mut(
+        &mut self,
+        additional: usize,
+    ) -> &mut [MaybeUninit<u8>] {
+        let overhead = <TBuilder::T as IntoBinMut>::mut_overhead_bytes();
+        let available = self.pending_available(overhead);
+        if available < additional {
+            let reserve = additional.checked_add(overhead).unwrap();
+            self.pending.reserve(reserve);
+        }
+        let available = self.pending_available(overhead);
+        &mut self.pending.spare_capacity_mut()[..available]
+    }
+
+    /// Marks `cnt` additional bytes of the pending vector as written. Panics if this would use
+    /// the capacity required for the reference-count metadata.
+    ///
+    /// Safety: Those bytes must be initialized (see `pending_spare_capacity_mut`).
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub(crate) unsafe fn advance_pending(&mut self, cnt: usize) {
+        let overhead = <TBuilder::T as IntoBinMut>::mut_overhead_bytes();
+        let available = self.pending_available(overhead);
+        assert!(
+            cnt <= available,
+            "Cannot advance past the end of the buffer (available {}; advance {}).",
+            available,
+            cnt
+        );
+        self.pending.set_len(self.pending.len() + cnt);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[inline]
+    fn pending_available(&self, overhead: usize) -> usize {
+        (self.pending.capacity() - self.pending.len()).saturating_sub(overhead)
+    }
+
+    /// Pushes the pending vector to the builder (the builder adopts it; it already reserves the
+    /// excess capacity required for reference-counting).
+    #[inline]
+    fn push_pending(&mut self) {
+        if !self.pending.is_empty() {
+            let pending = mem::take(&mut self.pending);
+            self.len = self.len.checked_add(pending.len()).unwrap();
+            self.builder.push_given_vec(pending);
+        }
+    }
+}
+
+impl<'a, TBuilder> io::Write for BinWriter<TBuilder>
+where
+    TBuilder: BinBuilder<'a>,
+    TBuilder::T: IntoBinMut,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
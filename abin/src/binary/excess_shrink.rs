@@ -67,7 +67,7 @@ impl ShrinkResult {
     /// Keep the vector untouched (do not shrink).
     #[inline]
     pub fn do_not_shrink() -> Self {
-        Self(core::u32::MAX)
+        Self(u32::MAX)
     }
 
     /// Shrinks the vector but keeps some remaining excess.
@@ -78,7 +78,7 @@ impl ShrinkResult {
 
     #[inline]
     fn do_shrink(self) -> bool {
-        self.0 != core::u32::MAX
+        self.0 != u32::MAX
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{AnyBin, BinSegment, ExcessShrink, SegmentIterator};
+use crate::{AnyBin, BinSegment, ExcessShrink, SegmentIterator, SlicePolicy};
 
 /// Use this factory to create binaries. There's a built-in implementation in this crate;
 /// custom implementations (that implement this trait) are possible.
@@ -85,6 +85,40 @@ pub trait BinFactory {
     /// ```
     fn from_segment<'a>(segment: impl Into<BinSegment<'a, Self::T>>) -> Self::T;
 
+    /// Like `from_segments` but binaries that are used as they are (a single
+    /// `BinSegment::Bin`) are compacted using the given policy (see
+    /// `AnyBin::compact_with_policy`); so the result does not keep a large buffer alive. Note:
+    /// Multiple segments are joined (copied) anyway.
+    ///
+    /// ```rust
+    /// use abin::{AnyBin, BinFactory, BinSegment, DefaultSlicePolicy, NewBin, SegmentsSlice};
+    ///
+    /// let response = NewBin::copy_from_slice(&[7u8; 100_000]);
+    /// let segments = &mut [BinSegment::Bin(response.slice(10..100).unwrap())];
+    /// let bin = NewBin::from_segments_with_policy::<DefaultSlicePolicy>(SegmentsSlice::new(segments));
+    /// assert_eq!(Some(1), response.strong_count());
+    /// assert_eq!(&[7u8; 90][..], bin.as_slice());
+    /// ```
+    fn from_segments_with_policy<'a, TPolicy: SlicePolicy>(
+        iter: impl SegmentIterator<BinSegment<'a, Self::T>>,
+    ) -> Self::T;
+
+    /// Like `from_segment` but a binary (`BinSegment::Bin`) is compacted using the given policy
+    /// (see `AnyBin::compact_with_policy`).
+    ///
+    /// ```rust
+    /// use abin::{AnyBin, BinFactory, BinSegment, DefaultSlicePolicy, NewBin};
+    ///
+    /// let response = NewBin::copy_from_slice(&[7u8; 100_000]);
+    /// let segment = BinSegment::Bin(response.slice(10..100).unwrap());
+    /// let bin = NewBin::from_segment_with_policy::<DefaultSlicePolicy>(segment);
+    /// assert_eq!(Some(1), response.strong_count());
+    /// assert!(bin.retained_bytes() < 1000);
+    /// ```
+    fn from_segment_with_policy<'a, TPolicy: SlicePolicy>(
+        segment: impl Into<BinSegment<'a, Self::T>>,
+    ) -> Self::T;
+
     /// Creates a binary from given vec. Important: Only use this method if you're given
     /// a `Vec<u8>` from outside (something you can't control). If you're in control, use
     /// any of the other methods provided by this factory (such as `from_iter`, `from_segments`).
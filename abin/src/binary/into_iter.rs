@@ -40,11 +40,7 @@ impl<T: AnyBin> Iterator for IntoIter<T> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let rem = if self.pos < self.len {
-            self.len - self.pos
-        } else {
-            0
-        };
+        let rem = self.len.saturating_sub(self.pos);
         (rem, Some(rem))
     }
 }
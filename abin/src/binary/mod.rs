@@ -1,13 +1,27 @@
 pub use {
-    any_bin::*, bin::*, bin_builder::*, bin_segment::*, excess_shrink::*, factory::*, into_iter::*,
-    s_bin::*,
+    any_bin::*, any_bin_mut::*, bin::*, bin_builder::*, bin_codec::*, bin_mut::*, bin_reader::*,
+    bin_segment::*, bin_split::*, bin_writer::*, excess_shrink::*, factory::*, into_iter::*,
+    s_bin::*, s_bin_mut::*, slice_error::*, slice_policy::*, storage_kind::*,
 };
 
+pub(crate) use bin_search::*;
+
 mod any_bin;
+mod any_bin_mut;
 mod bin;
 mod bin_builder;
+mod bin_codec;
+mod bin_mut;
+mod bin_reader;
+mod bin_search;
 mod bin_segment;
+mod bin_split;
+mod bin_writer;
 mod excess_shrink;
 mod factory;
 mod into_iter;
 mod s_bin;
+mod s_bin_mut;
+mod slice_error;
+mod slice_policy;
+mod storage_kind;
@@ -5,8 +5,12 @@ use std::fmt::{Debug, Formatter, LowerHex, UpperHex};
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, RangeBounds};
 
+use crate::binary::normalize_range;
 use crate::spi::UnsafeBin;
-use crate::{AnyBin, Bin, IntoIter, IntoSync, IntoUnSync, IntoUnSyncView, UnSyncRef};
+use crate::{
+    AnyBin, AnyRc, ArcBin, Bin, BinFactory, IntoBinMut, IntoIter, IntoSync, IntoUnSync,
+    IntoUnSyncView, NewSBin, SBinMut, SliceError, SlicePolicy, StorageKind, UnSyncRef,
+};
 
 /// A binary that does implement `Send + Sync`. See `AnyBin` for documentation; see `Bin`
 /// if you don't need `Send + Sync`. See `BinFactory` on how to create binaries.
@@ -24,10 +28,10 @@ unsafe impl Send for SBin {}
 
 /// Returns the un-synchronized view of this binary. (so it's the same as `IntoUnSyncView`;
 /// NOT `IntoUnSync`).
-impl Into<Bin> for SBin {
+impl From<SBin> for Bin {
     #[inline]
-    fn into(self) -> Bin {
-        self.0
+    fn from(bin: SBin) -> Self {
+        bin.0
     }
 }
 
@@ -95,6 +99,29 @@ impl AnyBin for SBin {
             .map(|bin| unsafe { bin._into_sync() })
     }
 
+    fn try_slice<TRange>(&self, range: TRange) -> Result<Self, SliceError>
+    where
+        TRange: RangeBounds<usize>,
+    {
+        self.un_sync_ref()
+            .try_slice(range)
+            .map(|bin| unsafe { bin._into_sync() })
+    }
+
+    fn slice_with_policy<TPolicy, TRange>(&self, range: TRange) -> Option<Self>
+    where
+        TPolicy: SlicePolicy,
+        TRange: RangeBounds<usize>,
+    {
+        let range = normalize_range(&range, self.len()).ok()?;
+        if TPolicy::copy(self.storage_kind(), range.len(), self.retained_bytes()) {
+            // copy the range directly (slicing first might already copy; e.g. `Vec` backend).
+            Some(NewSBin::copy_from_slice(&self.as_slice()[range]))
+        } else {
+            self.slice(range)
+        }
+    }
+
     #[inline]
     fn try_to_re_integrate(&self, slice: &[u8]) -> Option<Self> {
         unsafe {
@@ -103,6 +130,59 @@ impl AnyBin for SBin {
                 .map(|bin| bin._into_sync())
         }
     }
+
+    #[inline]
+    fn get_mut(&mut self) -> Option<&mut [u8]> {
+        self.0.get_mut()
+    }
+
+    fn make_mut(&mut self) -> &mut [u8] {
+        if self.0.get_mut().is_none() {
+            *self = NewSBin::copy_from_slice(self.as_slice());
+        }
+        self.0
+            .get_mut()
+            .expect("There's an implementation error: A newly created binary must be mutable.")
+    }
+
+    #[inline]
+    fn split_to(&mut self, at: usize) -> Option<Self> {
+        self.0.split_to(at).map(|bin| unsafe { bin._into_sync() })
+    }
+
+    #[inline]
+    fn split_off(&mut self, at: usize) -> Option<Self> {
+        self.0.split_off(at).map(|bin| unsafe { bin._into_sync() })
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) -> bool {
+        self.0.advance(cnt)
+    }
+
+    #[inline]
+    fn storage_kind(&self) -> StorageKind {
+        self.0.storage_kind()
+    }
+
+    #[inline]
+    fn strong_count(&self) -> Option<usize> {
+        self.0.strong_count()
+    }
+
+    #[inline]
+    fn retained_bytes(&self) -> usize {
+        self.0.retained_bytes()
+    }
+
+    fn compact_with_policy<TPolicy: SlicePolicy>(&mut self) -> bool {
+        if TPolicy::copy(self.storage_kind(), self.len(), self.retained_bytes()) {
+            *self = NewSBin::copy_from_slice(self.as_slice());
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl Debug for SBin {
@@ -130,7 +210,7 @@ impl Ord for SBin {
 impl PartialOrd for SBin {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.un_sync_ref().partial_cmp(other.un_sync_ref())
+        Some(self.cmp(other))
     }
 }
 
@@ -203,10 +283,34 @@ impl IntoIterator for SBin {
     }
 }
 
-impl Into<Vec<u8>> for SBin {
+impl From<SBin> for Vec<u8> {
+    #[inline]
+    fn from(bin: SBin) -> Self {
+        bin.into_vec()
+    }
+}
+
+impl IntoBinMut for SBin {
+    #[inline]
+    fn try_into_mut(self) -> Result<SBinMut, Self> {
+        self.0
+            .try_into_unique_vec()
+            .map(SBinMut::from_given_vec)
+            .map_err(|bin| unsafe { bin._into_sync() })
+    }
+
     #[inline]
-    fn into(self) -> Vec<u8> {
-        self.into_vec()
+    fn mut_overhead_bytes() -> usize {
+        ArcBin::overhead_bytes()
+    }
+
+    #[inline]
+    fn freeze_vec(vec: Vec<u8>) -> Self {
+        if vec.is_empty() {
+            NewSBin::empty()
+        } else {
+            ArcBin::from_vec(vec)
+        }
     }
 }
 
@@ -0,0 +1,13 @@
+use crate::{AnyBinMut, SBin};
+
+/// A mutable binary that can be frozen into a `SBin` (`Sync + Send`); see `BinMut` if you
+/// don't need `Sync + Send`. See `AnyBinMut` for documentation.
+///
+/// ```rust
+/// use abin::{SBinMut, SBin, AnyBin};
+/// let mut bin_mut = SBinMut::new();
+/// bin_mut.extend_from_slice("Hello".as_bytes());
+/// let bin: SBin = bin_mut.freeze();
+/// assert_eq!("Hello".as_bytes(), bin.as_slice());
+/// ```
+pub type SBinMut = AnyBinMut<SBin>;
@@ -0,0 +1,75 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Bound, Range, RangeBounds};
+
+/// Error returned by `AnyBin::try_slice` and `AnyStr::try_slice`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SliceError {
+    /// The range is not within the bounds of the binary / string (`start` and `end` are the
+    /// normalized range `[start, end)`; `end` is saturated to `usize::MAX` on overflow).
+    OutOfBounds {
+        len: usize,
+        start: usize,
+        end: usize,
+    },
+    /// The given index does not lie on a UTF-8 boundary (only returned for strings; see
+    /// `str::is_char_boundary`).
+    NotCharBoundary { index: usize },
+    /// The start of the range is greater than the end.
+    InvertedRange,
+}
+
+impl Error for SliceError {}
+
+impl Display for SliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::OutOfBounds { len, start, end } => write!(
+                f,
+                "Range {}..{} is out of bounds (length is {}).",
+                start, end, len
+            ),
+            SliceError::NotCharBoundary { index } => {
+                write!(f, "Index {} does not lie on a UTF-8 boundary.", index)
+            }
+            SliceError::InvertedRange => write!(f, "The start of the range is after the end."),
+        }
+    }
+}
+
+/// Converts the given range bounds to `[start, end)` and checks it against the given length.
+///
+/// A range that's out of bounds is reported as such even if it's also inverted.
+pub(crate) fn normalize_range<TRange>(
+    range: &TRange,
+    len: usize,
+) -> Result<Range<usize>, SliceError>
+where
+    TRange: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(start) => Some(*start),
+        Bound::Excluded(start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.checked_add(1),
+        Bound::Excluded(end) => Some(*end),
+        Bound::Unbounded => Some(len),
+    };
+    match (start, end) {
+        (Some(start), Some(end)) if start <= len && end <= len => {
+            if start > end {
+                Err(SliceError::InvertedRange)
+            } else {
+                Ok(start..end)
+            }
+        }
+        (start, end) => Err(SliceError::OutOfBounds {
+            len,
+            start: start.unwrap_or(usize::MAX),
+            end: end.unwrap_or(usize::MAX),
+        }),
+    }
+}
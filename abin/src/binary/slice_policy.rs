@@ -0,0 +1,61 @@
+use crate::StorageKind;
+
+/// Decides whether a slice shares the buffer of the binary it has been sliced from or whether
+/// the bytes are copied. Sharing is cheap but keeps the entire buffer alive: a 10 byte slice
+/// of a 4 MB buffer keeps 4 MB alive for as long as the slice lives.
+///
+/// The policy is only consulted where it's given explicitly: `AnyBin::slice_with_policy` (and
+/// `AnyStr::slice_with_policy`), `AnyBin::compact_with_policy`, the factories
+/// (`BinFactory::from_segments_with_policy` and `StrFactory::from_segments_with_policy`) and the
+/// serde re-integration scopes (see `DefaultScopes::sync_with_policy` and
+/// `DefaultScopes::binaries_with_policy`); `AnyBin::compact` uses `DefaultSlicePolicy`. The other
+/// factory methods (such as `BinFactory::from_segments`) do not consult a policy: binaries given
+/// to them (for example as `BinSegment::Bin`) are used as they are.
+pub trait SlicePolicy {
+    /// Returns `true` if the slice should be copied; `false` if the slice should share the
+    /// buffer.
+    ///
+    /// `storage_kind`: see `AnyBin::storage_kind` of the binary that's sliced. `slice_len`:
+    /// the length of the slice. `retained_bytes`: see `AnyBin::retained_bytes` of the binary
+    /// that's sliced.
+    fn copy(storage_kind: StorageKind, slice_len: usize, retained_bytes: usize) -> bool;
+}
+
+/// Always shares the buffer (never copies). This is what `AnyBin::slice` does.
+pub struct AlwaysShare;
+
+impl SlicePolicy for AlwaysShare {
+    #[inline]
+    fn copy(_storage_kind: StorageKind, _slice_len: usize, _retained_bytes: usize) -> bool {
+        false
+    }
+}
+
+/// Always copies the bytes (never shares the buffer).
+pub struct AlwaysCopy;
+
+impl SlicePolicy for AlwaysCopy {
+    #[inline]
+    fn copy(_storage_kind: StorageKind, _slice_len: usize, _retained_bytes: usize) -> bool {
+        true
+    }
+}
+
+/// Default implementation of `SlicePolicy` - should be ok for most use cases.
+///
+/// Copies small views of large heap buffers: The slice is copied if the buffer is at least
+/// 4 KiB and the slice is not larger than 1/8 of the buffer. Binaries without heap buffer
+/// (static, stack, memory-mapped files) and custom binaries are always shared.
+pub struct DefaultSlicePolicy;
+
+impl SlicePolicy for DefaultSlicePolicy {
+    #[inline]
+    fn copy(storage_kind: StorageKind, slice_len: usize, retained_bytes: usize) -> bool {
+        match storage_kind {
+            StorageKind::Rc | StorageKind::Arc | StorageKind::Vec | StorageKind::Bytes => {
+                retained_bytes >= 4 * 1024 && slice_len <= retained_bytes / 8
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/// Describes how the bytes of a binary are stored; see `AnyBin::storage_kind`. This is
+/// mostly useful for diagnostics (for example to find out why a service holds too much memory).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum StorageKind {
+    /// The binary is empty (no memory).
+    Empty,
+    /// The bytes are stored in-line (on the stack); no heap memory.
+    Stack,
+    /// The bytes are stored in a static slice; no heap memory.
+    Static,
+    /// The binary is backed by a vector (exclusively owned, not reference-counted).
+    Vec,
+    /// Reference-counted, not synchronized (`RcBin`, `Rc<[u8]>`).
+    Rc,
+    /// Reference-counted, synchronized (`ArcBin`, `Arc<[u8]>`).
+    Arc,
+    /// The binary wraps `bytes::Bytes` (see cargo feature `bytes`).
+    Bytes,
+    /// The binary is backed by a memory-mapped file (see cargo feature `mmap`).
+    Mmap,
+    /// A custom implementation that does not report its storage kind (see
+    /// `spi::FnTable::storage_kind`).
+    Custom,
+}
@@ -33,7 +33,7 @@ where
 /// Can convert `&T` to `Boo::Borrowed` and `T` to `Boo:Owned`.
 pub trait ToBooConverter {
     /// Convert `&self` to `Boo::Borrowed`.
-    fn borrowed<TBorrowed>(&self) -> Boo<TBorrowed, Self>
+    fn borrowed<TBorrowed>(&self) -> Boo<'_, TBorrowed, Self>
     where
         TBorrowed: ?Sized,
         Self: Borrow<TBorrowed>,
@@ -47,7 +47,7 @@ pub trait ToBooConverter {
 }
 
 impl<T> ToBooConverter for T {
-    fn borrowed<TBorrowed>(&self) -> Boo<TBorrowed, Self>
+    fn borrowed<TBorrowed>(&self) -> Boo<'_, TBorrowed, Self>
     where
         TBorrowed: ?Sized,
         Self: Borrow<TBorrowed>,
@@ -90,7 +90,7 @@ where
 {
     pub(crate) fn borrow_internal(&self) -> &TBorrowed {
         match self {
-            Boo::Borrowed(value) => *value,
+            Boo::Borrowed(value) => value,
             Boo::Owned(value) => value.borrow(),
         }
     }
@@ -127,7 +127,7 @@ where
 {
     fn borrow(&self) -> &TBorrowed {
         match self {
-            Boo::Borrowed(value) => *value,
+            Boo::Borrowed(value) => value,
             Boo::Owned(value) => value.borrow(),
         }
     }
@@ -140,7 +140,7 @@ where
 {
     fn as_ref(&self) -> &TBorrowed {
         match self {
-            Boo::Borrowed(value) => *value,
+            Boo::Borrowed(value) => value,
             Boo::Owned(value) => value.as_ref(),
         }
     }
@@ -155,7 +155,7 @@ where
 
     fn deref(&self) -> &Self::Target {
         match self {
-            Boo::Borrowed(value) => *value,
+            Boo::Borrowed(value) => value,
             Boo::Owned(value) => value.borrow(),
         }
     }
@@ -3,4 +3,4 @@ mod eq_ord;
 mod to_owned;
 mod types;
 
-pub use {boo_enum::*, eq_ord::*, to_owned::*, types::*};
+pub use {boo_enum::*, to_owned::*, types::*};
@@ -0,0 +1,95 @@
+use core::mem;
+
+use bytes::{Buf, Bytes};
+
+use crate::{AnyBin, BinFactory, BinReader, BytesBin, NewSBin, SBin, StorageKind};
+
+/// Reads the remaining bytes of the binary. `copy_to_bytes` does not copy: The binary of the
+/// reader is converted to a `Bytes`-backed binary once (this does not copy either); the returned
+/// `Bytes` are slices of it. Exception: Un-synchronized reference-counted binaries with other
+/// references can't be shared with `Bytes` (it's `Send + Sync`); in that case the returned
+/// bytes are copied.
+///
+/// ```rust
+/// use abin::{NewBin, BinFactory, BinReader};
+/// use bytes::Buf;
+///
+/// let mut reader = BinReader::new(NewBin::from_static(&[0, 1, 0, 0, 0, 2, 42]));
+/// assert_eq!(1, reader.get_u16());
+/// assert_eq!(2, reader.get_u32());
+/// assert_eq!(42, reader.get_u8());
+/// assert!(!reader.has_remaining());
+/// ```
+impl<T> Buf for BinReader<T>
+where
+    T: AnyBin + From<SBin>,
+{
+    #[inline]
+    fn remaining(&self) -> usize {
+        BinReader::remaining(self)
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.remaining_slice()
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= BinReader::remaining(self),
+            "Cannot advance past the end of the binary (remaining {}; advance {}).",
+            BinReader::remaining(self),
+            cnt
+        );
+        self.set_position(self.position() + cnt);
+    }
+
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        assert!(
+            len <= BinReader::remaining(self),
+            "Not enough bytes remaining (remaining {}; requested {}).",
+            BinReader::remaining(self),
+            len
+        );
+        let start = self.position();
+        let end = start + len;
+        if !share_with_bytes(self) {
+            let bytes = Bytes::copy_from_slice(&self.get_ref().as_slice()[start..end]);
+            self.set_position(end);
+            return bytes;
+        }
+        let slice = self
+            .get_ref()
+            .slice(start..end)
+            .expect("There's an implementation error: The range must be within bounds.");
+        self.set_position(end);
+        // does not copy: `Bytes`-backed binaries return the `Bytes` (see `From<SBin> for Bytes`).
+        Bytes::from(slice.into_sync())
+    }
+}
+
+/// Converts the binary of the reader into a `Bytes`-backed binary (see `BytesBin`) if it's not
+/// already one - so slices can be converted to `Bytes` without copying. Returns `false` if the
+/// binary can't be shared with `Bytes` without copying (un-synchronized reference-counted
+/// binaries with other references).
+fn share_with_bytes<T>(reader: &mut BinReader<T>) -> bool
+where
+    T: AnyBin + From<SBin>,
+{
+    let bin = reader.get_ref();
+    match bin.storage_kind() {
+        // small (or nothing); no need to convert.
+        StorageKind::Bytes | StorageKind::Empty | StorageKind::Stack => return true,
+        StorageKind::Rc if bin.strong_count() != Some(1) => return false,
+        _ => {}
+    }
+    let position = reader.position();
+    let placeholder = BinReader::new(T::from(NewSBin::empty()));
+    let bin = mem::replace(reader, placeholder).into_inner();
+    // does not copy for reference-counted binaries (see `IntoSync` and `From<SBin> for Bytes`).
+    let bytes = Bytes::from(bin.into_sync());
+    *reader = BinReader::new(T::from(BytesBin::from_bytes(bytes)));
+    reader.set_position(position);
+    true
+}
@@ -0,0 +1,93 @@
+use bytes::buf::UninitSlice;
+use bytes::BufMut;
+
+use crate::{AnyBinMut, BinBuilder, BinWriter, IntoBinMut};
+
+/// The mutable binary can be used as a builder; it's frozen into a binary without copying. Note:
+/// The capacity reserved for the reference-count metadata is never exposed by `chunk_mut`.
+///
+/// ```rust
+/// use abin::{BinMut, AnyBin};
+/// use bytes::BufMut;
+///
+/// let mut bin_mut = BinMut::new();
+/// bin_mut.put_u16(1);
+/// bin_mut.put_slice("Hello".as_bytes());
+/// assert_eq!(&[0u8, 1, 72, 101, 108, 108, 111], bin_mut.freeze().as_slice());
+/// ```
+unsafe impl<TBin> BufMut for AnyBinMut<TBin>
+where
+    TBin: IntoBinMut,
+{
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        // same as `Vec<u8>`; the capacity can grow (but not beyond `isize::MAX`).
+        (isize::MAX as usize) - self.len()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let len = self.len();
+        let available = self.capacity() - len;
+        assert!(
+            cnt <= available,
+            "Cannot advance past the end of the buffer (available {}; advance {}).",
+            available,
+            cnt
+        );
+        self.set_len(len + cnt);
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        // same as `Vec<u8>`: grows if there's no excess capacity.
+        let additional = if self.capacity() == self.len() { 64 } else { 0 };
+        UninitSlice::uninit(self.spare_capacity_mut(additional))
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src);
+    }
+}
+
+/// The builder adapter (see `BinWriter`): `put_slice` (and the `put_*` methods using it) pushes
+/// to the builder like `std::io::Write` does (small outputs stay on the stack). `chunk_mut`
+/// writes to a vector instead; that vector is pushed to the builder (without copying) on the
+/// next `put_slice` or when building.
+///
+/// ```rust
+/// use abin::{NewBin, AnyBin, BinWriter};
+/// use bytes::BufMut;
+///
+/// let mut writer = BinWriter::new(NewBin::builder());
+/// writer.put_u16(1);
+/// writer.put_slice("Hello".as_bytes());
+/// writer.put(&[33u8][..]);
+/// assert_eq!(&[0u8, 1, 72, 101, 108, 108, 111, 33], writer.build().as_slice());
+/// ```
+unsafe impl<'a, TBuilder> BufMut for BinWriter<TBuilder>
+where
+    TBuilder: BinBuilder<'a>,
+    TBuilder::T: IntoBinMut,
+{
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        (isize::MAX as usize) - self.len()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        self.advance_pending(cnt);
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        UninitSlice::uninit(self.pending_spare_capacity_mut(64))
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.write_slice(src);
+    }
+}
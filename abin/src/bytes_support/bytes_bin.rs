@@ -0,0 +1,203 @@
+use core::{mem, ptr, slice};
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::spi::{BinData, FnTable, UnsafeBin};
+use crate::{Bin, BinFactory, IntoUnSyncView, NewSBin, SBin, StackBin, StorageKind, UnSyncRef};
+
+/// A binary that wraps `bytes::Bytes` (without copying the content). Note: There's only a
+/// synchronized version (`Bytes` is always `Send + Sync`).
+pub struct BytesBin;
+
+impl BytesBin {
+    /// Does those steps:
+    ///
+    ///  * If it's empty, returns an empty binary.
+    ///  * If it's small, returns a stack binary (copying is cheaper than another allocation).
+    ///  * ...otherwise wraps the given `Bytes`.
+    pub fn from_bytes(bytes: Bytes) -> SBin {
+        if bytes.is_empty() {
+            NewSBin::empty()
+        } else if let Some(stack) = StackBin::try_from(bytes.as_ref()) {
+            stack
+        } else {
+            let bytes_data = BytesData {
+                ptr: bytes.as_ptr(),
+                len: bytes.len(),
+                owner: Arc::into_raw(Arc::new(bytes)),
+            };
+            unsafe { Bin::_new(bytes_data.to_bin_data(), &FN_TABLE)._into_sync() }
+        }
+    }
+
+    /// Returns the wrapped `Bytes` if the given binary is a bytes binary (see `from_bytes`);
+    /// returns the unchanged binary otherwise. Does not copy the content.
+    pub fn try_into_bytes(bin: SBin) -> Result<Bytes, SBin> {
+        if ptr::eq(unsafe { bin.un_sync_ref()._fn_table() }, &FN_TABLE) {
+            let bin = bin.un_sync();
+            let bytes_data = unsafe { BytesData::from_bin(&bin) };
+            let (data_ptr, data_len) = (bytes_data.ptr, bytes_data.len);
+            let owner = unsafe { Arc::from_raw(bytes_data.owner) };
+            // bin must not be dropped (we took the reference).
+            mem::forget(bin);
+            let bytes = Arc::try_unwrap(owner).unwrap_or_else(|owner| Bytes::clone(&owner));
+            if bytes.as_ptr() == data_ptr && bytes.len() == data_len {
+                Ok(bytes)
+            } else {
+                let slice = unsafe { slice::from_raw_parts(data_ptr, data_len) };
+                Ok(bytes.slice_ref(slice))
+            }
+        } else {
+            Err(bin)
+        }
+    }
+}
+
+#[repr(C)]
+struct BytesData {
+    /// pointer to the data; this differs from the `Bytes` data if sliced.
+    ptr: *const u8,
+    /// the length of the data; this differs from the `Bytes` length if sliced.
+    len: usize,
+    /// the reference-counted `Bytes`: See `Arc::into_raw`.
+    owner: *const Bytes,
+}
+
+impl BytesData {
+    #[inline]
+    unsafe fn from_bin(bin: &Bin) -> &Self {
+        let bin_data = bin._data() as *const BinData;
+        let self_data = bin_data as *const Self;
+        &*self_data
+    }
+
+    #[inline]
+    unsafe fn to_bin_data(&self) -> BinData {
+        mem::transmute_copy::<Self, BinData>(self)
+    }
+
+    #[inline]
+    fn owner(&self) -> &Bytes {
+        unsafe { &*self.owner }
+    }
+}
+
+/// This must be a `static` (not a `const`): the address is used to detect bytes binaries.
+static FN_TABLE: FnTable = FnTable {
+    drop: Some(drop),
+    as_slice: Some(as_slice),
+    is_empty: Some(is_empty),
+    clone,
+    into_vec,
+    slice,
+    // not required: sync only.
+    convert_into_un_sync: None,
+    // not required: sync only.
+    convert_into_sync: None,
+    try_re_integrate: Some(try_re_integrate),
+    // not supported: the buffer is owned by `Bytes`.
+    try_into_unique_vec: None,
+    // not supported: `Bytes` is immutable.
+    get_mut: None,
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind),
+    strong_count: Some(strong_count),
+    retained_bytes: Some(retained_bytes),
+};
+
+fn drop(bin: &mut Bin) {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    // decrements the reference count (and drops the `Bytes` if this is the last reference).
+    unsafe { mem::drop(Arc::from_raw(bytes_data.owner)) };
+}
+
+#[inline]
+fn as_slice(bin: &Bin) -> &[u8] {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    unsafe { slice::from_raw_parts(bytes_data.ptr, bytes_data.len) }
+}
+
+fn is_empty(bin: &Bin) -> bool {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    bytes_data.len == 0
+}
+
+fn clone(bin: &Bin) -> Bin {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    unsafe {
+        Arc::increment_strong_count(bytes_data.owner);
+        Bin::_new(bytes_data.to_bin_data(), &FN_TABLE)
+    }
+}
+
+fn into_vec(bin: Bin) -> Vec<u8> {
+    let bytes_data = unsafe { BytesData::from_bin(&bin) };
+    let is_whole = {
+        let owner = bytes_data.owner();
+        owner.as_ptr() == bytes_data.ptr && owner.len() == bytes_data.len
+    };
+    if is_whole {
+        let owner = unsafe { Arc::from_raw(bytes_data.owner) };
+        // bin must not be dropped (we took the reference).
+        mem::forget(bin);
+        match Arc::try_unwrap(owner) {
+            // `Bytes` can (sometimes) return the buffer without copying.
+            Ok(bytes) => Vec::from(bytes),
+            Err(owner) => owner.to_vec(),
+        }
+    } else {
+        // the only option is to copy
+        as_slice(&bin).to_vec()
+    }
+}
+
+fn slice(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    if as_slice(bin).get(start..end_excluded).is_some() {
+        let len = end_excluded - start;
+        if len == 0 {
+            Some(NewSBin::empty().un_sync())
+        } else {
+            unsafe { Arc::increment_strong_count(bytes_data.owner) };
+            let new_data = BytesData {
+                ptr: unsafe { bytes_data.ptr.add(start) },
+                len,
+                owner: bytes_data.owner,
+            };
+            Some(unsafe { Bin::_new(new_data.to_bin_data(), &FN_TABLE) })
+        }
+    } else {
+        None
+    }
+}
+
+fn try_re_integrate(bin: &Bin, slice_in: &[u8]) -> Option<Bin> {
+    let self_slice = as_slice(bin);
+    let start = (slice_in.as_ptr() as usize).checked_sub(self_slice.as_ptr() as usize);
+    if let Some(start) = start {
+        slice(bin, start, start + slice_in.len())
+    } else {
+        None
+    }
+}
+
+fn storage_kind(_: &Bin) -> StorageKind {
+    StorageKind::Bytes
+}
+
+fn strong_count(bin: &Bin) -> usize {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    // we don't own this one (must not be dropped).
+    let owner = mem::ManuallyDrop::new(unsafe { Arc::from_raw(bytes_data.owner) });
+    Arc::strong_count(&owner)
+}
+
+fn retained_bytes(bin: &Bin) -> usize {
+    let bytes_data = unsafe { BytesData::from_bin(bin) };
+    // the length of the wrapped `Bytes` (the buffer owned by `Bytes` might be larger).
+    bytes_data.owner().len()
+}
@@ -0,0 +1,43 @@
+use bytes::Bytes;
+
+use crate::{AnyBin, Bin, BytesBin, IntoUnSyncView, SBin};
+
+/// Wraps the given `Bytes`; does not copy (except for small binaries: those are stored on
+/// the stack).
+impl From<Bytes> for SBin {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        BytesBin::from_bytes(bytes)
+    }
+}
+
+/// Wraps the given `Bytes`; does not copy (except for small binaries: those are stored on
+/// the stack).
+impl From<Bytes> for Bin {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        BytesBin::from_bytes(bytes).un_sync()
+    }
+}
+
+/// Does not copy (the `Bytes` just references the binary). If the binary wraps a `Bytes`, that
+/// `Bytes` is returned.
+impl From<SBin> for Bytes {
+    fn from(bin: SBin) -> Self {
+        match BytesBin::try_into_bytes(bin) {
+            Ok(bytes) => bytes,
+            Err(bin) => {
+                if bin.is_empty() {
+                    Bytes::new()
+                } else {
+                    Bytes::from_owner(bin)
+                }
+            }
+        }
+    }
+}
+
+// note: There's intentionally no `From<Bin> for Bytes`: `Bytes` is `Send + Sync`, `Bin` is not.
+// So a reference-counted `Bin` can only be handed over to `Bytes` without copying if there are no
+// other references - a conversion that silently copies otherwise would be misleading. Use
+// `IntoSync::into_sync` (explicit; copies only if required) and convert the `SBin`.
@@ -0,0 +1,6 @@
+pub(crate) use bytes_bin::*;
+
+mod buf;
+mod buf_mut;
+mod bytes_bin;
+mod conversion;
@@ -60,12 +60,7 @@ where
 
     #[inline]
     fn remaining(&self) -> usize {
-        let len = self.slice.len();
-        if self.pos < len {
-            len - self.pos
-        } else {
-            0
-        }
+        self.slice.len().saturating_sub(self.pos)
     }
 
     fn take(&mut self, index: usize) -> Option<TSegment> {
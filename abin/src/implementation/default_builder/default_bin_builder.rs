@@ -8,10 +8,10 @@ use std::marker::PhantomData;
 /// There's two things we want to optimize:
 ///
 ///  * Only one single segment: Say for example if you add just a static-bin, we want to make
-/// sure there's no allocation.
+///    sure there's no allocation.
 ///  * Small length: Length that can be stack-allocated should not allocate.
 ///  * When there's larger items, we want to know the entire length when building (so
-/// we know how many bytes to allocate for the vector).
+///    we know how many bytes to allocate for the vector).
 ///
 /// ... for all other cases it's OK to allocate a `Vec<u8>` (since when converting to `Bin`
 /// this `Vec<u8>` must be allocated anyways).
@@ -1,7 +1,9 @@
+use crate::binary::{base64_decode, base64_validate, hex_decode, hex_decoded_len};
 use crate::{
-    maybe_shrink, AnyBin, AnyRc, ArcBin, Bin, BinFactory, BinSegment, DefaultGivenVecConfig,
-    EmptyBin, GivenVecConfig, GivenVecOptimization, IntoUnSyncView, NewBin, NewSBin, RcBin, SBin,
-    SegmentIterator, StackBin, StackBinBuilder, StaticBin, VecBin,
+    maybe_shrink, AnyBin, AnyRc, ArcBin, Base64Variant, Bin, BinFactory, BinSegment, DecodeError,
+    DefaultGivenVecConfig, EmptyBin, GivenVecConfig, GivenVecOptimization, IntoUnSyncView, NewBin,
+    NewSBin, RcBin, SBin, SegmentIterator, SlicePolicy, StackBin, StackBinBuilder, StaticBin,
+    VecBin,
 };
 
 pub trait CommonFactory {
@@ -163,6 +165,32 @@ where
         Self::from_segment_with_config::<'a, DefaultGivenVecConfig, _>(segment)
     }
 
+    fn from_segments_with_policy<'a, TPolicy: SlicePolicy>(
+        iter: impl SegmentIterator<BinSegment<'a, Self::T>>,
+    ) -> Self::T {
+        if iter.is_empty() {
+            Self::empty()
+        } else {
+            match iter.single() {
+                Ok(single) => Self::from_segment_with_policy::<TPolicy>(single),
+                // joined segments are copied anyway
+                Err(iter) => Self::from_segments(iter),
+            }
+        }
+    }
+
+    fn from_segment_with_policy<'a, TPolicy: SlicePolicy>(
+        segment: impl Into<BinSegment<'a, Self::T>>,
+    ) -> Self::T {
+        match segment.into() {
+            BinSegment::Bin(mut bin) => {
+                bin.compact_with_policy::<TPolicy>();
+                bin
+            }
+            segment => Self::from_segment(segment),
+        }
+    }
+
     #[inline]
     fn from_given_vec(vec: Vec<u8>) -> Self::T {
         TCf::from_given_vec_with_config::<DefaultGivenVecConfig>(vec)
@@ -205,6 +233,47 @@ where
     }
 }
 
+/// Creates a binary of exactly `len` bytes; `fill` writes the content. Small binaries are stored
+/// on the stack; otherwise the vector reserves the reference-counting overhead (so there's
+/// exactly one allocation and no re-allocation).
+pub(crate) fn build_exact<TCf, TFn>(len: usize, fill: TFn) -> <TCf::TAnyRc as AnyRc>::T
+where
+    TCf: CommonFactory,
+    TFn: FnOnce(&mut [u8]),
+{
+    if len <= StackBin::max_len() {
+        let mut buf = [0u8; StackBin::max_len()];
+        fill(&mut buf[..len]);
+        let stack = StackBin::try_from(&buf[..len])
+            .expect("There's an implementation error: Must fit onto the stack.");
+        TCf::TFunctions::convert_to_un_sync(stack)
+    } else {
+        let mut vec = Vec::with_capacity(len + TCf::TAnyRc::overhead_bytes());
+        vec.resize(len, 0);
+        fill(&mut vec);
+        TCf::TAnyRc::from_vec(vec)
+    }
+}
+
+/// Decodes the given hex string; see `NewBin::from_hex`.
+pub(crate) fn decode_hex<TCf: CommonFactory>(
+    input: &[u8],
+) -> Result<<TCf::TAnyRc as AnyRc>::T, DecodeError> {
+    let len = hex_decoded_len(input)?;
+    Ok(build_exact::<TCf, _>(len, |out| hex_decode(input, out)))
+}
+
+/// Decodes the given Base64 string; see `NewBin::from_base64`.
+pub(crate) fn decode_base64<TCf: CommonFactory>(
+    input: &[u8],
+    variant: Base64Variant,
+) -> Result<<TCf::TAnyRc as AnyRc>::T, DecodeError> {
+    let (symbols, len) = base64_validate(input, variant)?;
+    Ok(build_exact::<TCf, _>(len, |out| {
+        base64_decode(&input[..symbols], out, variant)
+    }))
+}
+
 impl CommonFactory for NewBin {
     type TAnyRc = RcBin;
     type TFunctions = FunctionsForNew;
@@ -1,8 +1,11 @@
 use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
 
+use crate::implementation::{decode_base64, decode_hex};
 use crate::{
-    AnyRc, Bin, BinBuilder, BinFactory, BooToOwned, BuilderCfg, DefaultBinBuilder, IntoUnSyncView,
-    RcBin, SBin,
+    AnyRc, Base64Variant, Bin, BinBuilder, BinFactory, BooToOwned, BuilderCfg, DecodeError,
+    DefaultBinBuilder, IntoUnSyncView, RcBin, SBin, SharedSliceBin, StackBin, VecBin,
 };
 
 /// Default implementation used to create `Bin`. See `BinFactory` for documentation.
@@ -21,6 +24,59 @@ impl NewBin {
     pub fn builder<'a>() -> impl BinBuilder<'a, T = Bin> {
         DefaultBinBuilder::<NewBin, BinBuilderCfg>::new()
     }
+
+    /// Decodes the given hex string (upper- and lowercase digits, no separators). The output is
+    /// sized exactly (small binaries are stored on the stack).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, AnyBin, DecodeError};
+    /// assert_eq!(&[0xCA, 0xFE], NewBin::from_hex("caFE").unwrap().as_slice());
+    /// assert_eq!(
+    ///     DecodeError::InvalidByte { offset: 2, byte: b'x' },
+    ///     NewBin::from_hex("cax0").unwrap_err()
+    /// );
+    /// ```
+    pub fn from_hex(input: impl AsRef<[u8]>) -> Result<Bin, DecodeError> {
+        decode_hex::<Self>(input.as_ref())
+    }
+
+    /// Decodes the given Base64 string (see `Base64Variant`; the input must match the variant
+    /// exactly, including padding). The output is sized exactly.
+    ///
+    /// ```rust
+    /// use abin::{NewBin, AnyBin, Base64Variant};
+    /// let bin = NewBin::from_base64("SGVsbG8=", Base64Variant::Standard).unwrap();
+    /// assert_eq!(b"Hello", bin.as_slice());
+    /// assert!(NewBin::from_base64("SGVsbG8", Base64Variant::Standard).is_err());
+    /// ```
+    pub fn from_base64(
+        input: impl AsRef<[u8]>,
+        variant: Base64Variant,
+    ) -> Result<Bin, DecodeError> {
+        decode_base64::<Self>(input.as_ref(), variant)
+    }
+
+    /// Adopts the given `Arc` without copying: clones and slices share the `Arc`. Small
+    /// binaries are stored on the stack.
+    pub fn from_arc(arc: Arc<[u8]>) -> Bin {
+        SharedSliceBin::from_arc(arc).un_sync()
+    }
+
+    /// Adopts the given `Rc` without copying: clones and slices share the `Rc`. Small
+    /// binaries are stored on the stack.
+    pub fn from_rc(rc: Rc<[u8]>) -> Bin {
+        SharedSliceBin::from_rc(rc)
+    }
+
+    /// Adopts the given boxed slice without copying (it's backed by a vector, see
+    /// `from_given_vec`). Small binaries are stored on the stack.
+    pub fn from_boxed(boxed: Box<[u8]>) -> Bin {
+        if let Some(stack) = StackBin::try_from(&boxed) {
+            stack.un_sync()
+        } else {
+            VecBin::from_vec(boxed.into_vec(), false).un_sync()
+        }
+    }
 }
 
 impl BooToOwned<[u8], Bin> for NewBin {
@@ -29,6 +85,24 @@ impl BooToOwned<[u8], Bin> for NewBin {
     }
 }
 
+impl From<Arc<[u8]>> for Bin {
+    fn from(arc: Arc<[u8]>) -> Self {
+        NewBin::from_arc(arc)
+    }
+}
+
+impl From<Rc<[u8]>> for Bin {
+    fn from(rc: Rc<[u8]>) -> Self {
+        NewBin::from_rc(rc)
+    }
+}
+
+impl From<Box<[u8]>> for Bin {
+    fn from(boxed: Box<[u8]>) -> Self {
+        NewBin::from_boxed(boxed)
+    }
+}
+
 struct BinBuilderCfg;
 
 impl BuilderCfg<Bin> for BinBuilderCfg {
@@ -1,7 +1,10 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 
+use crate::implementation::{decode_base64, decode_hex};
 use crate::{
-    AnyRc, ArcBin, BinBuilder, BinFactory, BooToOwned, BuilderCfg, DefaultBinBuilder, SBin,
+    AnyRc, ArcBin, Base64Variant, BinBuilder, BinFactory, BooToOwned, BuilderCfg, DecodeError,
+    DefaultBinBuilder, SBin, SharedSliceBin, StackBin, VecBin,
 };
 
 /// Default implementation used to create `SBin`. See `BinFactory` for documentation.
@@ -20,6 +23,35 @@ impl NewSBin {
     pub fn builder<'a>() -> impl BinBuilder<'a, T = SBin> {
         DefaultBinBuilder::<NewSBin, BinBuilderCfg>::new()
     }
+
+    /// Decodes the given hex string; see `NewBin::from_hex`.
+    pub fn from_hex(input: impl AsRef<[u8]>) -> Result<SBin, DecodeError> {
+        decode_hex::<Self>(input.as_ref())
+    }
+
+    /// Decodes the given Base64 string; see `NewBin::from_base64`.
+    pub fn from_base64(
+        input: impl AsRef<[u8]>,
+        variant: Base64Variant,
+    ) -> Result<SBin, DecodeError> {
+        decode_base64::<Self>(input.as_ref(), variant)
+    }
+
+    /// Adopts the given `Arc` without copying: clones and slices share the `Arc`. Small
+    /// binaries are stored on the stack.
+    pub fn from_arc(arc: Arc<[u8]>) -> SBin {
+        SharedSliceBin::from_arc(arc)
+    }
+
+    /// Adopts the given boxed slice without copying (it's backed by a vector, see
+    /// `from_given_vec`). Small binaries are stored on the stack.
+    pub fn from_boxed(boxed: Box<[u8]>) -> SBin {
+        if let Some(stack) = StackBin::try_from(&boxed) {
+            stack
+        } else {
+            VecBin::from_vec(boxed.into_vec(), true)
+        }
+    }
 }
 
 impl BooToOwned<[u8], SBin> for NewSBin {
@@ -28,6 +60,18 @@ impl BooToOwned<[u8], SBin> for NewSBin {
     }
 }
 
+impl From<Arc<[u8]>> for SBin {
+    fn from(arc: Arc<[u8]>) -> Self {
+        NewSBin::from_arc(arc)
+    }
+}
+
+impl From<Box<[u8]>> for SBin {
+    fn from(boxed: Box<[u8]>) -> Self {
+        NewSBin::from_boxed(boxed)
+    }
+}
+
 struct BinBuilderCfg;
 
 impl BuilderCfg<SBin> for BinBuilderCfg {
@@ -18,15 +18,15 @@ pub trait AnyRc {
     /// memory and also some operations might need to allocate later (like `to_vec`, `into_sync`).
     ///
     ///  * Note 1: The given vector is used to store the reference count - this avoids (in
-    /// most cases) another indirection and memory copy (best effort).
+    ///    most cases) another indirection and memory copy (best effort).
     ///
     ///  * Note 2: Do not manually shrink the given vector, the implementation needs the additional
-    /// capacity to store the reference count. The implementation will shrink the vector itself,
-    /// see `Self::from_with_cap_shrink` if you need to configure this behaviour.
+    ///    capacity to store the reference count. The implementation will shrink the vector itself,
+    ///    see `Self::from_with_cap_shrink` if you need to configure this behaviour.
     ///
     ///  * Note 3: If you manually create the vector using `Vec::with_capacity` make sure you
-    /// reserve additional space for the reference count to avoid a re-allocation.
-    /// See `Self::overhead_bytes`;
+    ///    reserve additional space for the reference count to avoid a re-allocation.
+    ///    See `Self::overhead_bytes`;
     ///
     fn from_vec(vec: Vec<u8>) -> Self::T;
 
@@ -1,5 +1,5 @@
 use crate::spi::{BinData, FnTable};
-use crate::{Bin, IntoUnSyncView, SBin};
+use crate::{Bin, IntoUnSyncView, SBin, StorageKind};
 
 /// A binary that's always empty.
 pub struct EmptyBin;
@@ -25,6 +25,16 @@ const FN_TABLE: FnTable = FnTable {
     convert_into_sync: None,
     // not supported.
     try_re_integrate: None,
+    try_into_unique_vec: Some(try_into_unique_vec),
+    get_mut: Some(get_mut),
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind),
+    // not reference-counted.
+    strong_count: None,
+    retained_bytes: Some(retained_bytes),
 };
 
 fn clone(_: &Bin) -> Bin {
@@ -35,6 +45,16 @@ fn into_vec(_: Bin) -> Vec<u8> {
     Vec::new()
 }
 
+fn try_into_unique_vec(_: Bin) -> Result<Vec<u8>, Bin> {
+    // an empty vector does not allocate.
+    Ok(Vec::new())
+}
+
+fn get_mut(_: &mut Bin) -> Option<&mut [u8]> {
+    // nothing to mutate (but also nothing to copy).
+    Some(&mut [])
+}
+
 fn slice(_: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
     if start == 0 && end_excluded == 0 {
         Some(EmptyBin::empty_sbin().un_sync())
@@ -42,3 +62,12 @@ fn slice(_: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
         None
     }
 }
+
+fn storage_kind(_: &Bin) -> StorageKind {
+    StorageKind::Empty
+}
+
+fn retained_bytes(_: &Bin) -> usize {
+    // no memory.
+    0
+}
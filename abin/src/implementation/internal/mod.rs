@@ -3,12 +3,13 @@ mod arc;
 mod default_given_vec_config;
 mod empty;
 mod rc;
+mod shared_slice;
 mod stack;
 mod stack_bin_builder;
 mod static_bin;
 mod vec;
 
 pub use {
-    any_rc::*, arc::*, default_given_vec_config::*, empty::*, rc::*, stack::*,
+    any_rc::*, arc::*, default_given_vec_config::*, empty::*, rc::*, shared_slice::*, stack::*,
     stack_bin_builder::*, static_bin::*, vec::*,
 };
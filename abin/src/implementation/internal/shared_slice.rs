@@ -0,0 +1,350 @@
+use core::ops::Deref;
+use core::{mem, slice};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::spi::{BinData, FnTable, UnsafeBin};
+use crate::{Bin, BinFactory, IntoUnSyncView, NewBin, NewSBin, SBin, StackBin, StorageKind};
+
+/// A binary that adopts a reference-counted slice (`Arc<[u8]>` or `Rc<[u8]>`) without copying.
+/// Clones and slices share the same pointer (the reference counter of the `Arc`/`Rc` is used).
+///
+/// Note: Since the binary data is limited to 3 words, the range of a slice is stored packed
+/// (half a word for the offset, half a word for the length of the `Arc`/`Rc`). Slices of
+/// very large `Arc`s/`Rc`s (4 GiB on 64-bit platforms, 64 KiB on 32-bit platforms) can't be
+/// packed; those slices are copied. Slicing the whole binary (and cloning) does never copy.
+pub struct SharedSliceBin;
+
+impl SharedSliceBin {
+    /// Adopts the given `Arc`. Does those steps:
+    ///
+    ///  * If it's small, returns a stack binary (copying is cheaper than keeping the `Arc`).
+    ///  * ...otherwise wraps the given `Arc`.
+    pub fn from_arc(arc: Arc<[u8]>) -> SBin {
+        if let Some(stack) = StackBin::try_from(&arc) {
+            stack
+        } else {
+            unsafe { Self::wrap::<Arc<[u8]>>(arc)._into_sync() }
+        }
+    }
+
+    /// Adopts the given `Rc`; see `from_arc`.
+    pub fn from_rc(rc: Rc<[u8]>) -> Bin {
+        if let Some(stack) = StackBin::try_from(&rc) {
+            stack.un_sync()
+        } else {
+            unsafe { Self::wrap::<Rc<[u8]>>(rc) }
+        }
+    }
+
+    unsafe fn wrap<TOwner: SliceOwner>(owner: TOwner) -> Bin {
+        let len = owner.len();
+        let raw = TOwner::into_raw(owner);
+        let data = SharedSliceData {
+            ptr: raw as *const u8,
+            len,
+            owner_range: WHOLE,
+        };
+        Bin::_new(data.to_bin_data(), TOwner::fn_table())
+    }
+}
+
+/// Abstraction over `Arc<[u8]>` and `Rc<[u8]>`.
+trait SliceOwner: Deref<Target = [u8]> + Sized {
+    fn into_raw(this: Self) -> *const [u8];
+    unsafe fn from_raw(ptr: *const [u8]) -> Self;
+    unsafe fn increment_strong_count(ptr: *const [u8]);
+    /// `true` if there are no other (strong or weak) references.
+    fn is_unique(this: &mut Self) -> bool;
+    fn strong_count(this: &Self) -> usize;
+    fn storage_kind() -> StorageKind;
+    /// Copies the given slice (used if the slice cannot be packed).
+    fn copy_from_slice(slice: &[u8]) -> Bin;
+    fn fn_table() -> &'static FnTable;
+}
+
+impl SliceOwner for Arc<[u8]> {
+    #[inline]
+    fn into_raw(this: Self) -> *const [u8] {
+        Arc::into_raw(this)
+    }
+
+    #[inline]
+    unsafe fn from_raw(ptr: *const [u8]) -> Self {
+        Arc::from_raw(ptr)
+    }
+
+    #[inline]
+    unsafe fn increment_strong_count(ptr: *const [u8]) {
+        Arc::increment_strong_count(ptr)
+    }
+
+    #[inline]
+    fn is_unique(this: &mut Self) -> bool {
+        Arc::get_mut(this).is_some()
+    }
+
+    #[inline]
+    fn strong_count(this: &Self) -> usize {
+        Arc::strong_count(this)
+    }
+
+    #[inline]
+    fn storage_kind() -> StorageKind {
+        StorageKind::Arc
+    }
+
+    fn copy_from_slice(slice: &[u8]) -> Bin {
+        // must stay synchronized.
+        NewSBin::copy_from_slice(slice).un_sync()
+    }
+
+    #[inline]
+    fn fn_table() -> &'static FnTable {
+        &FN_TABLE_SYNC
+    }
+}
+
+impl SliceOwner for Rc<[u8]> {
+    #[inline]
+    fn into_raw(this: Self) -> *const [u8] {
+        Rc::into_raw(this)
+    }
+
+    #[inline]
+    unsafe fn from_raw(ptr: *const [u8]) -> Self {
+        Rc::from_raw(ptr)
+    }
+
+    #[inline]
+    unsafe fn increment_strong_count(ptr: *const [u8]) {
+        Rc::increment_strong_count(ptr)
+    }
+
+    #[inline]
+    fn is_unique(this: &mut Self) -> bool {
+        Rc::get_mut(this).is_some()
+    }
+
+    #[inline]
+    fn strong_count(this: &Self) -> usize {
+        Rc::strong_count(this)
+    }
+
+    #[inline]
+    fn storage_kind() -> StorageKind {
+        StorageKind::Rc
+    }
+
+    fn copy_from_slice(slice: &[u8]) -> Bin {
+        NewBin::copy_from_slice(slice)
+    }
+
+    #[inline]
+    fn fn_table() -> &'static FnTable {
+        &FN_TABLE_NON_SYNC
+    }
+}
+
+/// `owner_range` if the binary is not sliced (the owner is exactly `ptr` / `len`).
+const WHOLE: usize = 0;
+const HALF_BITS: u32 = usize::BITS / 2;
+const HALF_MASK: usize = (1 << HALF_BITS) - 1;
+
+#[repr(C)]
+struct SharedSliceData {
+    /// pointer to the data; this differs from the owner's data if sliced.
+    ptr: *const u8,
+    /// the length of the data; this differs from the owner's length if sliced.
+    len: usize,
+    /// `WHOLE` if not sliced; otherwise the offset relative to the owner's data (upper half)
+    /// and the length of the owner (lower half; never 0, so this is never `WHOLE`).
+    owner_range: usize,
+}
+
+impl SharedSliceData {
+    #[inline]
+    unsafe fn from_bin(bin: &Bin) -> &Self {
+        let bin_data = bin._data() as *const BinData;
+        let self_data = bin_data as *const Self;
+        &*self_data
+    }
+
+    #[inline]
+    unsafe fn to_bin_data(&self) -> BinData {
+        mem::transmute_copy::<Self, BinData>(self)
+    }
+
+    /// The offset relative to the owner's data and the owner's length.
+    #[inline]
+    fn owner_offset_and_len(&self) -> (usize, usize) {
+        if self.owner_range == WHOLE {
+            (0, self.len)
+        } else {
+            (self.owner_range >> HALF_BITS, self.owner_range & HALF_MASK)
+        }
+    }
+
+    /// The raw `Arc`/`Rc` pointer (see `Arc::into_raw`).
+    #[inline]
+    fn owner(&self) -> *const [u8] {
+        let (offset, owner_len) = self.owner_offset_and_len();
+        let owner_ptr = unsafe { self.ptr.sub(offset) };
+        core::ptr::slice_from_raw_parts(owner_ptr, owner_len)
+    }
+
+    #[inline]
+    fn pack(offset: usize, owner_len: usize) -> Option<usize> {
+        if offset <= HALF_MASK && owner_len <= HALF_MASK {
+            Some((offset << HALF_BITS) | owner_len)
+        } else {
+            None
+        }
+    }
+}
+
+const FN_TABLE_SYNC: FnTable = FnTable {
+    drop: Some(drop::<Arc<[u8]>>),
+    as_slice: Some(as_slice),
+    is_empty: Some(is_empty),
+    clone: clone::<Arc<[u8]>>,
+    into_vec,
+    slice: slice::<Arc<[u8]>>,
+    // not required: sync only.
+    convert_into_un_sync: None,
+    // not required: already sync.
+    convert_into_sync: None,
+    try_re_integrate: Some(try_re_integrate::<Arc<[u8]>>),
+    // not supported: the memory layout of an `Arc` is not compatible with a vector.
+    try_into_unique_vec: None,
+    get_mut: Some(get_mut::<Arc<[u8]>>),
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind::<Arc<[u8]>>),
+    strong_count: Some(strong_count::<Arc<[u8]>>),
+    retained_bytes: Some(retained_bytes),
+};
+
+const FN_TABLE_NON_SYNC: FnTable = FnTable {
+    drop: Some(drop::<Rc<[u8]>>),
+    as_slice: Some(as_slice),
+    is_empty: Some(is_empty),
+    clone: clone::<Rc<[u8]>>,
+    into_vec,
+    slice: slice::<Rc<[u8]>>,
+    // not required: already un-sync.
+    convert_into_un_sync: None,
+    convert_into_sync: Some(convert_into_sync),
+    try_re_integrate: Some(try_re_integrate::<Rc<[u8]>>),
+    // not supported: the memory layout of an `Rc` is not compatible with a vector.
+    try_into_unique_vec: None,
+    get_mut: Some(get_mut::<Rc<[u8]>>),
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind::<Rc<[u8]>>),
+    strong_count: Some(strong_count::<Rc<[u8]>>),
+    retained_bytes: Some(retained_bytes),
+};
+
+fn drop<TOwner: SliceOwner>(bin: &mut Bin) {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    // decrements the reference count (and frees the memory if this is the last reference).
+    unsafe { mem::drop(TOwner::from_raw(data.owner())) };
+}
+
+#[inline]
+fn as_slice(bin: &Bin) -> &[u8] {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    unsafe { slice::from_raw_parts(data.ptr, data.len) }
+}
+
+fn is_empty(bin: &Bin) -> bool {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    data.len == 0
+}
+
+fn clone<TOwner: SliceOwner>(bin: &Bin) -> Bin {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    unsafe {
+        TOwner::increment_strong_count(data.owner());
+        Bin::_new(data.to_bin_data(), TOwner::fn_table())
+    }
+}
+
+fn into_vec(bin: Bin) -> Vec<u8> {
+    // the only option is to copy (the memory layout is not compatible with a vector).
+    as_slice(&bin).to_vec()
+}
+
+fn get_mut<TOwner: SliceOwner>(bin: &mut Bin) -> Option<&mut [u8]> {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    // we don't own this one (must not be dropped).
+    let mut owner = mem::ManuallyDrop::new(unsafe { TOwner::from_raw(data.owner()) });
+    if TOwner::is_unique(&mut owner) {
+        let ptr = data.ptr as *mut u8;
+        Some(unsafe { slice::from_raw_parts_mut(ptr, data.len) })
+    } else {
+        None
+    }
+}
+
+fn slice<TOwner: SliceOwner>(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    let new_slice = as_slice(bin).get(start..end_excluded)?;
+    if start == 0 && end_excluded == data.len {
+        // this is myself
+        Some(clone::<TOwner>(bin))
+    } else if new_slice.is_empty() {
+        Some(TOwner::copy_from_slice(&[]))
+    } else {
+        let (offset, owner_len) = data.owner_offset_and_len();
+        if let Some(owner_range) = SharedSliceData::pack(offset + start, owner_len) {
+            unsafe { TOwner::increment_strong_count(data.owner()) };
+            let new_data = SharedSliceData {
+                ptr: new_slice.as_ptr(),
+                len: new_slice.len(),
+                owner_range,
+            };
+            Some(unsafe { Bin::_new(new_data.to_bin_data(), TOwner::fn_table()) })
+        } else {
+            // too large: cannot be packed.
+            Some(TOwner::copy_from_slice(new_slice))
+        }
+    }
+}
+
+fn try_re_integrate<TOwner: SliceOwner>(bin: &Bin, slice_in: &[u8]) -> Option<Bin> {
+    let self_slice = as_slice(bin);
+    let start = (slice_in.as_ptr() as usize).checked_sub(self_slice.as_ptr() as usize);
+    if let Some(start) = start {
+        slice::<TOwner>(bin, start, start + slice_in.len())
+    } else {
+        None
+    }
+}
+
+fn convert_into_sync(bin: Bin) -> Bin {
+    // `Rc` cannot be synchronized: copy.
+    NewSBin::copy_from_slice(as_slice(&bin)).un_sync()
+}
+
+fn storage_kind<TOwner: SliceOwner>(_: &Bin) -> StorageKind {
+    TOwner::storage_kind()
+}
+
+fn strong_count<TOwner: SliceOwner>(bin: &Bin) -> usize {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    // we don't own this one (must not be dropped).
+    let owner = mem::ManuallyDrop::new(unsafe { TOwner::from_raw(data.owner()) });
+    TOwner::strong_count(&owner)
+}
+
+fn retained_bytes(bin: &Bin) -> usize {
+    let data = unsafe { SharedSliceData::from_bin(bin) };
+    let (_, owner_len) = data.owner_offset_and_len();
+    owner_len
+}
@@ -1,7 +1,7 @@
 use core::slice;
 
 use crate::spi::{BinData, FnTable, UnsafeBin};
-use crate::{AnyBin, Bin, SBin};
+use crate::{AnyBin, Bin, SBin, StorageKind};
 use crate::{EmptyBin, IntoUnSyncView};
 
 /// the number of bytes we can store + 1 (since one byte is required for the length information).
@@ -75,6 +75,17 @@ const FN_TABLE: FnTable = FnTable {
     convert_into_sync: None,
     // not supported.
     try_re_integrate: None,
+    // not supported: there's no buffer (it's on the stack).
+    try_into_unique_vec: None,
+    get_mut: Some(get_mut),
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind),
+    // not reference-counted.
+    strong_count: None,
+    retained_bytes: Some(retained_bytes),
 };
 
 #[inline]
@@ -95,6 +106,16 @@ fn is_empty(bin: &Bin) -> bool {
     len == 0
 }
 
+fn get_mut(bin: &mut Bin) -> Option<&mut [u8]> {
+    // the content is stored inside the binary itself, so it's never shared.
+    unsafe {
+        let data = data_raw_mut(bin._data_mut());
+        let len: u8 = *data.add(LENGTH_OFFSET);
+        let len = len as usize;
+        Some(slice::from_raw_parts_mut(data, len))
+    }
+}
+
 fn clone(bin: &Bin) -> Bin {
     let data = unsafe { bin._data() };
     unsafe { Bin::_new(*data, &FN_TABLE) }
@@ -106,17 +127,22 @@ fn into_vec(bin: Bin) -> Vec<u8> {
 
 fn slice(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
     let new_slice = bin.as_slice().get(start..end_excluded);
-    if let Some(new_slice) = new_slice {
-        Some(
-            StackBin::try_from(new_slice)
-                .expect(
-                    "There's an implementation error: Was \
+    new_slice.map(|new_slice| {
+        StackBin::try_from(new_slice)
+            .expect(
+                "There's an implementation error: Was \
         unable to slice a stack bin and re-create a stack bin from that slice (this MUST never \
         fail, since the slice is never longer than the original).",
-                )
-                .un_sync(),
-        )
-    } else {
-        None
-    }
+            )
+            .un_sync()
+    })
+}
+
+fn storage_kind(_: &Bin) -> StorageKind {
+    StorageKind::Stack
+}
+
+fn retained_bytes(_: &Bin) -> usize {
+    // no heap memory (it's stored inside the binary itself).
+    0
 }
@@ -42,7 +42,7 @@ impl StackBinBuilder {
                     // ok, still enough for the stack
                     let start_index = *len;
                     let end_index = start_index + other.len();
-                    (&mut array[start_index..end_index]).copy_from_slice(other);
+                    array[start_index..end_index].copy_from_slice(other);
                     *len = resulting_len;
                 }
             }
@@ -63,7 +63,7 @@ impl StackBinBuilder {
                     // ok, still enough for the stack
                     let start_index = *len;
                     let end_index = start_index + other.len();
-                    (&mut array[start_index..end_index]).copy_from_slice(other);
+                    array[start_index..end_index].copy_from_slice(other);
                     *len = resulting_len;
                     true
                 }
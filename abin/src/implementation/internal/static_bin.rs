@@ -2,7 +2,7 @@ use core::slice;
 use std::mem;
 
 use crate::spi::{BinData, FnTable, UnsafeBin};
-use crate::{Bin, EmptyBin, IntoUnSyncView, SBin};
+use crate::{Bin, EmptyBin, IntoUnSyncView, SBin, StorageKind};
 
 /// A binary from a static slice.
 pub struct StaticBin;
@@ -64,6 +64,18 @@ const FN_TABLE: FnTable = FnTable {
     // not required: sync only.
     convert_into_sync: None,
     try_re_integrate: Some(try_re_integrate),
+    // not supported: the buffer is not owned.
+    try_into_unique_vec: None,
+    // not supported: the memory is not owned (and immutable).
+    get_mut: None,
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind),
+    // not reference-counted.
+    strong_count: None,
+    retained_bytes: Some(retained_bytes),
 };
 
 #[inline]
@@ -93,11 +105,7 @@ fn into_vec(bin: Bin) -> Vec<u8> {
 fn slice(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
     let self_slice = as_slice(bin);
     let new_slice = self_slice.get(start..end_excluded);
-    if let Some(new_slice) = new_slice {
-        Some(StaticBin::from(new_slice).un_sync())
-    } else {
-        None
-    }
+    new_slice.map(|new_slice| StaticBin::from(new_slice).un_sync())
 }
 
 fn try_re_integrate(bin: &Bin, slice: &[u8]) -> Option<Bin> {
@@ -105,12 +113,17 @@ fn try_re_integrate(bin: &Bin, slice: &[u8]) -> Option<Bin> {
     let start = (slice.as_ptr() as usize).checked_sub(self_slice.as_ptr() as usize);
     if let Some(start) = start {
         let new_slice = self_slice.get(start..(start + slice.len()));
-        if let Some(new_slice) = new_slice {
-            Some(StaticBin::from(new_slice).un_sync())
-        } else {
-            None
-        }
+        new_slice.map(|new_slice| StaticBin::from(new_slice).un_sync())
     } else {
         None
     }
 }
+
+fn storage_kind(_: &Bin) -> StorageKind {
+    StorageKind::Static
+}
+
+fn retained_bytes(_: &Bin) -> usize {
+    // the static memory is not owned.
+    0
+}
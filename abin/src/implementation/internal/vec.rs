@@ -1,7 +1,7 @@
 use core::{mem, slice};
 
 use crate::spi::{BinData, FnTable, UnsafeBin};
-use crate::{Bin, BinFactory, IntoUnSyncView, NewBin, NewSBin, SBin};
+use crate::{AnyBin, Bin, BinFactory, IntoUnSyncView, NewBin, NewSBin, SBin, StorageKind};
 
 /// A binary that is backed by a `Vec<u8>`. Note: It's not reference-counted:
 /// If you clone it or slice it, it will be converted to a reference-counted version.
@@ -63,6 +63,15 @@ const FN_TABLE_SYNC: FnTable = FnTable {
     convert_into_sync: None,
     // not supported
     try_re_integrate: None,
+    try_into_unique_vec: Some(try_into_unique_vec),
+    get_mut: Some(get_mut),
+    split_to: Some(split_to_sync),
+    split_off: Some(split_off_sync),
+    advance: Some(advance_sync),
+    storage_kind: Some(storage_kind),
+    // not reference-counted.
+    strong_count: None,
+    retained_bytes: Some(retained_bytes),
 };
 
 const FN_TABLE_NON_SYNC: FnTable = FnTable {
@@ -77,6 +86,15 @@ const FN_TABLE_NON_SYNC: FnTable = FnTable {
     convert_into_sync: None,
     // not supported
     try_re_integrate: None,
+    try_into_unique_vec: Some(try_into_unique_vec),
+    get_mut: Some(get_mut),
+    split_to: Some(split_to_non_sync),
+    split_off: Some(split_off_non_sync),
+    advance: Some(advance_non_sync),
+    storage_kind: Some(storage_kind),
+    // not reference-counted.
+    strong_count: None,
+    retained_bytes: Some(retained_bytes),
 };
 
 fn drop(bin: &mut Bin) {
@@ -133,17 +151,26 @@ fn into_vec(bin: Bin) -> Vec<u8> {
     unsafe { Vec::<u8>::from_raw_parts(ptr, len, capacity) }
 }
 
+fn try_into_unique_vec(bin: Bin) -> Result<Vec<u8>, Bin> {
+    // the vector is never shared.
+    Ok(into_vec(bin))
+}
+
+fn get_mut(bin: &mut Bin) -> Option<&mut [u8]> {
+    // the vector is never shared.
+    let vec_data = unsafe { VecData::from_bin(bin) };
+    let ptr = vec_data.ptr as *mut u8;
+    let len = vec_data.len;
+    Some(unsafe { slice::from_raw_parts_mut(ptr, len) })
+}
+
 fn slice_sync(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
     if start == 0 && end_excluded == len(bin) {
         // this is myself
         Some(bin.clone())
     } else {
         let slice = as_slice(bin).get(start..end_excluded);
-        if let Some(slice) = slice {
-            Some(NewSBin::copy_from_slice(slice).un_sync())
-        } else {
-            None
-        }
+        slice.map(|slice| NewSBin::copy_from_slice(slice).un_sync())
     }
 }
 
@@ -153,10 +180,83 @@ fn slice_non_sync(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
         Some(bin.clone())
     } else {
         let slice = as_slice(bin).get(start..end_excluded);
-        if let Some(slice) = slice {
-            Some(NewBin::copy_from_slice(slice))
-        } else {
-            None
-        }
+        slice.map(NewBin::copy_from_slice)
+    }
+}
+
+/// Splitting (and advancing) would require a copy for each operation, since the vector is not
+/// reference-counted. So we convert it once to a reference-counted binary (this does not copy
+/// if the vector has enough excess capacity); all further operations are cheap.
+fn convert_into_rc_sync(bin: &mut Bin) {
+    let vec_bin = mem::replace(bin, NewBin::empty());
+    *bin = NewSBin::from_given_vec(into_vec(vec_bin)).un_sync();
+}
+
+/// See `convert_into_rc_sync`.
+fn convert_into_rc_non_sync(bin: &mut Bin) {
+    let vec_bin = mem::replace(bin, NewBin::empty());
+    *bin = NewBin::from_given_vec(into_vec(vec_bin));
+}
+
+fn split_to_sync(bin: &mut Bin, at: usize) -> Option<Bin> {
+    if at > len(bin) {
+        None
+    } else {
+        convert_into_rc_sync(bin);
+        bin.split_to(at)
+    }
+}
+
+fn split_to_non_sync(bin: &mut Bin, at: usize) -> Option<Bin> {
+    if at > len(bin) {
+        None
+    } else {
+        convert_into_rc_non_sync(bin);
+        bin.split_to(at)
     }
 }
+
+fn split_off_sync(bin: &mut Bin, at: usize) -> Option<Bin> {
+    if at > len(bin) {
+        None
+    } else {
+        convert_into_rc_sync(bin);
+        bin.split_off(at)
+    }
+}
+
+fn split_off_non_sync(bin: &mut Bin, at: usize) -> Option<Bin> {
+    if at > len(bin) {
+        None
+    } else {
+        convert_into_rc_non_sync(bin);
+        bin.split_off(at)
+    }
+}
+
+fn advance_sync(bin: &mut Bin, cnt: usize) -> bool {
+    if cnt > len(bin) {
+        false
+    } else {
+        convert_into_rc_sync(bin);
+        bin.advance(cnt)
+    }
+}
+
+fn advance_non_sync(bin: &mut Bin, cnt: usize) -> bool {
+    if cnt > len(bin) {
+        false
+    } else {
+        convert_into_rc_non_sync(bin);
+        bin.advance(cnt)
+    }
+}
+
+fn storage_kind(_: &Bin) -> StorageKind {
+    StorageKind::Vec
+}
+
+fn retained_bytes(bin: &Bin) -> usize {
+    let vec_data = unsafe { VecData::from_bin(bin) };
+    vec_data.capacity
+}
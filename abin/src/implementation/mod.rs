@@ -1,3 +1,6 @@
+#[cfg(any(test, feature = "serde"))]
+pub(crate) use factory_common::CommonFactory;
+pub(crate) use factory_common::{build_exact, decode_base64, decode_hex};
 pub(crate) use {default_builder::*, internal::*, reference_counted::*};
 pub use {factory_new::*, factory_s_new::*, str_factory::*};
 
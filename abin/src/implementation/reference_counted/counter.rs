@@ -1,5 +1,5 @@
 use core::sync::atomic;
-use std::sync::atomic::Ordering::{Relaxed, Release};
+use std::sync::atomic::Ordering::{Acquire, Relaxed, Release};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub trait RcCounter {
@@ -7,6 +7,11 @@ pub trait RcCounter {
     fn new() -> Self;
     fn decrement(&mut self) -> RcDecResult;
     fn increment(&mut self);
+    /// `true` if there's just one single reference (the next call to `decrement` would
+    /// return `Zero`).
+    fn is_unique(&self) -> bool;
+    /// The number of references (this is at least 1).
+    fn strong_count(&self) -> usize;
 }
 
 #[derive(Copy, Clone)]
@@ -55,6 +60,16 @@ impl RcCounter for NsRcCounter {
         }
         self.0 = current + 1;
     }
+
+    #[inline]
+    fn is_unique(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    fn strong_count(&self) -> usize {
+        self.0 + 1
+    }
 }
 
 /// a synchronized reference counter.
@@ -86,10 +101,23 @@ impl RcCounter for SyncRcCounter {
     fn increment(&mut self) {
         // 'Relaxed' seems to be ok according to the sources from "Arc" (rust std lib).
         let previous_value = self.0.fetch_add(1, Relaxed);
-        if previous_value == core::usize::MAX {
+        if previous_value == usize::MAX {
             // we need to revert that
             self.0.fetch_sub(1, Release);
             panic!("Too many reference counts or implementation error (too many rc decrements).")
         }
     }
+
+    #[inline]
+    fn is_unique(&self) -> bool {
+        // 'Acquire' is required: if another thread just dropped its reference, we have to see
+        // all its writes before we take ownership of the data.
+        self.0.load(Acquire) == 0
+    }
+
+    #[inline]
+    fn strong_count(&self) -> usize {
+        // this is just informational (like `Arc::strong_count`).
+        self.0.load(Relaxed) + 1
+    }
 }
@@ -1,4 +1,4 @@
-use core::{mem, slice};
+use core::{mem, ptr, slice};
 
 use crate::spi::{BinData, UnsafeBin};
 use crate::{Bin, DefaultExcessShrink, RcCounter, RcDecResult, RcMeta, RcUtils};
@@ -22,17 +22,6 @@ impl<TCounter: RcCounter> RcData<TCounter> {
         &*self_data
     }
 
-    /// This is highly unsafe: converts a reference into a mutable reference. This is used for
-    /// example for clone, where we get `clone(&self)` but we actually need `clone(&mut self)` to
-    /// be able to mutate the reference counter.
-    #[allow(clippy::mut_from_ref)]
-    #[inline]
-    pub unsafe fn from_bin_mut_cast(bin: &Bin) -> &mut Self {
-        let bin_data = bin._data() as *const BinData as *mut BinData;
-        let self_data = bin_data as *mut Self;
-        &mut *self_data
-    }
-
     #[inline]
     pub unsafe fn from_bin_mut(bin: &mut Bin) -> &mut Self {
         let bin_data = bin._data_mut() as *mut BinData;
@@ -80,8 +69,12 @@ impl<TCounter: RcCounter> RcData<TCounter> {
         unsafe { &*meta_ptr }
     }
 
+    /// Note: The metadata is not part of `self` (it's stored in-line in the vector), so it's
+    /// ok to hand out a mutable reference from `&self`. This is required for `clone` and `slice`
+    /// where we only get `&Bin` but have to mutate the reference counter.
+    #[allow(clippy::mut_from_ref)]
     #[inline]
-    fn rc_meta_mut(&mut self) -> &mut RcMeta<TCounter> {
+    fn rc_meta_mut(&self) -> &mut RcMeta<TCounter> {
         let meta_ptr = self.meta_ptr as *mut RcMeta<TCounter>;
         unsafe { &mut *meta_ptr }
     }
@@ -104,7 +97,7 @@ impl<TCounter: RcCounter> RcData<TCounter> {
     }
 
     #[inline]
-    pub(crate) fn clone(&mut self) -> Self {
+    pub(crate) fn clone(&self) -> Self {
         self.rc_meta_mut().counter.increment();
         Self {
             data_ptr: self.data_ptr,
@@ -163,9 +156,53 @@ impl<TCounter: RcCounter> RcData<TCounter> {
         }
     }
 
+    /// Converts `&mut self` into a vec if there's just one single reference (this is the case
+    /// if the returned value is `Some`; in this case the `Bin` must not be dropped). Does
+    /// never allocate. If this is a slice with a start offset, the bytes are moved to the start
+    /// of the buffer. The returned vector still has the excess capacity for the metadata.
+    #[inline]
+    pub(crate) fn mut_self_try_into_unique_vec(&mut self) -> Option<Vec<u8>> {
+        let meta = self.rc_meta();
+        if meta.counter.is_unique() {
+            let vec_ptr = meta.vec_ptr as *mut u8;
+            if !ptr::eq(self.data_ptr, vec_ptr) {
+                // it's a slice with an offset: move the data to the start of the buffer (the
+                // two regions might overlap).
+                unsafe { ptr::copy(self.data_ptr, vec_ptr, self.data_len) };
+            }
+            Some(unsafe { meta.extract_vec(self.data_len) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable slice if there's just one single reference. Does never allocate.
+    #[inline]
+    pub(crate) fn get_mut(&mut self) -> Option<&mut [u8]> {
+        if self.rc_meta().counter.is_unique() {
+            let ptr = self.data_ptr as *mut u8;
+            let len = self.data_len;
+            Some(unsafe { slice::from_raw_parts_mut(ptr, len) })
+        } else {
+            None
+        }
+    }
+
+    /// The number of references to the buffer.
+    #[inline]
+    pub(crate) fn strong_count(&self) -> usize {
+        self.rc_meta().counter.strong_count()
+    }
+
+    /// The capacity of the whole buffer (this is kept alive, even if this is a small slice).
+    #[inline]
+    pub(crate) fn retained_bytes(&self) -> usize {
+        self.rc_meta().capacity
+    }
+
     /// It's basically the same as clone with some adjustments.
     #[inline]
-    pub(crate) fn slice(&mut self, start: usize, end_excluded: usize) -> Option<Self> {
+    pub(crate) fn slice(&self, start: usize, end_excluded: usize) -> Option<Self> {
         if self.as_slice().get(start..end_excluded).is_some() {
             // ok, within range
             let mut clone = self.clone();
@@ -178,4 +215,33 @@ impl<TCounter: RcCounter> RcData<TCounter> {
             None
         }
     }
+
+    /// Afterwards `self` contains `[at, len)`; returns `[0, at)`. See `FnTable::split_to`.
+    #[inline]
+    pub(crate) fn split_to(&mut self, at: usize) -> Option<Self> {
+        let head = self.slice(0, at)?;
+        self.data_ptr = unsafe { self.data_ptr.add(at) };
+        self.data_len -= at;
+        Some(head)
+    }
+
+    /// Afterwards `self` contains `[0, at)`; returns `[at, len)`. See `FnTable::split_off`.
+    #[inline]
+    pub(crate) fn split_off(&mut self, at: usize) -> Option<Self> {
+        let tail = self.slice(at, self.data_len)?;
+        self.data_len = at;
+        Some(tail)
+    }
+
+    /// Removes the first `cnt` bytes; does not touch the reference counter.
+    #[inline]
+    pub(crate) fn advance(&mut self, cnt: usize) -> bool {
+        if cnt <= self.data_len {
+            self.data_ptr = unsafe { self.data_ptr.add(cnt) };
+            self.data_len -= cnt;
+            true
+        } else {
+            false
+        }
+    }
 }
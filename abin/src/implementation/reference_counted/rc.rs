@@ -2,7 +2,7 @@ use core::mem;
 use std::marker::PhantomData;
 
 use crate::spi::{FnTable, UnsafeBin};
-use crate::{Bin, NsRcCounter, RcCounter, RcData, RcUtils, SyncRcCounter};
+use crate::{Bin, NsRcCounter, RcCounter, RcData, RcUtils, StorageKind, SyncRcCounter};
 
 pub struct AnyRcImpl<TConfig: AnyRcImplConfig> {
     _phantom: PhantomData<TConfig>,
@@ -73,6 +73,14 @@ const NON_SYNC_FN_TABLE: FnTable = FnTable {
     // required. Since this version is not sync.
     convert_into_sync: Some(convert_into_sync),
     try_re_integrate: Some(try_re_integrate::<NsRcCounter>),
+    try_into_unique_vec: Some(try_into_unique_vec::<NsRcCounter>),
+    get_mut: Some(get_mut::<NsRcCounter>),
+    split_to: Some(split_to::<NsRcCounter>),
+    split_off: Some(split_off::<NsRcCounter>),
+    advance: Some(advance::<NsRcCounter>),
+    storage_kind: Some(storage_kind_rc),
+    strong_count: Some(strong_count::<NsRcCounter>),
+    retained_bytes: Some(retained_bytes::<NsRcCounter>),
 };
 
 const SYNC_FN_TABLE: FnTable = FnTable {
@@ -87,6 +95,14 @@ const SYNC_FN_TABLE: FnTable = FnTable {
     // not required, it's already sync
     convert_into_sync: None,
     try_re_integrate: Some(try_re_integrate::<SyncRcCounter>),
+    try_into_unique_vec: Some(try_into_unique_vec::<SyncRcCounter>),
+    get_mut: Some(get_mut::<SyncRcCounter>),
+    split_to: Some(split_to::<SyncRcCounter>),
+    split_off: Some(split_off::<SyncRcCounter>),
+    advance: Some(advance::<SyncRcCounter>),
+    storage_kind: Some(storage_kind_arc),
+    strong_count: Some(strong_count::<SyncRcCounter>),
+    retained_bytes: Some(retained_bytes::<SyncRcCounter>),
 };
 
 fn drop<TCounter: RcCounter>(bin: &mut Bin) {
@@ -106,7 +122,7 @@ fn is_empty<TCounter: RcCounter>(bin: &Bin) -> bool {
 }
 
 fn clone<TCounter: RcCounter>(bin: &Bin) -> Bin {
-    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut_cast(bin) };
+    let rc_data = unsafe { RcData::<TCounter>::from_bin(bin) };
     let rc_data = rc_data.clone();
     unsafe { Bin::_new(rc_data.into_bin_data(), bin._fn_table()) }
 }
@@ -119,16 +135,69 @@ fn into_vec<TCounter: RcCounter>(mut bin: Bin) -> Vec<u8> {
     vec
 }
 
-#[inline]
-fn slice<TCounter: RcCounter>(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
-    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut_cast(bin) };
-    if let Some(rc_data) = rc_data.slice(start, end_excluded) {
-        Some(unsafe { Bin::_new(rc_data.into_bin_data(), bin._fn_table()) })
+fn try_into_unique_vec<TCounter: RcCounter>(mut bin: Bin) -> Result<Vec<u8>, Bin> {
+    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut(&mut bin) };
+    if let Some(vec) = rc_data.mut_self_try_into_unique_vec() {
+        // bin must not be dropped (the vector now owns the buffer)
+        mem::forget(bin);
+        Ok(vec)
     } else {
-        None
+        Err(bin)
     }
 }
 
+fn get_mut<TCounter: RcCounter + 'static>(bin: &mut Bin) -> Option<&mut [u8]> {
+    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut(bin) };
+    rc_data.get_mut()
+}
+
+#[inline]
+fn slice<TCounter: RcCounter>(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
+    let rc_data = unsafe { RcData::<TCounter>::from_bin(bin) };
+    rc_data
+        .slice(start, end_excluded)
+        .map(|rc_data| unsafe { Bin::_new(rc_data.into_bin_data(), bin._fn_table()) })
+}
+
+fn split_to<TCounter: RcCounter + 'static>(bin: &mut Bin, at: usize) -> Option<Bin> {
+    let fn_table = unsafe { bin._fn_table() };
+    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut(bin) };
+    rc_data
+        .split_to(at)
+        .map(|rc_data| unsafe { Bin::_new(rc_data.into_bin_data(), fn_table) })
+}
+
+fn split_off<TCounter: RcCounter + 'static>(bin: &mut Bin, at: usize) -> Option<Bin> {
+    let fn_table = unsafe { bin._fn_table() };
+    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut(bin) };
+    rc_data
+        .split_off(at)
+        .map(|rc_data| unsafe { Bin::_new(rc_data.into_bin_data(), fn_table) })
+}
+
+fn advance<TCounter: RcCounter + 'static>(bin: &mut Bin, cnt: usize) -> bool {
+    let rc_data = unsafe { RcData::<TCounter>::from_bin_mut(bin) };
+    rc_data.advance(cnt)
+}
+
+fn storage_kind_rc(_: &Bin) -> StorageKind {
+    StorageKind::Rc
+}
+
+fn storage_kind_arc(_: &Bin) -> StorageKind {
+    StorageKind::Arc
+}
+
+fn strong_count<TCounter: RcCounter + 'static>(bin: &Bin) -> usize {
+    let rc_data = unsafe { RcData::<TCounter>::from_bin(bin) };
+    rc_data.strong_count()
+}
+
+fn retained_bytes<TCounter: RcCounter + 'static>(bin: &Bin) -> usize {
+    let rc_data = unsafe { RcData::<TCounter>::from_bin(bin) };
+    rc_data.retained_bytes()
+}
+
 fn convert_into_sync(bin: Bin) -> Bin {
     // extract the vector.
     let vec = into_vec::<NsRcCounter>(bin);
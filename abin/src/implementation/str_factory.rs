@@ -1,7 +1,14 @@
+use std::char::REPLACEMENT_CHARACTER;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::str;
+use std::sync::Arc;
+
 use crate::{
-    Bin, BooToOwned, DefaultStrBuilder, NewBin, NewSBin, SBin, SStr, Str, StrBuilder, StrFactory,
+    AnyBin, AnyRc, AnyStr, ArcBin, Bin, BooToOwned, DefaultStrBuilder, NewBin, NewSBin, RcBin,
+    SBin, SStr, StackBin, Str, StrBuilder, StrFactory, StrSegment,
 };
-use std::marker::PhantomData;
 
 /// Default implementation used to create `Str`. See `StrFactory` for documentation.
 ///
@@ -20,6 +27,46 @@ impl NewStr {
     pub fn builder<'a>() -> impl StrBuilder<'a, T = Bin> {
         DefaultStrBuilder::new(NewBin::builder())
     }
+
+    /// Creates a string from format arguments; see `format_str!`.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Str {
+        from_fmt(args, Self::builder(), RcBin::overhead_bytes())
+    }
+
+    /// Converts the binary to a string, replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER` (like `String::from_utf8_lossy`). If the binary is valid
+    /// UTF-8, it's returned as string without copying; otherwise a new string is built (valid
+    /// parts still share the buffer with the binary if possible).
+    ///
+    /// ```rust
+    /// use abin::{NewBin, BinFactory, NewStr};
+    /// let string = NewStr::from_utf8_lossy(NewBin::from_static(b"Hello \xF0\x90\x80World"));
+    /// assert_eq!("Hello \u{FFFD}World", string.as_str());
+    /// ```
+    pub fn from_utf8_lossy(bin: Bin) -> Str {
+        from_utf8_lossy(bin, &mut Self::builder())
+    }
+
+    /// Adopts the given `Arc` without copying; see `NewBin::from_arc`.
+    pub fn from_arc(arc: Arc<str>) -> Str {
+        let bin = NewBin::from_arc(Arc::from(arc));
+        // it's valid UTF-8: it comes from a `str`.
+        unsafe { Str::from_utf8_unchecked(bin) }
+    }
+
+    /// Adopts the given `Rc` without copying; see `NewBin::from_rc`.
+    pub fn from_rc(rc: Rc<str>) -> Str {
+        let bin = NewBin::from_rc(Rc::from(rc));
+        // it's valid UTF-8: it comes from a `str`.
+        unsafe { Str::from_utf8_unchecked(bin) }
+    }
+
+    /// Adopts the given boxed string without copying; see `NewBin::from_boxed`.
+    pub fn from_boxed(boxed: Box<str>) -> Str {
+        let bin = NewBin::from_boxed(boxed.into_boxed_bytes());
+        // it's valid UTF-8: it comes from a `str`.
+        unsafe { Str::from_utf8_unchecked(bin) }
+    }
 }
 
 impl StrFactory for NewStr {
@@ -49,6 +96,31 @@ impl NewSStr {
     pub fn builder<'a>() -> impl StrBuilder<'a, T = SBin> {
         DefaultStrBuilder::new(NewSBin::builder())
     }
+
+    /// Creates a string from format arguments; see `format_sstr!`.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> SStr {
+        from_fmt(args, Self::builder(), ArcBin::overhead_bytes())
+    }
+
+    /// Converts the binary to a string, replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`; see `NewStr::from_utf8_lossy`.
+    pub fn from_utf8_lossy(bin: SBin) -> SStr {
+        from_utf8_lossy(bin, &mut Self::builder())
+    }
+
+    /// Adopts the given `Arc` without copying; see `NewSBin::from_arc`.
+    pub fn from_arc(arc: Arc<str>) -> SStr {
+        let bin = NewSBin::from_arc(Arc::from(arc));
+        // it's valid UTF-8: it comes from a `str`.
+        unsafe { SStr::from_utf8_unchecked(bin) }
+    }
+
+    /// Adopts the given boxed string without copying; see `NewSBin::from_boxed`.
+    pub fn from_boxed(boxed: Box<str>) -> SStr {
+        let bin = NewSBin::from_boxed(boxed.into_boxed_bytes());
+        // it's valid UTF-8: it comes from a `str`.
+        unsafe { SStr::from_utf8_unchecked(bin) }
+    }
 }
 
 impl StrFactory for NewSStr {
@@ -60,3 +132,178 @@ impl BooToOwned<str, SStr> for NewSStr {
         Self::copy_from_str(borrowed)
     }
 }
+
+/// Formats the arguments (see `format_str!`): Without arguments, the string is pushed as static
+/// segment. Otherwise the arguments are formatted once: Small strings stay on the stack; larger
+/// strings are written to a string that's adopted by the builder (see `FmtWriter`).
+fn from_fmt<'a, TBuilder>(
+    args: fmt::Arguments<'_>,
+    mut builder: TBuilder,
+    overhead_bytes: usize,
+) -> AnyStr<TBuilder::T>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    if let Some(string) = args.as_str() {
+        builder.push(StrSegment::Static(string));
+        return builder.build();
+    }
+    let mut writer = FmtWriter::new(overhead_bytes);
+    fmt::write(&mut writer, args).expect("A formatting trait implementation returned an error.");
+    match writer.string {
+        Some(string) => builder.push(StrSegment::GivenString(string)),
+        None => {
+            let string = str::from_utf8(&writer.stack[..writer.stack_len]).expect(
+                "There's an implementation error: Only complete UTF-8 strings are written.",
+            );
+            for chr in string.chars() {
+                builder.push(StrSegment::Char(chr));
+            }
+        }
+    }
+    builder.build()
+}
+
+/// Additional capacity reserved for the rest of the output when the output gets too large for the
+/// stack. Note: Not too large, since excess capacity is shrunk (see `DefaultExcessShrink`).
+const FMT_ESTIMATED_REMAINING_LEN: usize = 64;
+
+/// Writes to the stack as long as the output is small enough (see `StackBin`). Larger output is
+/// written to a string instead; the length of the output is not known up front (`fmt::Arguments`
+/// does not expose it), so some additional capacity is reserved for the rest of the output (see
+/// `FMT_ESTIMATED_REMAINING_LEN`). The string always keeps the excess capacity required for
+/// reference-counting (so there's no re-allocation when it's converted to a reference-counted
+/// string).
+struct FmtWriter {
+    overhead_bytes: usize,
+    stack: [u8; StackBin::max_len()],
+    stack_len: usize,
+    string: Option<String>,
+}
+
+impl FmtWriter {
+    #[inline]
+    fn new(overhead_bytes: usize) -> Self {
+        Self {
+            overhead_bytes,
+            stack: [0; StackBin::max_len()],
+            stack_len: 0,
+            string: None,
+        }
+    }
+}
+
+impl fmt::Write for FmtWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let overhead_bytes = self.overhead_bytes;
+        match &mut self.string {
+            Some(string) => {
+                let required = s.len().checked_add(overhead_bytes).ok_or(fmt::Error)?;
+                // note: `reserve` grows the string exponentially.
+                string.reserve(required);
+                string.push_str(s);
+            }
+            None => {
+                let stack_len = self.stack_len;
+                let new_len = stack_len.checked_add(s.len()).ok_or(fmt::Error)?;
+                if new_len <= self.stack.len() {
+                    self.stack[stack_len..new_len].copy_from_slice(s.as_bytes());
+                    self.stack_len = new_len;
+                } else {
+                    let capacity = new_len
+                        .checked_add(FMT_ESTIMATED_REMAINING_LEN)
+                        .and_then(|capacity| capacity.checked_add(overhead_bytes))
+                        .ok_or(fmt::Error)?;
+                    let mut string = String::with_capacity(capacity);
+                    string.push_str(str::from_utf8(&self.stack[..stack_len]).expect(
+                        "There's an implementation error: Only complete UTF-8 strings \
+                            are written.",
+                    ));
+                    string.push_str(s);
+                    self.string = Some(string);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Converts the given binary to a string, replacing invalid UTF-8 sequences with
+/// `U+FFFD REPLACEMENT CHARACTER` (like `String::from_utf8_lossy`). If the binary is valid UTF-8,
+/// it's converted without copying.
+fn from_utf8_lossy<'a, TBuilder>(bin: TBuilder::T, builder: &mut TBuilder) -> AnyStr<TBuilder::T>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    let bytes = bin.as_slice();
+    let mut pos = 0;
+    loop {
+        match core::str::from_utf8(&bytes[pos..]) {
+            Ok(_) => {
+                if pos == 0 {
+                    // valid (this is the common case): no need to copy.
+                    return unsafe { AnyStr::from_utf8_unchecked(bin) };
+                }
+                push_bin_run(&bin, builder, pos, bytes.len());
+                return builder.build();
+            }
+            Err(err) => {
+                let valid_up_to = pos + err.valid_up_to();
+                push_bin_run(&bin, builder, pos, valid_up_to);
+                builder.push_char(REPLACEMENT_CHARACTER);
+                if let Some(error_len) = err.error_len() {
+                    pos = valid_up_to + error_len;
+                } else {
+                    // incomplete sequence at the end
+                    return builder.build();
+                }
+            }
+        }
+    }
+}
+
+/// Adds the run `[start, end)` of the binary (must be valid UTF-8) to the builder (shares the
+/// buffer with `bin`).
+#[inline]
+fn push_bin_run<'a, TBuilder>(bin: &TBuilder::T, builder: &mut TBuilder, start: usize, end: usize)
+where
+    TBuilder: StrBuilder<'a>,
+{
+    if start < end {
+        let run = bin.slice(start..end).expect(
+            "There's an implementation error: The run must be within the bounds of the binary.",
+        );
+        // we know it's valid UTF-8 (confirmed by `str::from_utf8`).
+        builder.push(StrSegment::Str(unsafe { AnyStr::from_utf8_unchecked(run) }));
+    }
+}
+
+impl From<Arc<str>> for Str {
+    fn from(arc: Arc<str>) -> Self {
+        NewStr::from_arc(arc)
+    }
+}
+
+impl From<Rc<str>> for Str {
+    fn from(rc: Rc<str>) -> Self {
+        NewStr::from_rc(rc)
+    }
+}
+
+impl From<Box<str>> for Str {
+    fn from(boxed: Box<str>) -> Self {
+        NewStr::from_boxed(boxed)
+    }
+}
+
+impl From<Arc<str>> for SStr {
+    fn from(arc: Arc<str>) -> Self {
+        NewSStr::from_arc(arc)
+    }
+}
+
+impl From<Box<str>> for SStr {
+    fn from(boxed: Box<str>) -> Self {
+        NewSStr::from_boxed(boxed)
+    }
+}
@@ -60,14 +60,23 @@
 //! ```
 
 pub mod spi;
+#[cfg(feature = "bytes")]
+pub(crate) use bytes_support::*;
+#[cfg(feature = "mmap")]
+pub use mmap_support::*;
 #[cfg(any(test, feature = "serde"))]
 pub use serde_support::*;
 pub use {binary::*, boo::*, common::*, implementation::*, string::*};
 
 mod binary;
 mod boo;
+#[cfg(feature = "bytes")]
+mod bytes_support;
 mod common;
 mod implementation;
+mod macros;
+#[cfg(feature = "mmap")]
+mod mmap_support;
 mod string;
 
 #[cfg(any(test, feature = "serde"))]
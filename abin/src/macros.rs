@@ -0,0 +1,42 @@
+/// Creates a `Str` using interpolation of runtime expressions; like `std::format!` but without
+/// the intermediate `String`.
+///
+/// If there are no arguments, a static string is returned (no allocation). Otherwise the
+/// arguments are formatted once: Small strings (up to 23 bytes on 64-bit platforms) are stored on
+/// the stack; larger strings are written to a buffer that also reserves the capacity required for
+/// reference-counting (usually a single allocation; the buffer is adopted without copying).
+///
+/// Note: The literal pieces of the format string are copied if there are arguments (they're only
+/// returned as static string if there are no arguments; `fmt::Arguments` does not expose them).
+///
+/// ```rust
+/// use abin::{format_str, Str};
+///
+/// let name = "World";
+/// let string : Str = format_str!("Hello, {}!", name);
+/// assert_eq!("Hello, World!", string.as_str());
+///
+/// let static_str : Str = format_str!("No arguments");
+/// assert_eq!("No arguments", static_str.as_str());
+/// ```
+#[macro_export]
+macro_rules! format_str {
+    ($($arg:tt)*) => {
+        $crate::NewStr::from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+/// Creates a `SStr` using interpolation of runtime expressions; see `format_str!`.
+///
+/// ```rust
+/// use abin::{format_sstr, SStr};
+///
+/// let string : SStr = format_sstr!("{}-{:04}", "key", 42);
+/// assert_eq!("key-0042", string.as_str());
+/// ```
+#[macro_export]
+macro_rules! format_sstr {
+    ($($arg:tt)*) => {
+        $crate::NewSStr::from_fmt(::core::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,188 @@
+use core::{mem, slice};
+use std::fs::File;
+use std::io;
+use std::sync::Arc;
+
+use memmap2::Mmap;
+
+use crate::spi::{BinData, FnTable, UnsafeBin};
+use crate::{Bin, BinFactory, IntoUnSyncView, NewSBin, SBin, StackBin, StorageKind};
+
+/// A binary backed by a read-only memory-mapped file: the content is not read into memory; the
+/// mapping is shared (reference-counted) among all clones and slices and is unmapped when the
+/// last binary is dropped. There's only a synchronized version (a mapping is `Send + Sync`).
+///
+/// Cloning, slicing and re-integration (see `AnyBin::try_to_re_integrate`) do not copy and do
+/// not allocate. Converting into a vector always copies.
+///
+/// ```rust
+/// use std::io::Write;
+/// use abin::{AnyBin, MmapBin};
+///
+/// let mut file = tempfile::tempfile().unwrap();
+/// file.write_all(&[7u8; 4096]).unwrap();
+/// let bin = unsafe { MmapBin::map_file(&file) }.unwrap();
+/// assert_eq!(4096, bin.len());
+/// assert_eq!(&[7u8; 10], bin.slice(100..110).unwrap().as_slice());
+/// ```
+pub struct MmapBin;
+
+impl MmapBin {
+    /// Maps the given file read-only; see `from_mmap`.
+    ///
+    /// # Safety
+    ///
+    /// See `memmap2::Mmap::map`: The file must not be modified (by this or by other processes)
+    /// while the mapping (or any binary that references it) is alive; this would result in
+    /// undefined behaviour.
+    pub unsafe fn map_file(file: &File) -> io::Result<SBin> {
+        Ok(Self::from_mmap(Mmap::map(file)?))
+    }
+
+    /// Does those steps:
+    ///
+    ///  * If it's empty, returns an empty binary.
+    ///  * If it's small, returns a stack binary (the mapping is dropped).
+    ///  * ...otherwise wraps the given mapping.
+    pub fn from_mmap(mmap: Mmap) -> SBin {
+        if mmap.is_empty() {
+            NewSBin::empty()
+        } else if let Some(stack) = StackBin::try_from(mmap.as_ref()) {
+            stack
+        } else {
+            let mmap_data = MmapData {
+                ptr: mmap.as_ptr(),
+                len: mmap.len(),
+                owner: Arc::into_raw(Arc::new(mmap)),
+            };
+            unsafe { Bin::_new(mmap_data.to_bin_data(), &FN_TABLE)._into_sync() }
+        }
+    }
+}
+
+#[repr(C)]
+struct MmapData {
+    /// pointer to the data; this differs from the start of the mapping if sliced.
+    ptr: *const u8,
+    /// the length of the data; this differs from the length of the mapping if sliced.
+    len: usize,
+    /// the reference-counted mapping: See `Arc::into_raw`.
+    owner: *const Mmap,
+}
+
+impl MmapData {
+    #[inline]
+    unsafe fn from_bin(bin: &Bin) -> &Self {
+        let bin_data = bin._data() as *const BinData;
+        let self_data = bin_data as *const Self;
+        &*self_data
+    }
+
+    #[inline]
+    unsafe fn to_bin_data(&self) -> BinData {
+        mem::transmute_copy::<Self, BinData>(self)
+    }
+}
+
+const FN_TABLE: FnTable = FnTable {
+    drop: Some(drop),
+    as_slice: Some(as_slice),
+    is_empty: Some(is_empty),
+    clone,
+    into_vec,
+    slice,
+    // not required: sync only.
+    convert_into_un_sync: None,
+    // not required: sync only.
+    convert_into_sync: None,
+    try_re_integrate: Some(try_re_integrate),
+    // not supported: the memory is owned by the mapping.
+    try_into_unique_vec: None,
+    // not supported: the mapping is read-only.
+    get_mut: None,
+    // not required: slicing is cheap.
+    split_to: None,
+    split_off: None,
+    advance: None,
+    storage_kind: Some(storage_kind),
+    strong_count: Some(strong_count),
+    retained_bytes: Some(retained_bytes),
+};
+
+fn drop(bin: &mut Bin) {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    // decrements the reference count (and unmaps the file if this is the last reference).
+    unsafe { mem::drop(Arc::from_raw(mmap_data.owner)) };
+}
+
+#[inline]
+fn as_slice(bin: &Bin) -> &[u8] {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    unsafe { slice::from_raw_parts(mmap_data.ptr, mmap_data.len) }
+}
+
+fn is_empty(bin: &Bin) -> bool {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    mmap_data.len == 0
+}
+
+fn clone(bin: &Bin) -> Bin {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    unsafe {
+        Arc::increment_strong_count(mmap_data.owner);
+        Bin::_new(mmap_data.to_bin_data(), &FN_TABLE)
+    }
+}
+
+fn into_vec(bin: Bin) -> Vec<u8> {
+    // the only option is to copy (the memory is owned by the mapping).
+    as_slice(&bin).to_vec()
+}
+
+fn slice(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin> {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    if as_slice(bin).get(start..end_excluded).is_some() {
+        let len = end_excluded - start;
+        if len == 0 {
+            Some(NewSBin::empty().un_sync())
+        } else {
+            unsafe { Arc::increment_strong_count(mmap_data.owner) };
+            let new_data = MmapData {
+                ptr: unsafe { mmap_data.ptr.add(start) },
+                len,
+                owner: mmap_data.owner,
+            };
+            Some(unsafe { Bin::_new(new_data.to_bin_data(), &FN_TABLE) })
+        }
+    } else {
+        None
+    }
+}
+
+fn try_re_integrate(bin: &Bin, slice_in: &[u8]) -> Option<Bin> {
+    let self_slice = as_slice(bin);
+    let start = (slice_in.as_ptr() as usize).checked_sub(self_slice.as_ptr() as usize);
+    if let Some(start) = start {
+        slice(bin, start, start + slice_in.len())
+    } else {
+        None
+    }
+}
+
+fn storage_kind(_: &Bin) -> StorageKind {
+    StorageKind::Mmap
+}
+
+fn strong_count(bin: &Bin) -> usize {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    // we don't own this one (must not be dropped).
+    let owner = mem::ManuallyDrop::new(unsafe { Arc::from_raw(mmap_data.owner) });
+    Arc::strong_count(&owner)
+}
+
+fn retained_bytes(bin: &Bin) -> usize {
+    let mmap_data = unsafe { MmapData::from_bin(bin) };
+    // the whole mapping is kept alive (note: this is not heap memory).
+    let owner = unsafe { &*mmap_data.owner };
+    owner.len()
+}
@@ -0,0 +1,3 @@
+pub use mmap_bin::*;
+
+mod mmap_bin;
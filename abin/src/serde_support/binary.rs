@@ -6,7 +6,8 @@ use std::marker::PhantomData;
 use serde::de::Visitor;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{AnyBin, Bin, BinFactory, NewBin, NewSBin, SBin};
+use crate::implementation::CommonFactory;
+use crate::{AnyBin, AnyRc, Bin, BinFactory, BinTextEncoding, NewBin, NewSBin, SBin};
 
 impl Serialize for Bin {
     #[inline]
@@ -14,7 +15,7 @@ impl Serialize for Bin {
     where
         S: Serializer,
     {
-        serializer.serialize_bytes(self.as_slice())
+        serialize_bin(self, serializer, BinTextEncoding::default_encoding())
     }
 }
 
@@ -24,7 +25,7 @@ impl<'de> Deserialize<'de> for Bin {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(RcBytesVisitor::<NewBin>::new())
+        Self::deserialize_with_encoding(deserializer, BinTextEncoding::default_encoding())
     }
 }
 
@@ -34,7 +35,7 @@ impl Serialize for SBin {
     where
         S: Serializer,
     {
-        serializer.serialize_bytes(self.as_slice())
+        serialize_bin(self, serializer, BinTextEncoding::default_encoding())
     }
 }
 
@@ -44,18 +45,96 @@ impl<'de> Deserialize<'de> for SBin {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_bytes(RcBytesVisitor::<NewSBin>::new())
+        Self::deserialize_with_encoding(deserializer, BinTextEncoding::default_encoding())
+    }
+}
+
+/// Binaries that can be de-serialized using a specific text encoding (for human-readable
+/// formats); see `BinTextEncoding`, `serde_base64` and `serde_hex`.
+pub trait DeserializeWithEncoding: Sized {
+    /// De-serializes the binary: Text (in human-readable formats) is decoded using the given
+    /// encoding; binary formats provide the raw bytes.
+    fn deserialize_with_encoding<'de, D>(
+        deserializer: D,
+        encoding: BinTextEncoding,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+impl DeserializeWithEncoding for Bin {
+    #[inline]
+    fn deserialize_with_encoding<'de, D>(
+        deserializer: D,
+        encoding: BinTextEncoding,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bin::<D, NewBin>(deserializer, encoding)
+    }
+}
+
+impl DeserializeWithEncoding for SBin {
+    #[inline]
+    fn deserialize_with_encoding<'de, D>(
+        deserializer: D,
+        encoding: BinTextEncoding,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bin::<D, NewSBin>(deserializer, encoding)
+    }
+}
+
+/// Serializes the binary as text (human-readable formats) or as raw bytes (binary formats).
+#[inline]
+pub(crate) fn serialize_bin<S, TBin>(
+    bin: &TBin,
+    serializer: S,
+    encoding: BinTextEncoding,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    TBin: AnyBin,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(encoding.encode(bin).as_str())
+    } else {
+        serializer.serialize_bytes(bin.as_slice())
+    }
+}
+
+#[inline]
+fn deserialize_bin<'de, D, T>(
+    deserializer: D,
+    encoding: BinTextEncoding,
+) -> Result<<T::TAnyRc as AnyRc>::T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: CommonFactory,
+    <T::TAnyRc as AnyRc>::T: AnyBin,
+{
+    if deserializer.is_human_readable() {
+        // 'any': legacy data might contain an array of numbers (instead of text).
+        deserializer.deserialize_any(RcBytesVisitor::<T>::new(Some(encoding)))
+    } else {
+        deserializer.deserialize_bytes(RcBytesVisitor::<T>::new(None))
     }
 }
 
 struct RcBytesVisitor<T> {
+    /// `Some` if strings are text-encoded binaries (human-readable formats).
+    encoding: Option<BinTextEncoding>,
     _phantom: PhantomData<T>,
 }
 
 impl<T> RcBytesVisitor<T> {
-    pub fn new() -> Self {
+    pub fn new(encoding: Option<BinTextEncoding>) -> Self {
         Self {
-            _phantom: PhantomData::default(),
+            encoding,
+            _phantom: PhantomData,
         }
     }
 }
@@ -66,12 +145,17 @@ static OVERHEAD_BYTES: usize = 128;
 
 impl<'de, T> Visitor<'de> for RcBytesVisitor<T>
 where
-    T: BinFactory,
+    T: CommonFactory,
+    <T::TAnyRc as AnyRc>::T: AnyBin,
 {
-    type Value = T::T;
+    type Value = <T::TAnyRc as AnyRc>::T;
 
     fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        formatter.write_str("expecting a byte array")
+        if self.encoding.is_some() {
+            formatter.write_str("expecting a text-encoded byte array")
+        } else {
+            formatter.write_str("expecting a byte array")
+        }
     }
 
     #[inline]
@@ -79,7 +163,11 @@ where
     where
         E: de::Error,
     {
-        Ok(T::copy_from_slice(v.as_bytes()))
+        if let Some(encoding) = self.encoding {
+            encoding.decode::<T>(v).map_err(E::custom)
+        } else {
+            Ok(T::copy_from_slice(v.as_bytes()))
+        }
     }
 
     #[inline]
@@ -87,7 +175,11 @@ where
     where
         E: de::Error,
     {
-        Ok(T::from_given_vec(v.into_bytes()))
+        if self.encoding.is_some() {
+            self.visit_str(&v)
+        } else {
+            Ok(T::from_given_vec(v.into_bytes()))
+        }
     }
 
     #[inline]
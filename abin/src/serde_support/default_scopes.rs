@@ -1,6 +1,9 @@
-use serde::export::PhantomData;
+use std::marker::PhantomData;
 
-use crate::{AnyBin, Bin, Binaries, IntoUnSyncView, SBin, ScopedRiSetup};
+use crate::{
+    AlwaysCopy, AlwaysShare, AnyBin, Bin, Binaries, IntoUnSyncView, RiSource, SBin, ScopedRiSetup,
+    SlicePolicy,
+};
 
 /// Constructs `ScopedRiSetup` with sane defaults.
 pub struct DefaultScopes {
@@ -13,27 +16,74 @@ impl DefaultScopes {
     ///
     /// This is what you most likely want to use if you can't guarantee that you don't
     /// de-serialize only `Bin`.
-    pub fn sync(bin: &SBin) -> ScopedRiSetup {
+    pub fn sync(bin: &SBin) -> ScopedRiSetup<'_> {
+        Self::sync_with_policy::<AlwaysShare>(bin)
+    }
+
+    /// Like `sync`, but the given policy decides whether de-serialized binaries share the
+    /// buffer of the given binary or whether they're copied: Use this (with
+    /// `DefaultSlicePolicy`) if the de-serialized values live much longer than the message
+    /// and you don't want small values to keep the entire message alive.
+    pub fn sync_with_policy<TPolicy: SlicePolicy>(bin: &SBin) -> ScopedRiSetup<'_> {
+        Self::binaries_with_policy::<TPolicy>(Binaries::new_sync_bin(bin))
+    }
+
+    /// A scope with multiple source binaries (e.g. a message assembled from several received
+    /// frames). Note: `SBin` can only be re-integrated from `SBin` sources (slices of `Bin`
+    /// sources are copied).
+    pub fn bins(bins: &[Bin]) -> ScopedRiSetup<'_> {
+        Self::binaries_with_policy::<AlwaysShare>(Binaries::from_bins(bins))
+    }
+
+    /// A scope with multiple source binaries; see `sync`.
+    pub fn sync_bins(bins: &[SBin]) -> ScopedRiSetup<'_> {
+        Self::binaries_with_policy::<AlwaysShare>(Binaries::from_sync_bins(bins))
+    }
+
+    /// A scope with the given source binaries (of mixed sync-ness); the given policy decides
+    /// whether de-serialized binaries share the buffer of the source (see `sync_with_policy`).
+    pub fn binaries_with_policy<TPolicy: SlicePolicy>(binaries: Binaries<'_>) -> ScopedRiSetup<'_> {
         ScopedRiSetup::new(
-            Binaries::new_sync_bin(bin),
-            sync_re_integration_fn,
-            sync_sync_re_integration_fn,
+            binaries,
+            re_integration_fn::<TPolicy>,
+            sync_re_integration_fn::<TPolicy>,
         )
     }
 }
 
-fn sync_re_integration_fn(binaries: &Binaries, slice: &[u8]) -> Option<Bin> {
-    match binaries.both() {
-        (Some(bin), _) => bin.try_to_re_integrate(slice),
-        (_, Some(sync)) => sync.try_to_re_integrate(slice).map(|item| item.un_sync()),
-        _ => None,
+fn re_integration_fn<TPolicy: SlicePolicy>(binaries: &Binaries, slice: &[u8]) -> Option<Bin> {
+    match binaries.find(slice)? {
+        RiSource::Bin(bin) => bin
+            .try_to_re_integrate(slice)
+            .map(|re_integrated| apply_policy::<TPolicy, _>(bin, re_integrated)),
+        RiSource::SBin(bin) => bin
+            .try_to_re_integrate(slice)
+            .map(|re_integrated| apply_policy::<TPolicy, _>(bin, re_integrated).un_sync()),
+    }
+}
+
+fn sync_re_integration_fn<TPolicy: SlicePolicy>(binaries: &Binaries, slice: &[u8]) -> Option<SBin> {
+    match binaries.find(slice)? {
+        // can't produce a `SBin` from a `Bin` without copying.
+        RiSource::Bin(_) => None,
+        RiSource::SBin(bin) => bin
+            .try_to_re_integrate(slice)
+            .map(|re_integrated| apply_policy::<TPolicy, _>(bin, re_integrated)),
     }
 }
 
-fn sync_sync_re_integration_fn(binaries: &Binaries, slice: &[u8]) -> Option<SBin> {
-    if let Some(bin) = binaries.sync_bin() {
-        bin.try_to_re_integrate(slice)
+/// Copies the re-integrated binary if the policy says so (the copy is not counted as
+/// re-integrated in `RiStats`: it no longer shares the de-serializer input).
+fn apply_policy<TPolicy: SlicePolicy, TBin: AnyBin>(bin: &TBin, re_integrated: TBin) -> TBin {
+    if TPolicy::copy(
+        bin.storage_kind(),
+        re_integrated.len(),
+        bin.retained_bytes(),
+    ) {
+        re_integrated
+            .slice_with_policy::<AlwaysCopy, _>(..)
+            .expect("Slicing the entire binary must never fail.")
     } else {
-        None
+        re_integrated
     }
 }
@@ -1,16 +1,23 @@
 mod binary;
 mod boo;
 mod default_scopes;
+mod packed;
 mod ri_deserialization_base_bin;
 mod ri_deserialization_base_str;
 mod ri_deserialization_bin;
 mod ri_deserialization_str;
+mod ri_seed;
+mod ri_stats;
 mod scoped_ri;
+pub mod serde_base64;
+pub mod serde_base64_url;
+pub mod serde_hex;
 mod string;
+mod text_encoding;
 
 pub use {
-    binary::*, boo::*, default_scopes::*, ri_deserialization_bin::*, ri_deserialization_str::*,
-    scoped_ri::*, string::*,
+    binary::*, default_scopes::*, packed::*, ri_deserialization_bin::*, ri_deserialization_str::*,
+    ri_seed::*, ri_stats::*, scoped_ri::*, text_encoding::*,
 };
 
 pub(crate) use {ri_deserialization_base_bin::*, ri_deserialization_base_str::*};
@@ -0,0 +1,447 @@
+use core::fmt;
+use std::fmt::Formatter;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::{de, Deserializer};
+
+use crate::{
+    AlwaysShare, AnyBin, AnyRc, AnyStr, ArcBin, Bin, BinFactory, BinTextEncoding, NewBin, NewSBin,
+    RcBin, SBin, SStr, StackBin, Str,
+};
+
+/// De-serializes a sequence of binaries or strings (`Bin`, `SBin`, `Str`, `SStr`) into one
+/// shared allocation, see `#[serde(deserialize_with = "path")]`.
+///
+/// All elements are collected into one growable buffer; the elements are slices of that single
+/// reference-counted buffer (small elements - up to 23 bytes on 64 bit platforms - are stored
+/// on the stack instead). Use this for lists of many small strings that can't be re-integrated
+/// (such as escaped JSON strings; see `ri_deserialize_str`): it's one allocation instead of one
+/// allocation per element. Note: As long as one element lives, the entire buffer is kept
+/// alive.
+///
+/// In human-readable formats (such as JSON), binaries are text-encoded (see `BinTextEncoding`).
+///
+/// ```rust
+/// use abin::{AnyBin, AnyStr, Str};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Names {
+///     #[serde(deserialize_with = "abin::deserialize_packed")]
+///     names: Vec<Str>,
+/// }
+///
+/// let json = r#"{"names": ["A name that is \"too long\" for the stack", "Short", "Another long name (not on the stack)"]}"#;
+/// let names: Names = serde_json::from_str(json).unwrap();
+/// assert_eq!("Short", names.names[1].as_str());
+/// // the two long names share the same buffer.
+/// assert_eq!(Some(2), names.names[0].as_bin().strong_count());
+/// assert_eq!(None, names.names[1].as_bin().strong_count());
+/// ```
+pub fn deserialize_packed<'de, D, TColl>(deserializer: D) -> Result<TColl, D::Error>
+where
+    D: Deserializer<'de>,
+    TColl: IntoIterator + FromIterator<<TColl as IntoIterator>::Item>,
+    TColl::Item: Packable,
+{
+    let encoding = text_encoding::<TColl::Item>(deserializer.is_human_readable());
+    deserializer.deserialize_seq(PackedSeqVisitor {
+        encoding,
+        _phantom: PhantomData::<TColl>,
+    })
+}
+
+/// Like `deserialize_packed` but for maps: Keys and values are packed (there's one buffer for
+/// the keys and one buffer for the values).
+///
+/// ```rust
+/// use abin::{AnyStr, Str};
+/// use serde::Deserialize;
+/// use std::collections::HashMap;
+///
+/// #[derive(Deserialize)]
+/// struct Labels {
+///     #[serde(deserialize_with = "abin::deserialize_packed_map")]
+///     labels: HashMap<Str, Str>,
+/// }
+///
+/// let json = r#"{"labels": {"name": "value", "other name": "other value"}}"#;
+/// let labels: Labels = serde_json::from_str(json).unwrap();
+/// assert_eq!("value", labels.labels.get("name").unwrap().as_str());
+/// ```
+pub fn deserialize_packed_map<'de, D, TMap, K, V>(deserializer: D) -> Result<TMap, D::Error>
+where
+    D: Deserializer<'de>,
+    TMap: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+    K: Packable,
+    V: Packable,
+{
+    let human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(PackedMapVisitor {
+        key_encoding: text_encoding::<K>(human_readable),
+        value_encoding: text_encoding::<V>(human_readable),
+        _phantom: PhantomData::<TMap>,
+    })
+}
+
+/// Binaries and strings that can be de-serialized using `deserialize_packed`: `Bin`, `SBin`,
+/// `Str` and `SStr`.
+pub trait Packable: private::PackableItem {}
+
+impl Packable for Bin {}
+
+impl Packable for SBin {}
+
+impl Packable for Str {}
+
+impl Packable for SStr {}
+
+mod private {
+    use crate::AnyBin;
+
+    /// Not public API (sealed); see `Packable`.
+    pub trait PackableItem: Sized {
+        type TBin: AnyBin;
+        /// `true` for strings (the elements must be valid UTF-8).
+        const UTF8: bool;
+        /// Small elements (not more than `StackBin::max_len()` bytes).
+        fn small(slice: &[u8]) -> Self::TBin;
+        /// The shared buffer (has an excess of `overhead_bytes()` if possible).
+        fn shared(vec: Vec<u8>) -> Self::TBin;
+        fn overhead_bytes() -> usize;
+        /// Must only be called with valid UTF-8 for strings (see `UTF8`).
+        unsafe fn from_bin(bin: Self::TBin) -> Self;
+    }
+}
+
+impl private::PackableItem for Bin {
+    type TBin = Bin;
+    const UTF8: bool = false;
+
+    #[inline]
+    fn small(slice: &[u8]) -> Self::TBin {
+        NewBin::copy_from_slice(slice)
+    }
+
+    #[inline]
+    fn shared(vec: Vec<u8>) -> Self::TBin {
+        RcBin::from_vec(vec)
+    }
+
+    #[inline]
+    fn overhead_bytes() -> usize {
+        RcBin::overhead_bytes()
+    }
+
+    #[inline]
+    unsafe fn from_bin(bin: Self::TBin) -> Self {
+        bin
+    }
+}
+
+impl private::PackableItem for SBin {
+    type TBin = SBin;
+    const UTF8: bool = false;
+
+    #[inline]
+    fn small(slice: &[u8]) -> Self::TBin {
+        NewSBin::copy_from_slice(slice)
+    }
+
+    #[inline]
+    fn shared(vec: Vec<u8>) -> Self::TBin {
+        ArcBin::from_vec(vec)
+    }
+
+    #[inline]
+    fn overhead_bytes() -> usize {
+        ArcBin::overhead_bytes()
+    }
+
+    #[inline]
+    unsafe fn from_bin(bin: Self::TBin) -> Self {
+        bin
+    }
+}
+
+impl private::PackableItem for Str {
+    type TBin = Bin;
+    const UTF8: bool = true;
+
+    #[inline]
+    fn small(slice: &[u8]) -> Self::TBin {
+        NewBin::copy_from_slice(slice)
+    }
+
+    #[inline]
+    fn shared(vec: Vec<u8>) -> Self::TBin {
+        RcBin::from_vec(vec)
+    }
+
+    #[inline]
+    fn overhead_bytes() -> usize {
+        RcBin::overhead_bytes()
+    }
+
+    #[inline]
+    unsafe fn from_bin(bin: Self::TBin) -> Self {
+        AnyStr::from_utf8_unchecked(bin)
+    }
+}
+
+impl private::PackableItem for SStr {
+    type TBin = SBin;
+    const UTF8: bool = true;
+
+    #[inline]
+    fn small(slice: &[u8]) -> Self::TBin {
+        NewSBin::copy_from_slice(slice)
+    }
+
+    #[inline]
+    fn shared(vec: Vec<u8>) -> Self::TBin {
+        ArcBin::from_vec(vec)
+    }
+
+    #[inline]
+    fn overhead_bytes() -> usize {
+        ArcBin::overhead_bytes()
+    }
+
+    #[inline]
+    unsafe fn from_bin(bin: Self::TBin) -> Self {
+        AnyStr::from_utf8_unchecked(bin)
+    }
+}
+
+/// Binaries are text-encoded in human-readable formats; strings are never text-encoded.
+fn text_encoding<T: Packable>(human_readable: bool) -> Option<BinTextEncoding> {
+    if human_readable && !T::UTF8 {
+        Some(BinTextEncoding::default_encoding())
+    } else {
+        None
+    }
+}
+
+/// Collects the elements; see `deserialize_packed`.
+struct Packer<T: Packable> {
+    buf: Vec<u8>,
+    items: Vec<PackedItem<T::TBin>>,
+}
+
+enum PackedItem<TBin> {
+    /// Small element (stack).
+    Ready(TBin),
+    /// Range within the shared buffer.
+    Packed { start: usize, end: usize },
+}
+
+impl<T: Packable> Packer<T> {
+    fn new(size_hint: Option<usize>) -> Self {
+        Self {
+            buf: Vec::new(),
+            items: Vec::with_capacity(size_hint.unwrap_or(0).min(SAFE_MAX_ITEMS)),
+        }
+    }
+
+    /// The element has been appended to `buf` (starting at `start`).
+    fn push(&mut self, start: usize) {
+        let end = self.buf.len();
+        if end - start <= StackBin::max_len() {
+            // small: not added to the shared buffer.
+            self.items
+                .push(PackedItem::Ready(T::small(&self.buf[start..end])));
+            self.buf.truncate(start);
+        } else {
+            self.items.push(PackedItem::Packed { start, end });
+        }
+    }
+
+    fn finish(self) -> impl Iterator<Item = T> {
+        let mut buf = self.buf;
+        let shared = if buf.is_empty() {
+            // all elements are on the stack (or there are no elements).
+            None
+        } else {
+            buf.reserve_exact(T::overhead_bytes());
+            Some(T::shared(buf))
+        };
+        self.items.into_iter().map(move |item| {
+            let bin = match item {
+                PackedItem::Ready(bin) => bin,
+                PackedItem::Packed { start, end } => shared
+                    .as_ref()
+                    .and_then(|shared| shared.slice_with_policy::<AlwaysShare, _>(start..end))
+                    .expect("There's an implementation error: Range not within the buffer."),
+            };
+            // safe: the elements have been validated (see `PackedElementVisitor`).
+            unsafe { T::from_bin(bin) }
+        })
+    }
+}
+
+/// Limit for the size hint (in case there's a problem with the input data).
+const SAFE_MAX_ITEMS: usize = 4096;
+
+struct PackedSeqVisitor<TColl> {
+    encoding: Option<BinTextEncoding>,
+    _phantom: PhantomData<TColl>,
+}
+
+impl<'de, TColl> Visitor<'de> for PackedSeqVisitor<TColl>
+where
+    TColl: IntoIterator + FromIterator<<TColl as IntoIterator>::Item>,
+    TColl::Item: Packable,
+{
+    type Value = TColl;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("expecting a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut packer = Packer::<TColl::Item>::new(seq.size_hint());
+        while seq
+            .next_element_seed(PackedElement {
+                packer: &mut packer,
+                encoding: self.encoding,
+            })?
+            .is_some()
+        {}
+        Ok(packer.finish().collect())
+    }
+}
+
+struct PackedMapVisitor<TMap> {
+    key_encoding: Option<BinTextEncoding>,
+    value_encoding: Option<BinTextEncoding>,
+    _phantom: PhantomData<TMap>,
+}
+
+impl<'de, TMap, K, V> Visitor<'de> for PackedMapVisitor<TMap>
+where
+    TMap: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+    K: Packable,
+    V: Packable,
+{
+    type Value = TMap;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("expecting a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut keys = Packer::<K>::new(map.size_hint());
+        let mut values = Packer::<V>::new(map.size_hint());
+        while map
+            .next_key_seed(PackedElement {
+                packer: &mut keys,
+                encoding: self.key_encoding,
+            })?
+            .is_some()
+        {
+            map.next_value_seed(PackedElement {
+                packer: &mut values,
+                encoding: self.value_encoding,
+            })?;
+        }
+        Ok(keys.finish().zip(values.finish()).collect())
+    }
+}
+
+/// De-serializes one element (appends it to the packer).
+struct PackedElement<'a, T: Packable> {
+    packer: &'a mut Packer<T>,
+    /// `Some` if binaries are text-encoded (human-readable formats).
+    encoding: Option<BinTextEncoding>,
+}
+
+impl<'de, 'a, T: Packable> DeserializeSeed<'de> for PackedElement<'a, T> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if T::UTF8 {
+            deserializer.deserialize_str(PackedElementVisitor(self))
+        } else if self.encoding.is_some() {
+            // 'any': legacy data might contain an array of numbers (instead of text).
+            deserializer.deserialize_any(PackedElementVisitor(self))
+        } else {
+            deserializer.deserialize_bytes(PackedElementVisitor(self))
+        }
+    }
+}
+
+struct PackedElementVisitor<'a, T: Packable>(PackedElement<'a, T>);
+
+impl<'de, 'a, T: Packable> Visitor<'de> for PackedElementVisitor<'a, T> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        if T::UTF8 {
+            formatter.write_str("expecting a string")
+        } else if self.0.encoding.is_some() {
+            formatter.write_str("expecting a text-encoded byte array")
+        } else {
+            formatter.write_str("expecting a byte array")
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let packer = self.0.packer;
+        let start = packer.buf.len();
+        if let Some(encoding) = self.0.encoding {
+            encoding
+                .decode_into(v, &mut packer.buf)
+                .map_err(E::custom)?;
+        } else {
+            packer.buf.extend_from_slice(v.as_bytes());
+        }
+        packer.push(start);
+        Ok(())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if T::UTF8 && std::str::from_utf8(v).is_err() {
+            return Err(E::invalid_value(de::Unexpected::Bytes(v), &self));
+        }
+        let packer = self.0.packer;
+        let start = packer.buf.len();
+        packer.buf.extend_from_slice(v);
+        packer.push(start);
+        Ok(())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let packer = &mut *self.0.packer;
+        let start = packer.buf.len();
+        while let Some(byte) = seq.next_element::<u8>()? {
+            packer.buf.push(byte);
+        }
+        if T::UTF8 && std::str::from_utf8(&packer.buf[start..]).is_err() {
+            packer.buf.truncate(start);
+            return Err(de::Error::custom("invalid UTF-8 sequence"));
+        }
+        packer.push(start);
+        Ok(())
+    }
+}
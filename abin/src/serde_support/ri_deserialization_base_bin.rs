@@ -6,38 +6,101 @@ use std::marker::PhantomData;
 use serde::de;
 use serde::de::Visitor;
 
-use crate::AnyBin;
+use crate::serde_support::{RiContext, RiOutcome, RiPath};
+use crate::{AnyBin, BinTextEncoding, DecodeError};
 
 static SAFE_MAX_LEN: usize = 256 * 1024;
 static GUESSED_LEN: usize = 256;
 
-pub struct ReIntegrationBytesVisitor<TReIntegrator> {
+pub struct ReIntegrationBytesVisitor<'a, TReIntegrator> {
+    context: RiContext<'a>,
+    /// `Some` if strings are text-encoded binaries (human-readable formats); these can't be
+    /// re-integrated (the decoded bytes are not part of the serialized data).
+    encoding: Option<BinTextEncoding>,
     _phantom: PhantomData<TReIntegrator>,
 }
 
-impl<TReIntegrator> ReIntegrationBytesVisitor<TReIntegrator> {
-    pub fn new() -> Self {
+impl<'a, TReIntegrator> ReIntegrationBytesVisitor<'a, TReIntegrator>
+where
+    TReIntegrator: ReIntegrator,
+{
+    pub fn new(context: RiContext<'a>, encoding: Option<BinTextEncoding>) -> Self {
         Self {
-            _phantom: PhantomData::default(),
+            context,
+            encoding,
+            _phantom: PhantomData,
         }
     }
+
+    /// The slice is borrowed from the de-serializer input; tries to re-integrate it.
+    #[inline]
+    fn re_integrate(&self, path: RiPath, slice: &[u8]) -> TReIntegrator::TBin {
+        if let Some(bin) = TReIntegrator::try_re_integrate(&self.context, slice) {
+            // nice, could re-integrate (unless the slice policy copied it)
+            let outcome = RiOutcome::re_integrated(bin.as_slice(), slice);
+            self.context.record(path, outcome, &bin);
+            bin
+        } else {
+            // bad!
+            let bin = TReIntegrator::copy(slice);
+            self.context.record(path, RiOutcome::Missed, &bin);
+            bin
+        }
+    }
+
+    /// Value that can't be re-integrated.
+    #[inline]
+    fn not_re_integrated(&self, path: RiPath, bin: TReIntegrator::TBin) -> TReIntegrator::TBin {
+        self.context.record(path, RiOutcome::NotTried, &bin);
+        bin
+    }
+
+    #[inline]
+    fn decode<E: de::Error>(
+        &self,
+        text: &str,
+        encoding: BinTextEncoding,
+    ) -> Result<TReIntegrator::TBin, E> {
+        let bin = TReIntegrator::decode(text, encoding).map_err(E::custom)?;
+        Ok(self.not_re_integrated(RiPath::TextEncoded, bin))
+    }
 }
 
 pub trait ReIntegrator {
     type TBin: AnyBin;
-    fn re_integrate(slice: &[u8]) -> Self::TBin;
+    /// Tries to re-integrate the slice (it's borrowed from the de-serializer input).
+    fn try_re_integrate(context: &RiContext<'_>, slice: &[u8]) -> Option<Self::TBin>;
+    /// The slice can't be re-integrated (e.g. a scratch buffer of the de-serializer).
+    fn copy(slice: &[u8]) -> Self::TBin;
     fn vec(vec: Vec<u8>) -> Self::TBin;
+    fn decode(text: &str, encoding: BinTextEncoding) -> Result<Self::TBin, DecodeError>;
     fn overhead_bytes_for_vec() -> usize;
 }
 
-impl<'de, TReIntegrator> Visitor<'de> for ReIntegrationBytesVisitor<TReIntegrator>
+impl<'de, 'a, TReIntegrator> Visitor<'de> for ReIntegrationBytesVisitor<'a, TReIntegrator>
 where
     TReIntegrator: ReIntegrator,
 {
     type Value = TReIntegrator::TBin;
 
     fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        formatter.write_str("expecting a byte array")
+        if self.encoding.is_some() {
+            formatter.write_str("expecting a text-encoded byte array")
+        } else {
+            formatter.write_str("expecting a byte array")
+        }
+    }
+
+    #[inline]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Some(encoding) = self.encoding {
+            self.decode(v, encoding)
+        } else {
+            Ok(self.re_integrate(RiPath::BorrowedStr, v.as_bytes()))
+        }
     }
 
     #[inline]
@@ -45,7 +108,11 @@ where
     where
         E: de::Error,
     {
-        Ok(TReIntegrator::re_integrate(v.as_bytes()))
+        if let Some(encoding) = self.encoding {
+            self.decode(v, encoding)
+        } else {
+            Ok(self.not_re_integrated(RiPath::Str, TReIntegrator::copy(v.as_bytes())))
+        }
     }
 
     #[inline]
@@ -53,7 +120,19 @@ where
     where
         E: de::Error,
     {
-        Ok(TReIntegrator::vec(v.into_bytes()))
+        if self.encoding.is_some() {
+            self.visit_str(&v)
+        } else {
+            Ok(self.not_re_integrated(RiPath::String, TReIntegrator::vec(v.into_bytes())))
+        }
+    }
+
+    #[inline]
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.re_integrate(RiPath::BorrowedBytes, v))
     }
 
     #[inline]
@@ -61,7 +140,7 @@ where
     where
         E: de::Error,
     {
-        Ok(TReIntegrator::re_integrate(v))
+        Ok(self.not_re_integrated(RiPath::Bytes, TReIntegrator::copy(v)))
     }
 
     #[inline]
@@ -69,7 +148,7 @@ where
     where
         E: de::Error,
     {
-        Ok(TReIntegrator::vec(v))
+        Ok(self.not_re_integrated(RiPath::ByteBuf, TReIntegrator::vec(v)))
     }
 
     #[inline]
@@ -98,6 +177,6 @@ where
             values.push(value);
         }
 
-        Ok(TReIntegrator::vec(values))
+        Ok(self.not_re_integrated(RiPath::Seq, TReIntegrator::vec(values)))
     }
 }
@@ -5,27 +5,53 @@ use std::marker::PhantomData;
 use serde::de;
 use serde::de::Visitor;
 
+use crate::serde_support::{RiContext, RiOutcome, RiPath};
 use crate::{AnyBin, AnyStr};
 
-pub struct ReIntegrationStrVisitor<TReIntegrator> {
+pub struct ReIntegrationStrVisitor<'a, TReIntegrator> {
+    context: RiContext<'a>,
     _phantom: PhantomData<TReIntegrator>,
 }
 
-impl<TReIntegrator> ReIntegrationStrVisitor<TReIntegrator> {
-    pub fn new() -> Self {
+impl<'a, TReIntegrator> ReIntegrationStrVisitor<'a, TReIntegrator>
+where
+    TReIntegrator: StrReIntegrator,
+{
+    pub fn new(context: RiContext<'a>) -> Self {
         Self {
-            _phantom: PhantomData::default(),
+            context,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The string can't be re-integrated: Interned (if there's an interner) or copied.
+    #[inline]
+    fn intern_or_copy(
+        &self,
+        str: &str,
+        not_interned: RiOutcome,
+    ) -> (AnyStr<TReIntegrator::TBin>, RiOutcome) {
+        if let Some(string) = TReIntegrator::intern(&self.context, str) {
+            let missed = not_interned == RiOutcome::Missed;
+            (string, RiOutcome::Interned { missed })
+        } else {
+            (TReIntegrator::copy_str(str), not_interned)
         }
     }
 }
 
 pub trait StrReIntegrator {
     type TBin: AnyBin;
-    fn re_integrate_str(str: &str) -> AnyStr<Self::TBin>;
+    /// Tries to re-integrate the string (it's borrowed from the de-serializer input).
+    fn try_re_integrate_str(context: &RiContext<'_>, str: &str) -> Option<AnyStr<Self::TBin>>;
+    /// The string can't be re-integrated (e.g. a scratch buffer of the de-serializer).
+    fn copy_str(str: &str) -> AnyStr<Self::TBin>;
     fn re_integrate_string(string: String) -> AnyStr<Self::TBin>;
+    /// Interns the string (`None` if there's no interner; see `ScopedRiSetup::with_interner`).
+    fn intern(context: &RiContext<'_>, str: &str) -> Option<AnyStr<Self::TBin>>;
 }
 
-impl<'de, TReIntegrator> Visitor<'de> for ReIntegrationStrVisitor<TReIntegrator>
+impl<'de, 'a, TReIntegrator> Visitor<'de> for ReIntegrationStrVisitor<'a, TReIntegrator>
 where
     TReIntegrator: StrReIntegrator,
 {
@@ -35,12 +61,33 @@ where
         formatter.write_str("expecting a string")
     }
 
+    #[inline]
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let (string, outcome) =
+            if let Some(string) = TReIntegrator::try_re_integrate_str(&self.context, v) {
+                // nice, could re-integrate (unless the slice policy copied it)
+                let outcome = RiOutcome::re_integrated(string.as_bin().as_slice(), v.as_bytes());
+                (string, outcome)
+            } else {
+                // bad!
+                self.intern_or_copy(v, RiOutcome::Missed)
+            };
+        self.context
+            .record(RiPath::BorrowedStr, outcome, string.as_bin());
+        Ok(string)
+    }
+
     #[inline]
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: de::Error,
     {
-        Ok(TReIntegrator::re_integrate_str(v))
+        let (string, outcome) = self.intern_or_copy(v, RiOutcome::NotTried);
+        self.context.record(RiPath::Str, outcome, string.as_bin());
+        Ok(string)
     }
 
     #[inline]
@@ -48,6 +95,13 @@ where
     where
         E: de::Error,
     {
-        Ok(TReIntegrator::re_integrate_string(v))
+        let (string, outcome) = if let Some(string) = TReIntegrator::intern(&self.context, &v) {
+            (string, RiOutcome::Interned { missed: false })
+        } else {
+            (TReIntegrator::re_integrate_string(v), RiOutcome::NotTried)
+        };
+        self.context
+            .record(RiPath::String, outcome, string.as_bin());
+        Ok(string)
     }
 }
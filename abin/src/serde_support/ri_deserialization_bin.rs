@@ -1,10 +1,16 @@
 use serde::Deserializer;
 
-use crate::serde_support::{ReIntegrationBytesVisitor, ReIntegrator, RiScope};
-use crate::{AnyRc, ArcBin, Bin, RcBin, SBin};
+use crate::serde_support::{ReIntegrationBytesVisitor, ReIntegrator, RiContext, ScopedRiSetup};
+use crate::{AnyRc, ArcBin, Bin, BinTextEncoding, DecodeError, NewBin, NewSBin, RcBin, SBin};
 
 /// Performs re-integration de-serialization for `Bin`, see `#[serde(deserialize_with = "path")]`.
 ///
+/// In human-readable formats (such as JSON), the binary is text-encoded (see `BinTextEncoding`);
+/// it's decoded (so there's nothing to re-integrate).
+///
+/// This uses the thread-local scope (see `ScopedRiSetup::scoped`); `RiSeed` passes the setup
+/// explicitly instead.
+///
 /// ```rust
 /// use serde::{Deserialize, Serialize};
 /// use abin::Bin;
@@ -20,7 +26,7 @@ pub fn ri_deserialize_bin<'de, D>(deserialize: D) -> Result<Bin, D::Error>
 where
     D: Deserializer<'de>,
 {
-    deserialize.deserialize_bytes(ReIntegrationBytesVisitor::<BinReIntegrator>::new())
+    deserialize_with_re_integrator::<_, BinReIntegrator>(deserialize, RiContext::thread_local())
 }
 
 /// Performs re-integration de-serialization for `SBin`, see `#[serde(deserialize_with = "path")]`.
@@ -40,7 +46,54 @@ pub fn ri_deserialize_sbin<'de, D>(deserialize: D) -> Result<SBin, D::Error>
 where
     D: Deserializer<'de>,
 {
-    deserialize.deserialize_bytes(ReIntegrationBytesVisitor::<SyncBinReIntegrator>::new())
+    deserialize_with_re_integrator::<_, SyncBinReIntegrator>(deserialize, RiContext::thread_local())
+}
+
+/// Like `ri_deserialize_bin` but uses the given setup (instead of the thread-local scope).
+pub(crate) fn ri_deserialize_bin_with_setup<'de, D>(
+    deserialize: D,
+    setup: &ScopedRiSetup<'_>,
+) -> Result<Bin, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_with_re_integrator::<_, BinReIntegrator>(deserialize, RiContext::with_setup(setup))
+}
+
+/// Like `ri_deserialize_sbin` but uses the given setup (instead of the thread-local scope).
+pub(crate) fn ri_deserialize_sbin_with_setup<'de, D>(
+    deserialize: D,
+    setup: &ScopedRiSetup<'_>,
+) -> Result<SBin, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_with_re_integrator::<_, SyncBinReIntegrator>(
+        deserialize,
+        RiContext::with_setup(setup),
+    )
+}
+
+fn deserialize_with_re_integrator<'de, D, TReIntegrator>(
+    deserialize: D,
+    context: RiContext<'_>,
+) -> Result<TReIntegrator::TBin, D::Error>
+where
+    D: Deserializer<'de>,
+    TReIntegrator: ReIntegrator,
+{
+    if deserialize.is_human_readable() {
+        let encoding = BinTextEncoding::default_encoding();
+        // 'any': legacy data might contain an array of numbers (instead of text).
+        deserialize.deserialize_any(ReIntegrationBytesVisitor::<TReIntegrator>::new(
+            context,
+            Some(encoding),
+        ))
+    } else {
+        deserialize.deserialize_bytes(ReIntegrationBytesVisitor::<TReIntegrator>::new(
+            context, None,
+        ))
+    }
 }
 
 /// re-integrator for `Bin`.
@@ -50,14 +103,13 @@ impl ReIntegrator for BinReIntegrator {
     type TBin = Bin;
 
     #[inline]
-    fn re_integrate(slice: &[u8]) -> Self::TBin {
-        if let Some(bin) = RiScope::try_re_integrate(slice) {
-            // nice, could re-integrate
-            bin
-        } else {
-            // bad!
-            RcBin::copy_from_slice(slice)
-        }
+    fn try_re_integrate(context: &RiContext<'_>, slice: &[u8]) -> Option<Self::TBin> {
+        context.try_re_integrate(slice)
+    }
+
+    #[inline]
+    fn copy(slice: &[u8]) -> Self::TBin {
+        RcBin::copy_from_slice(slice)
     }
 
     #[inline]
@@ -66,6 +118,11 @@ impl ReIntegrator for BinReIntegrator {
         RcBin::from_vec(vec)
     }
 
+    #[inline]
+    fn decode(text: &str, encoding: BinTextEncoding) -> Result<Self::TBin, DecodeError> {
+        encoding.decode::<NewBin>(text)
+    }
+
     #[inline]
     fn overhead_bytes_for_vec() -> usize {
         RcBin::overhead_bytes()
@@ -79,14 +136,13 @@ impl ReIntegrator for SyncBinReIntegrator {
     type TBin = SBin;
 
     #[inline]
-    fn re_integrate(slice: &[u8]) -> Self::TBin {
-        if let Some(bin) = RiScope::try_re_integrate_sync(slice) {
-            // nice, could re-integrate
-            bin
-        } else {
-            // bad!
-            ArcBin::copy_from_slice(slice)
-        }
+    fn try_re_integrate(context: &RiContext<'_>, slice: &[u8]) -> Option<Self::TBin> {
+        context.try_re_integrate_sync(slice)
+    }
+
+    #[inline]
+    fn copy(slice: &[u8]) -> Self::TBin {
+        ArcBin::copy_from_slice(slice)
     }
 
     #[inline]
@@ -95,6 +151,11 @@ impl ReIntegrator for SyncBinReIntegrator {
         ArcBin::from_vec(vec)
     }
 
+    #[inline]
+    fn decode(text: &str, encoding: BinTextEncoding) -> Result<Self::TBin, DecodeError> {
+        encoding.decode::<NewSBin>(text)
+    }
+
     #[inline]
     fn overhead_bytes_for_vec() -> usize {
         ArcBin::overhead_bytes()
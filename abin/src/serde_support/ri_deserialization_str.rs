@@ -1,6 +1,6 @@
 use serde::Deserializer;
 
-use crate::serde_support::RiScope;
+use crate::serde_support::{RiContext, ScopedRiSetup};
 use crate::{
     AnyStr, Bin, NewSStr, NewStr, ReIntegrationStrVisitor, SBin, SStr, Str, StrFactory,
     StrReIntegrator,
@@ -8,6 +8,10 @@ use crate::{
 
 /// Performs re-integration de-serialization for `Str`, see `#[serde(deserialize_with = "path")]`.
 ///
+/// This uses the thread-local scope (see `ScopedRiSetup::scoped`); `RiSeed` passes the setup
+/// explicitly instead. Strings that can't be re-integrated are interned if the setup has an
+/// interner (see `ScopedRiSetup::with_interner`).
+///
 /// ```rust
 /// use abin::Str;
 /// use serde::{Serialize, Deserialize};
@@ -23,27 +27,57 @@ pub fn ri_deserialize_str<'de, D>(deserialize: D) -> Result<Str, D::Error>
 where
     D: Deserializer<'de>,
 {
-    deserialize.deserialize_str(ReIntegrationStrVisitor::<NonSyncStrReIntegrator>::new())
+    deserialize.deserialize_str(ReIntegrationStrVisitor::<NonSyncStrReIntegrator>::new(
+        RiContext::thread_local(),
+    ))
 }
 
 /// Performs re-integration de-serialization for `SStr`, see `#[serde(deserialize_with = "path")]`.
 ///
 /// ```rust
-/// use abin::SyncStr;
+/// use abin::SStr;
 /// use serde::{Serialize, Deserialize};
 ///
 /// #[derive(Deserialize, Serialize)]
 /// pub struct ServerRequest {
 ///     pub request_id: u64,
 ///     #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
-///     pub user_name: SyncStr,
+///     pub user_name: SStr,
 /// }
 /// ```
 pub fn ri_deserialize_sstr<'de, D>(deserialize: D) -> Result<SStr, D::Error>
 where
     D: Deserializer<'de>,
 {
-    deserialize.deserialize_str(ReIntegrationStrVisitor::<SyncStrReIntegrator>::new())
+    deserialize.deserialize_str(ReIntegrationStrVisitor::<SyncStrReIntegrator>::new(
+        RiContext::thread_local(),
+    ))
+}
+
+/// Like `ri_deserialize_str` but uses the given setup (instead of the thread-local scope).
+pub(crate) fn ri_deserialize_str_with_setup<'de, D>(
+    deserialize: D,
+    setup: &ScopedRiSetup<'_>,
+) -> Result<Str, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize.deserialize_str(ReIntegrationStrVisitor::<NonSyncStrReIntegrator>::new(
+        RiContext::with_setup(setup),
+    ))
+}
+
+/// Like `ri_deserialize_sstr` but uses the given setup (instead of the thread-local scope).
+pub(crate) fn ri_deserialize_sstr_with_setup<'de, D>(
+    deserialize: D,
+    setup: &ScopedRiSetup<'_>,
+) -> Result<SStr, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize.deserialize_str(ReIntegrationStrVisitor::<SyncStrReIntegrator>::new(
+        RiContext::with_setup(setup),
+    ))
 }
 
 /// re-integrator for `Str`.
@@ -52,20 +86,24 @@ struct NonSyncStrReIntegrator {}
 impl StrReIntegrator for NonSyncStrReIntegrator {
     type TBin = Bin;
 
-    fn re_integrate_str(str: &str) -> AnyStr<Self::TBin> {
-        if let Some(bin) = RiScope::try_re_integrate(str.as_bytes()) {
-            // nice, could re-integrate
-            unsafe { Str::from_utf8_unchecked(bin) }
-        } else {
-            // bad!
-            NewStr::copy_from_str(str)
-        }
+    fn try_re_integrate_str(context: &RiContext<'_>, str: &str) -> Option<AnyStr<Self::TBin>> {
+        context
+            .try_re_integrate(str.as_bytes())
+            .map(|bin| unsafe { Str::from_utf8_unchecked(bin) })
+    }
+
+    fn copy_str(str: &str) -> AnyStr<Self::TBin> {
+        NewStr::copy_from_str(str)
     }
 
     fn re_integrate_string(string: String) -> AnyStr<Self::TBin> {
         // can't do much here...
         NewStr::from_given_string(string)
     }
+
+    fn intern(context: &RiContext<'_>, str: &str) -> Option<AnyStr<Self::TBin>> {
+        context.intern(str)
+    }
 }
 
 /// re-integrator for `SyncStr`.
@@ -74,18 +112,22 @@ struct SyncStrReIntegrator {}
 impl StrReIntegrator for SyncStrReIntegrator {
     type TBin = SBin;
 
-    fn re_integrate_str(str: &str) -> AnyStr<Self::TBin> {
-        if let Some(bin) = RiScope::try_re_integrate_sync(str.as_bytes()) {
-            // nice, could re-integrate
-            unsafe { SStr::from_utf8_unchecked(bin) }
-        } else {
-            // bad!
-            NewSStr::copy_from_str(str)
-        }
+    fn try_re_integrate_str(context: &RiContext<'_>, str: &str) -> Option<AnyStr<Self::TBin>> {
+        context
+            .try_re_integrate_sync(str.as_bytes())
+            .map(|bin| unsafe { SStr::from_utf8_unchecked(bin) })
+    }
+
+    fn copy_str(str: &str) -> AnyStr<Self::TBin> {
+        NewSStr::copy_from_str(str)
     }
 
     fn re_integrate_string(string: String) -> AnyStr<Self::TBin> {
         // can't do much here...
         NewSStr::from_given_string(string)
     }
+
+    fn intern(context: &RiContext<'_>, str: &str) -> Option<AnyStr<Self::TBin>> {
+        context.intern_sync(str)
+    }
 }
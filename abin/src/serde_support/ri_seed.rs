@@ -0,0 +1,497 @@
+use core::cmp::min;
+use core::fmt;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, Visitor};
+use serde::{de, Deserialize, Deserializer};
+
+use crate::serde_support::{
+    ri_deserialize_bin_with_setup, ri_deserialize_sbin_with_setup, ri_deserialize_sstr_with_setup,
+    ri_deserialize_str_with_setup, ScopedRiSetup,
+};
+use crate::{Bin, SBin, SStr, Str};
+
+/// Limits the initial capacity of vectors (in case there's a problem with the input data).
+const SAFE_MAX_CAPACITY: usize = 4 * 1024;
+
+/// A type that can be de-serialized using re-integration where the re-integration context
+/// (`ScopedRiSetup`) is passed explicitly (see `RiSeed`) - instead of using the thread-local
+/// scope (see `ScopedRiSetup::scoped` and `ri_deserialize_bin`).
+///
+/// It's implemented for `Bin`, `SBin`, `Str`, `SStr`, `Vec<T>`, `Option<T>`, `Box<T>` and for
+/// the primitive types (and `String`); use `ri_deserialize_struct!` to implement it for
+/// structs.
+pub trait RiDeserialize<'de>: Sized {
+    /// De-serializes the value; nested values get the same setup.
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    /// Called if the value is missing in a struct (see `ri_deserialize_struct!`). This returns
+    /// an error by default; `Option` returns `None`.
+    fn ri_missing_field<E: de::Error>(field: &'static str) -> Result<Self, E> {
+        Err(E::missing_field(field))
+    }
+}
+
+/// A `DeserializeSeed` that carries the re-integration context (`ScopedRiSetup`) explicitly.
+///
+/// Unlike `ScopedRiSetup::scoped` (which stores the setup in a thread-local) this also works if
+/// the de-serialization is not bound to a thread (e.g. inside an async task that's moved between
+/// worker threads).
+///
+/// ```rust
+/// use abin::{
+///     ri_deserialize_struct, AnyBin, BinFactory, DefaultScopes, NewSBin, NewSStr, SBin, SStr,
+///     StrFactory,
+/// };
+/// use serde::de::DeserializeSeed;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Request {
+///     id: u64,
+///     name: SStr,
+///     payloads: Vec<SBin>,
+///     comment: Option<SStr>,
+/// }
+/// ri_deserialize_struct!(Request { id, name, payloads, comment });
+///
+/// let request = Request {
+///     id: 7,
+///     name: NewSStr::from_static("some name that is too long to be stored on the stack"),
+///     payloads: vec![NewSBin::copy_from_slice(&[15u8; 200])],
+///     comment: None,
+/// };
+/// let message = NewSBin::from_given_vec(serde_cbor::to_vec(&request).unwrap());
+///
+/// let setup = DefaultScopes::sync(&message);
+/// let mut deserializer = serde_cbor::Deserializer::from_slice(message.as_slice());
+/// let restored: Request = setup.seed().deserialize(&mut deserializer).unwrap();
+/// assert_eq!(request.name, restored.name);
+/// assert_eq!(request.payloads, restored.payloads);
+/// ```
+pub struct RiSeed<'a, T> {
+    setup: &'a ScopedRiSetup<'a>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> RiSeed<'a, T> {
+    #[inline]
+    pub fn new(setup: &'a ScopedRiSetup<'a>) -> Self {
+        Self {
+            setup,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Clone for RiSeed<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for RiSeed<'a, T> {}
+
+impl<'de, 'a, T> DeserializeSeed<'de> for RiSeed<'a, T>
+where
+    T: RiDeserialize<'de>,
+{
+    type Value = T;
+
+    #[inline]
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::ri_deserialize(self.setup, deserializer)
+    }
+}
+
+impl<'a> ScopedRiSetup<'a> {
+    /// Returns a seed for de-serializing `T` using this setup; see `RiSeed`.
+    #[inline]
+    pub fn seed<T>(&self) -> RiSeed<'_, T> {
+        RiSeed::new(self)
+    }
+}
+
+impl<'de> RiDeserialize<'de> for Bin {
+    #[inline]
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ri_deserialize_bin_with_setup(deserializer, setup)
+    }
+}
+
+impl<'de> RiDeserialize<'de> for SBin {
+    #[inline]
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ri_deserialize_sbin_with_setup(deserializer, setup)
+    }
+}
+
+impl<'de> RiDeserialize<'de> for Str {
+    #[inline]
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ri_deserialize_str_with_setup(deserializer, setup)
+    }
+}
+
+impl<'de> RiDeserialize<'de> for SStr {
+    #[inline]
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ri_deserialize_sstr_with_setup(deserializer, setup)
+    }
+}
+
+impl<'de, T> RiDeserialize<'de> for Vec<T>
+where
+    T: RiDeserialize<'de>,
+{
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(VecVisitor {
+            seed: RiSeed::<T>::new(setup),
+        })
+    }
+}
+
+impl<'de, T> RiDeserialize<'de> for Option<T>
+where
+    T: RiDeserialize<'de>,
+{
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor {
+            seed: RiSeed::<T>::new(setup),
+        })
+    }
+
+    #[inline]
+    fn ri_missing_field<E: de::Error>(_field: &'static str) -> Result<Self, E> {
+        Ok(None)
+    }
+}
+
+impl<'de, T> RiDeserialize<'de> for Box<T>
+where
+    T: RiDeserialize<'de>,
+{
+    #[inline]
+    fn ri_deserialize<D>(setup: &ScopedRiSetup<'_>, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::ri_deserialize(setup, deserializer).map(Box::new)
+    }
+}
+
+/// Types without binaries / strings to re-integrate.
+macro_rules! ri_deserialize_plain {
+    ($($ty:ty),*) => {
+        $(
+            impl<'de> RiDeserialize<'de> for $ty {
+                #[inline]
+                fn ri_deserialize<D>(
+                    _setup: &ScopedRiSetup<'_>,
+                    deserializer: D,
+                ) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    <$ty>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+ri_deserialize_plain!(
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    char,
+    String,
+    ()
+);
+
+struct VecVisitor<'a, T> {
+    seed: RiSeed<'a, T>,
+}
+
+impl<'de, 'a, T> Visitor<'de> for VecVisitor<'a, T>
+where
+    T: RiDeserialize<'de>,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("expecting a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let capacity = min(seq.size_hint().unwrap_or(0), SAFE_MAX_CAPACITY);
+        let mut values = Vec::with_capacity(capacity);
+        while let Some(value) = seq.next_element_seed(self.seed)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct OptionVisitor<'a, T> {
+    seed: RiSeed<'a, T>,
+}
+
+impl<'de, 'a, T> Visitor<'de> for OptionVisitor<'a, T>
+where
+    T: RiDeserialize<'de>,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("expecting an optional value")
+    }
+
+    #[inline]
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    #[inline]
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.seed.deserialize(deserializer).map(Some)
+    }
+}
+
+/// Implements `RiDeserialize` for a struct (with named fields; all field types must implement
+/// `RiDeserialize`). Fields are matched by name (or by position for formats that serialize
+/// structs as sequences); the struct can still derive `Serialize`.
+///
+/// ```rust
+/// use abin::{ri_deserialize_struct, Bin, Str};
+///
+/// struct Message {
+///     id: u32,
+///     sender: Str,
+///     attachments: Vec<Bin>,
+/// }
+/// ri_deserialize_struct!(Message { id, sender, attachments });
+/// ```
+#[macro_export]
+macro_rules! ri_deserialize_struct {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl<'de> $crate::RiDeserialize<'de> for $name {
+            fn ri_deserialize<D>(
+                setup: &$crate::ScopedRiSetup<'_>,
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__ri_private::serde::Deserializer<'de>,
+            {
+                use $crate::__ri_private::serde::de::{self, Error as _};
+
+                const FIELDS: &[&str] = &[$(::core::stringify!($field)),*];
+
+                struct StructVisitor<'a> {
+                    setup: &'a $crate::ScopedRiSetup<'a>,
+                }
+
+                impl<'de, 'a> de::Visitor<'de> for StructVisitor<'a> {
+                    type Value = $name;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        formatter.write_str(::core::concat!("struct ", ::core::stringify!($name)))
+                    }
+
+                    #[allow(unused_mut, unused_variables, unused_assignments)]
+                    fn visit_seq<A>(self, mut seq: A) -> ::core::result::Result<$name, A::Error>
+                    where
+                        A: de::SeqAccess<'de>,
+                    {
+                        let mut index = 0usize;
+                        $(
+                            let $field = seq
+                                .next_element_seed($crate::RiSeed::new(self.setup))?
+                                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+                            index += 1;
+                        )*
+                        ::core::result::Result::Ok($name { $($field),* })
+                    }
+
+                    #[allow(unused_mut)]
+                    fn visit_map<A>(self, mut map: A) -> ::core::result::Result<$name, A::Error>
+                    where
+                        A: de::MapAccess<'de>,
+                    {
+                        $(let mut $field = ::core::option::Option::None;)*
+                        while let ::core::option::Option::Some(key) =
+                            map.next_key_seed($crate::__ri_private::FieldKey::new(FIELDS))?
+                        {
+                            $(
+                                if key == ::core::option::Option::Some(::core::stringify!($field)) {
+                                    if $field.is_some() {
+                                        return ::core::result::Result::Err(
+                                            A::Error::duplicate_field(::core::stringify!($field)),
+                                        );
+                                    }
+                                    $field = ::core::option::Option::Some(
+                                        map.next_value_seed($crate::RiSeed::new(self.setup))?,
+                                    );
+                                    continue;
+                                }
+                            )*
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                        ::core::result::Result::Ok($name {
+                            $(
+                                $field: match $field {
+                                    ::core::option::Option::Some(value) => value,
+                                    ::core::option::Option::None => {
+                                        $crate::RiDeserialize::ri_missing_field(
+                                            ::core::stringify!($field),
+                                        )?
+                                    }
+                                },
+                            )*
+                        })
+                    }
+                }
+
+                deserializer.deserialize_struct(
+                    ::core::stringify!($name),
+                    FIELDS,
+                    StructVisitor { setup },
+                )
+            }
+        }
+    };
+}
+
+/// Implementation details of `ri_deserialize_struct!`; not part of the public API.
+#[doc(hidden)]
+pub mod __ri_private {
+    use core::convert::TryFrom;
+    use core::fmt;
+    use std::fmt::Formatter;
+
+    use serde::de::{DeserializeSeed, Visitor};
+    use serde::{de, Deserializer};
+
+    pub use serde;
+
+    /// De-serializes a struct field identifier (without allocation); returns `None` for
+    /// unknown fields.
+    pub struct FieldKey {
+        fields: &'static [&'static str],
+    }
+
+    impl FieldKey {
+        #[inline]
+        pub fn new(fields: &'static [&'static str]) -> Self {
+            Self { fields }
+        }
+
+        fn find(&self, name: &[u8]) -> Option<&'static str> {
+            self.fields
+                .iter()
+                .find(|field| field.as_bytes() == name)
+                .copied()
+        }
+    }
+
+    impl<'de> DeserializeSeed<'de> for FieldKey {
+        type Value = Option<&'static str>;
+
+        #[inline]
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for FieldKey {
+        type Value = Option<&'static str>;
+
+        fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+            formatter.write_str("expecting a field identifier")
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(usize::try_from(v)
+                .ok()
+                .and_then(|index| self.fields.get(index))
+                .copied())
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(self.find(v.as_bytes()))
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(self.find(v))
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{AnyBin, StorageKind};
+
+/// Re-integration diagnostics: What happened to the de-serialized binaries and strings, broken
+/// down by the visitor path (see `ScopedRiSetup::scoped_with_stats`).
+///
+/// Use this in tests to make sure the serde models actually benefit from re-integration (e.g.
+/// CBOR indefinite-length strings are never borrowed; they end up in `seq` or `string`).
+///
+/// ```rust
+/// use abin::{AnyBin, BinFactory, DefaultScopes, NewSBin, SBin};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Message {
+///     #[serde(deserialize_with = "abin::ri_deserialize_sbin")]
+///     payload: SBin,
+/// }
+///
+/// let message = Message {
+///     payload: NewSBin::copy_from_slice(&[42u8; 100]),
+/// };
+/// let serialized = NewSBin::from_given_vec(serde_cbor::to_vec(&message).unwrap());
+/// let (_, stats) = DefaultScopes::sync(&serialized).scoped_with_stats(|| {
+///     serde_cbor::from_slice::<Message>(serialized.as_slice()).unwrap()
+/// });
+/// assert_eq!(1, stats.borrowed_bytes.re_integrated);
+/// assert_eq!(100, stats.total().re_integrated_bytes);
+/// assert_eq!(0, stats.total().copied);
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RiStats {
+    /// Bytes borrowed from the de-serializer input (`Visitor::visit_borrowed_bytes`).
+    pub borrowed_bytes: RiPathStats,
+    /// Strings borrowed from the de-serializer input (`Visitor::visit_borrowed_str`).
+    pub borrowed_str: RiPathStats,
+    /// Transient bytes (`Visitor::visit_bytes`); can't be re-integrated.
+    pub bytes: RiPathStats,
+    /// Transient strings (`Visitor::visit_str`); can't be re-integrated.
+    pub str: RiPathStats,
+    /// Bytes owned by the de-serializer (`Visitor::visit_byte_buf`).
+    pub byte_buf: RiPathStats,
+    /// Strings owned by the de-serializer (`Visitor::visit_string`).
+    pub string: RiPathStats,
+    /// Binaries de-serialized as a sequence of bytes (`Visitor::visit_seq`).
+    pub seq: RiPathStats,
+    /// Text-encoded binaries in human-readable formats (see `BinTextEncoding`); those are
+    /// decoded.
+    pub text_encoded: RiPathStats,
+}
+
+/// Re-integration diagnostics for one visitor path; see `RiStats`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RiPathStats {
+    /// Number of values that have been re-integrated (zero-copy).
+    pub re_integrated: usize,
+    /// Number of bytes that have been re-integrated.
+    pub re_integrated_bytes: usize,
+    /// Number of values that have not been re-integrated but have been stored without heap
+    /// allocation (stack or empty).
+    pub stack: usize,
+    /// Number of values that have not been re-integrated and are stored on the heap (copied or
+    /// taken over from the de-serializer). This includes values that have been found in a
+    /// source binary but have been copied anyway because of the slice policy (see
+    /// `DefaultScopes::sync_with_policy`).
+    pub copied: usize,
+    /// Number of bytes of the `copied` values.
+    pub copied_bytes: usize,
+    /// Number of strings that have not been re-integrated but have been served by the interner
+    /// (see `ScopedRiSetup::with_interner`); those are not counted in `copied`.
+    pub interned: usize,
+    /// Number of values where re-integration has been tried but failed (no source binary
+    /// contains the slice); those are also counted in `stack`, `copied` or `interned`.
+    pub misses: usize,
+}
+
+impl RiStats {
+    /// The sum of all visitor paths.
+    pub fn total(&self) -> RiPathStats {
+        let mut total = RiPathStats::default();
+        for path in &[
+            self.borrowed_bytes,
+            self.borrowed_str,
+            self.bytes,
+            self.str,
+            self.byte_buf,
+            self.string,
+            self.seq,
+            self.text_encoded,
+        ] {
+            total.merge(path);
+        }
+        total
+    }
+
+    /// Adds the given stats to these stats.
+    pub fn merge(&mut self, other: &RiStats) {
+        self.borrowed_bytes.merge(&other.borrowed_bytes);
+        self.borrowed_str.merge(&other.borrowed_str);
+        self.bytes.merge(&other.bytes);
+        self.str.merge(&other.str);
+        self.byte_buf.merge(&other.byte_buf);
+        self.string.merge(&other.string);
+        self.seq.merge(&other.seq);
+        self.text_encoded.merge(&other.text_encoded);
+    }
+
+    pub(crate) fn record(&mut self, path: RiPath, outcome: RiOutcome, bin: &impl AnyBin) {
+        let stats = match path {
+            RiPath::BorrowedBytes => &mut self.borrowed_bytes,
+            RiPath::BorrowedStr => &mut self.borrowed_str,
+            RiPath::Bytes => &mut self.bytes,
+            RiPath::Str => &mut self.str,
+            RiPath::ByteBuf => &mut self.byte_buf,
+            RiPath::String => &mut self.string,
+            RiPath::Seq => &mut self.seq,
+            RiPath::TextEncoded => &mut self.text_encoded,
+        };
+        stats.record(outcome, bin);
+    }
+}
+
+impl RiPathStats {
+    /// Adds the given stats to these stats.
+    pub fn merge(&mut self, other: &RiPathStats) {
+        self.re_integrated += other.re_integrated;
+        self.re_integrated_bytes += other.re_integrated_bytes;
+        self.stack += other.stack;
+        self.copied += other.copied;
+        self.copied_bytes += other.copied_bytes;
+        self.interned += other.interned;
+        self.misses += other.misses;
+    }
+
+    fn record(&mut self, outcome: RiOutcome, bin: &impl AnyBin) {
+        let interned = match outcome {
+            RiOutcome::ReIntegrated => {
+                self.re_integrated += 1;
+                self.re_integrated_bytes += bin.len();
+                return;
+            }
+            RiOutcome::PolicyCopied | RiOutcome::NotTried => false,
+            RiOutcome::Missed => {
+                self.misses += 1;
+                false
+            }
+            RiOutcome::Interned { missed } => {
+                if missed {
+                    self.misses += 1;
+                }
+                true
+            }
+        };
+        match bin.storage_kind() {
+            StorageKind::Empty | StorageKind::Stack => self.stack += 1,
+            _ if interned => self.interned += 1,
+            _ => {
+                self.copied += 1;
+                self.copied_bytes += bin.len();
+            }
+        }
+    }
+}
+
+/// The visitor path; see the fields of `RiStats`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum RiPath {
+    BorrowedBytes,
+    BorrowedStr,
+    Bytes,
+    Str,
+    ByteBuf,
+    String,
+    Seq,
+    TextEncoded,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum RiOutcome {
+    /// re-integrated: shares the buffer of the source binary.
+    ReIntegrated,
+    /// found in a source binary but copied (see `SlicePolicy`).
+    PolicyCopied,
+    /// re-integration has been tried but failed.
+    Missed,
+    /// re-integration is not possible (e.g. transient slices).
+    NotTried,
+    /// not re-integrated; served by the interner (`missed`: re-integration has been tried but
+    /// failed).
+    Interned { missed: bool },
+}
+
+impl RiOutcome {
+    /// The outcome of a successful re-integration: The slice policy might have copied the
+    /// bytes (so the value does not share the de-serializer input).
+    #[inline]
+    pub(crate) fn re_integrated(value: &[u8], input: &[u8]) -> Self {
+        if value.as_ptr() == input.as_ptr() {
+            RiOutcome::ReIntegrated
+        } else {
+            RiOutcome::PolicyCopied
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use core::cell::RefCell;
+use core::cmp::max;
 use core::mem;
 use std::{marker::PhantomData, ops::Deref};
 
-use crate::{Bin, SBin};
+use crate::serde_support::{RiOutcome, RiPath};
+use crate::{AnyBin, Bin, Interner, RiStats, SBin, SStr, Str, SyncInterner};
 
 std::thread_local! {
-  static THREAD_LOCAL_BIN: RefCell<Option<*const ScopedRiSetup<'static>>> = RefCell::new(None);
+  static THREAD_LOCAL_BIN: RefCell<Option<*const ScopedRiSetup<'static>>> = const { RefCell::new(None) };
 }
 
 /// This struct contains functions that give access to the re-integration scope.
@@ -23,15 +25,7 @@ impl RiScope {
     ///   * `ReIntegrationFn` returned `None`.
     #[inline]
     pub fn try_re_integrate(slice: &[u8]) -> Option<Bin> {
-        THREAD_LOCAL_BIN.with(|value| {
-            let borrowed = value.borrow();
-            if let Some(value) = borrowed.deref() {
-                let config: &ScopedRiSetup = unsafe { &**value };
-                (config.re_integration_fn)(&config.binaries, slice)
-            } else {
-                None
-            }
-        })
+        Self::with_setup(|config| config.try_re_integrate(slice)).flatten()
     }
 
     /// Returns the re-integrated binary. See `AnyBin::try_to_re_integrate`.
@@ -42,11 +36,20 @@ impl RiScope {
     ///   * `SyncReIntegrationSync` returned `None`.
     #[inline]
     pub fn try_re_integrate_sync(slice: &[u8]) -> Option<SBin> {
+        Self::with_setup(|config| config.try_re_integrate_sync(slice)).flatten()
+    }
+
+    /// Calls the function with the setup of the current scope (if there's a scope).
+    #[inline]
+    fn with_setup<TFn, TRet>(fun: TFn) -> Option<TRet>
+    where
+        TFn: FnOnce(&ScopedRiSetup) -> TRet,
+    {
         THREAD_LOCAL_BIN.with(|value| {
             let borrowed = value.borrow();
             if let Some(value) = borrowed.deref() {
                 let config: &ScopedRiSetup = unsafe { &**value };
-                (config.sync_re_integration_fn)(&config.binaries, slice)
+                Some(fun(config))
             } else {
                 None
             }
@@ -54,6 +57,74 @@ impl RiScope {
     }
 }
 
+/// The re-integration context: Either an explicit setup (see `RiSeed`) or the thread-local
+/// scope (see `ScopedRiSetup::scoped`).
+#[derive(Copy, Clone)]
+pub(crate) struct RiContext<'a> {
+    setup: Option<&'a ScopedRiSetup<'a>>,
+}
+
+impl<'a> RiContext<'a> {
+    #[inline]
+    pub(crate) fn thread_local() -> Self {
+        Self { setup: None }
+    }
+
+    #[inline]
+    pub(crate) fn with_setup(setup: &'a ScopedRiSetup<'a>) -> Self {
+        Self { setup: Some(setup) }
+    }
+
+    #[inline]
+    pub(crate) fn try_re_integrate(&self, slice: &[u8]) -> Option<Bin> {
+        if let Some(setup) = self.setup {
+            setup.try_re_integrate(slice)
+        } else {
+            RiScope::try_re_integrate(slice)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn try_re_integrate_sync(&self, slice: &[u8]) -> Option<SBin> {
+        if let Some(setup) = self.setup {
+            setup.try_re_integrate_sync(slice)
+        } else {
+            RiScope::try_re_integrate_sync(slice)
+        }
+    }
+
+    /// Interns the string (`None` if there's no interner; see `ScopedRiSetup::with_interner`).
+    #[inline]
+    pub(crate) fn intern(&self, string: &str) -> Option<Str> {
+        if let Some(setup) = self.setup {
+            setup.intern(string)
+        } else {
+            RiScope::with_setup(|config| config.intern(string)).flatten()
+        }
+    }
+
+    /// Interns the string (`None` if there's no interner; see
+    /// `ScopedRiSetup::with_sync_interner`).
+    #[inline]
+    pub(crate) fn intern_sync(&self, string: &str) -> Option<SStr> {
+        if let Some(setup) = self.setup {
+            setup.intern_sync(string)
+        } else {
+            RiScope::with_setup(|config| config.intern_sync(string)).flatten()
+        }
+    }
+
+    /// Records the de-serialized value (if stats are collected; see `RiStats`).
+    #[inline]
+    pub(crate) fn record(&self, path: RiPath, outcome: RiOutcome, bin: &impl AnyBin) {
+        if let Some(setup) = self.setup {
+            setup.record(path, outcome, bin)
+        } else {
+            RiScope::with_setup(|config| config.record(path, outcome, bin));
+        }
+    }
+}
+
 pub type ReIntegrationFn = fn(binaries: &Binaries, slice: &[u8]) -> Option<Bin>;
 pub type SyncReIntegrationSync = fn(binaries: &Binaries, slice: &[u8]) -> Option<SBin>;
 
@@ -62,6 +133,10 @@ pub struct ScopedRiSetup<'a> {
     binaries: Binaries<'a>,
     re_integration_fn: ReIntegrationFn,
     sync_re_integration_fn: SyncReIntegrationSync,
+    interner: Option<&'a Interner>,
+    sync_interner: Option<&'a SyncInterner>,
+    /// `Some` while stats are collected (see `with_stats`).
+    stats: RefCell<Option<RiStats>>,
 }
 
 impl<'a> ScopedRiSetup<'a> {
@@ -74,6 +149,103 @@ impl<'a> ScopedRiSetup<'a> {
             binaries,
             re_integration_fn,
             sync_re_integration_fn,
+            interner: None,
+            sync_interner: None,
+            stats: RefCell::new(None),
+        }
+    }
+
+    /// Strings (`Str`) that can't be re-integrated are interned (instead of allocating a new
+    /// string for every value; see `Interner`). Use this if there's massive repetition in the
+    /// de-serialized strings (that can't be re-integrated, such as escaped JSON strings).
+    ///
+    /// ```rust
+    /// use abin::{AnyBin, BinFactory, DefaultScopes, Interner, NewSBin, Str};
+    ///
+    /// let interner = Interner::new();
+    /// let json = NewSBin::from_static(br#"["a longer \"escaped\" string value", "a longer \"escaped\" string value"]"#);
+    /// let strings = DefaultScopes::sync(&json)
+    ///     .with_interner(&interner)
+    ///     .scoped(|| {
+    ///         #[derive(serde::Deserialize)]
+    ///         struct Wrapper(#[serde(deserialize_with = "abin::ri_deserialize_str")] Str);
+    ///         serde_json::from_slice::<Vec<Wrapper>>(json.as_slice()).unwrap()
+    ///     });
+    /// assert_eq!(r#"a longer "escaped" string value"#, strings[0].0.as_str());
+    /// // shared: both strings and the interner.
+    /// assert_eq!(Some(3), strings[0].0.as_bin().strong_count());
+    /// ```
+    pub fn with_interner(mut self, interner: &'a Interner) -> Self {
+        self.interner = Some(interner);
+        self
+    }
+
+    /// Like `with_interner` but for synchronized strings (`SStr`); see `SyncInterner` (e.g.
+    /// `SyncInterner::global`).
+    pub fn with_sync_interner(mut self, interner: &'a SyncInterner) -> Self {
+        self.sync_interner = Some(interner);
+        self
+    }
+
+    /// Like `RiScope::try_re_integrate` but uses this setup directly (no thread-local scope
+    /// required; see `RiSeed`).
+    #[inline]
+    pub fn try_re_integrate(&self, slice: &[u8]) -> Option<Bin> {
+        (self.re_integration_fn)(&self.binaries, slice)
+    }
+
+    /// Like `RiScope::try_re_integrate_sync` but uses this setup directly (no thread-local scope
+    /// required; see `RiSeed`).
+    #[inline]
+    pub fn try_re_integrate_sync(&self, slice: &[u8]) -> Option<SBin> {
+        (self.sync_re_integration_fn)(&self.binaries, slice)
+    }
+
+    /// Interns the string using the interner of this setup (`None` if there's no interner; see
+    /// `with_interner`).
+    #[inline]
+    pub fn intern(&self, string: &str) -> Option<Str> {
+        self.interner.map(|interner| interner.intern(string))
+    }
+
+    /// Interns the string using the synchronized interner of this setup (`None` if there's no
+    /// interner; see `with_sync_interner`).
+    #[inline]
+    pub fn intern_sync(&self, string: &str) -> Option<SStr> {
+        self.sync_interner.map(|interner| interner.intern(string))
+    }
+
+    /// Like `scoped` but also returns the re-integration diagnostics (see `RiStats`).
+    pub fn scoped_with_stats<TFn, TRet>(&self, fun: TFn) -> (TRet, RiStats)
+    where
+        TFn: FnOnce() -> TRet,
+    {
+        self.with_stats(|| self.scoped(fun))
+    }
+
+    /// Collects re-integration diagnostics (see `RiStats`) of all values de-serialized using
+    /// this setup while the function runs - using the thread-local scope (see `scoped`) or
+    /// explicitly (see `RiSeed`). Can be nested (the outer call gets the stats of the inner
+    /// call too).
+    pub fn with_stats<TFn, TRet>(&self, fun: TFn) -> (TRet, RiStats)
+    where
+        TFn: FnOnce() -> TRet,
+    {
+        let previous = self.stats.replace(Some(RiStats::default()));
+        // restores the previous stats in case the function panics.
+        let mut restore_on_drop = RestoreStatsOnDrop {
+            cell: &self.stats,
+            previous: Some(previous),
+        };
+        let result = fun();
+        let stats = restore_on_drop.restore();
+        (result, stats)
+    }
+
+    #[inline]
+    fn record(&self, path: RiPath, outcome: RiOutcome, bin: &impl AnyBin) {
+        if let Some(stats) = self.stats.borrow_mut().as_mut() {
+            stats.record(path, outcome, bin);
         }
     }
 }
@@ -115,14 +287,93 @@ impl<'a> ScopedRiSetup<'a> {
     }
 }
 
+/// A source binary for re-integration (see `Binaries`).
+#[derive(Debug, Copy, Clone)]
+pub enum RiSource<'a> {
+    Bin(&'a Bin),
+    SBin(&'a SBin),
+}
+
+impl<'a> RiSource<'a> {
+    #[inline]
+    fn as_slice(&self) -> &'a [u8] {
+        match self {
+            RiSource::Bin(bin) => bin.as_slice(),
+            RiSource::SBin(bin) => bin.as_slice(),
+        }
+    }
+}
+
+/// The source binaries for re-integration: A slice can be re-integrated if it's part of one of
+/// those binaries. The binaries can be of mixed sync-ness (e.g. a header received as `Bin` and a
+/// body received as `SBin`).
+///
+/// The sources are sorted by address; finding the source of a slice (see `find`) is a binary
+/// search.
 pub struct Binaries<'a> {
-    bin: Option<&'a Bin>,
-    sync_bin: Option<&'a SBin>,
+    /// sorted by `start`.
+    entries: BinariesEntries<'a>,
+}
+
+/// Most setups have one or two sources; those don't require an allocation.
+enum BinariesEntries<'a> {
+    Empty,
+    One(BinariesEntry<'a>),
+    Two([BinariesEntry<'a>; 2]),
+    Many(Vec<BinariesEntry<'a>>),
+}
+
+impl<'a> BinariesEntries<'a> {
+    fn push(self, entry: BinariesEntry<'a>) -> Self {
+        match self {
+            BinariesEntries::Empty => BinariesEntries::One(entry),
+            BinariesEntries::One(first) => BinariesEntries::Two([first, entry]),
+            BinariesEntries::Two([first, second]) => {
+                BinariesEntries::Many(vec![first, second, entry])
+            }
+            BinariesEntries::Many(mut entries) => {
+                entries.push(entry);
+                BinariesEntries::Many(entries)
+            }
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[BinariesEntry<'a>] {
+        match self {
+            BinariesEntries::Empty => &[],
+            BinariesEntries::One(entry) => std::slice::from_ref(entry),
+            BinariesEntries::Two(entries) => entries,
+            BinariesEntries::Many(entries) => entries,
+        }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [BinariesEntry<'a>] {
+        match self {
+            BinariesEntries::Empty => &mut [],
+            BinariesEntries::One(entry) => std::slice::from_mut(entry),
+            BinariesEntries::Two(entries) => entries,
+            BinariesEntries::Many(entries) => entries,
+        }
+    }
+}
+
+struct BinariesEntry<'a> {
+    start: usize,
+    end: usize,
+    /// the maximum `end` of this entry and all entries before (sources might overlap; e.g. if
+    /// one source is a slice of another source).
+    max_end: usize,
+    source: RiSource<'a>,
 }
 
 impl<'a> Binaries<'a> {
     pub fn new(bin: Option<&'a Bin>, sync_bin: Option<&'a SBin>) -> Self {
-        Self { bin, sync_bin }
+        Self::from_sources(
+            bin.map(RiSource::Bin)
+                .into_iter()
+                .chain(sync_bin.map(RiSource::SBin)),
+        )
     }
 
     pub fn new_bin(bin: &'a Bin) -> Self {
@@ -133,19 +384,82 @@ impl<'a> Binaries<'a> {
         Self::new(None, Some(bin))
     }
 
+    /// Multiple sources of mixed sync-ness.
+    pub fn from_sources(sources: impl IntoIterator<Item = RiSource<'a>>) -> Self {
+        let mut entries = sources
+            .into_iter()
+            .fold(BinariesEntries::Empty, |entries, source| {
+                let range = source.as_slice().as_ptr_range();
+                entries.push(BinariesEntry {
+                    start: range.start as usize,
+                    end: range.end as usize,
+                    max_end: 0,
+                    source,
+                })
+            });
+        let sorted = entries.as_mut_slice();
+        sorted.sort_unstable_by_key(|entry| entry.start);
+        let mut max_end = 0;
+        for entry in sorted.iter_mut() {
+            max_end = max(max_end, entry.end);
+            entry.max_end = max_end;
+        }
+        Self { entries }
+    }
+
+    /// Multiple `Bin` sources.
+    pub fn from_bins(bins: &'a [Bin]) -> Self {
+        Self::from_sources(bins.iter().map(RiSource::Bin))
+    }
+
+    /// Multiple `SBin` sources.
+    pub fn from_sync_bins(bins: &'a [SBin]) -> Self {
+        Self::from_sources(bins.iter().map(RiSource::SBin))
+    }
+
+    /// Returns the source that contains the given slice (`None` if the slice is not part of any
+    /// source). If multiple sources contain the slice, the one with the highest address wins.
+    pub fn find(&self, slice: &[u8]) -> Option<RiSource<'a>> {
+        let range = slice.as_ptr_range();
+        let (start, end) = (range.start as usize, range.end as usize);
+        // the entries that start at or before the slice.
+        let entries = self.entries.as_slice();
+        let candidates = entries.partition_point(|entry| entry.start <= start);
+        entries[..candidates]
+            .iter()
+            .rev()
+            // entries before can't contain the slice if they all end before the slice ends.
+            .take_while(|entry| entry.max_end >= end)
+            .find(|entry| entry.end >= end)
+            .map(|entry| entry.source)
+    }
+
+    /// All sources (sorted by address).
+    pub fn sources(&self) -> impl Iterator<Item = RiSource<'a>> + '_ {
+        self.entries.as_slice().iter().map(|entry| entry.source)
+    }
+
+    /// The first `Bin` source (sorted by address).
     #[inline]
     pub fn bin(&self) -> Option<&'a Bin> {
-        self.bin
+        self.sources().find_map(|source| match source {
+            RiSource::Bin(bin) => Some(bin),
+            RiSource::SBin(_) => None,
+        })
     }
 
+    /// The first `SBin` source (sorted by address).
     #[inline]
     pub fn sync_bin(&self) -> Option<&'a SBin> {
-        self.sync_bin
+        self.sources().find_map(|source| match source {
+            RiSource::Bin(_) => None,
+            RiSource::SBin(bin) => Some(bin),
+        })
     }
 
     #[inline]
     pub fn both(&self) -> (Option<&'a Bin>, Option<&'a SBin>) {
-        (self.bin, self.sync_bin)
+        (self.bin(), self.sync_bin())
     }
 }
 
@@ -168,3 +482,33 @@ impl<'a> Drop for CleanupOnDrop<'a> {
         self.cell.replace(self.previous_value.take());
     }
 }
+
+/// Restores the previous stats (see `ScopedRiSetup::with_stats`); this is required in case the
+/// function panics.
+struct RestoreStatsOnDrop<'a> {
+    cell: &'a RefCell<Option<RiStats>>,
+    /// `None` once restored.
+    previous: Option<Option<RiStats>>,
+}
+
+impl<'a> RestoreStatsOnDrop<'a> {
+    /// Restores the previous stats (and merges the collected stats into them); returns the
+    /// collected stats.
+    fn restore(&mut self) -> RiStats {
+        if let Some(previous) = self.previous.take() {
+            let stats = self.cell.replace(previous).unwrap_or_default();
+            if let Some(outer) = self.cell.borrow_mut().as_mut() {
+                outer.merge(&stats);
+            }
+            stats
+        } else {
+            RiStats::default()
+        }
+    }
+}
+
+impl<'a> Drop for RestoreStatsOnDrop<'a> {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
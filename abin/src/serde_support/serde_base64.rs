@@ -0,0 +1,39 @@
+//! (De-)serializes `Bin` / `SBin` as Base64 (standard alphabet, padded; see `Base64Variant::Standard`) in human-readable formats (such as JSON),
+//! independent of `BinTextEncoding::default_encoding`. Binary formats get the raw bytes.
+//! Use it with `#[serde(with = "abin::serde_base64")]`.
+//!
+//! ```rust
+//! use abin::Bin;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Message {
+//!     #[serde(with = "abin::serde_base64")]
+//!     payload: Bin,
+//! }
+//! ```
+
+use serde::{Deserializer, Serializer};
+
+use crate::serde_support::serialize_bin;
+use crate::{AnyBin, Base64Variant, BinTextEncoding, DeserializeWithEncoding};
+
+const ENCODING: BinTextEncoding = BinTextEncoding::Base64(Base64Variant::Standard);
+
+/// Serializes the binary; see the module documentation.
+pub fn serialize<S, TBin>(bin: &TBin, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    TBin: AnyBin,
+{
+    serialize_bin(bin, serializer, ENCODING)
+}
+
+/// De-serializes the binary; see the module documentation.
+pub fn deserialize<'de, D, TBin>(deserializer: D) -> Result<TBin, D::Error>
+where
+    D: Deserializer<'de>,
+    TBin: DeserializeWithEncoding,
+{
+    TBin::deserialize_with_encoding(deserializer, ENCODING)
+}
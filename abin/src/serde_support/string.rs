@@ -54,7 +54,7 @@ struct RcStrVisitor<T> {
 impl<T> RcStrVisitor<T> {
     pub fn new() -> Self {
         Self {
-            _phantom: PhantomData::default(),
+            _phantom: PhantomData,
         }
     }
 }
@@ -0,0 +1,77 @@
+use crate::binary::{base64_decode, base64_validate, hex_decode, hex_decoded_len};
+use crate::implementation::{decode_base64, decode_hex, CommonFactory};
+use crate::{AnyBin, AnyRc, Base64Variant, DecodeError, Str};
+
+/// The text encoding used to serialize binaries (`Bin`, `SBin`) in human-readable formats (such
+/// as JSON; see `Serializer::is_human_readable`). Binary formats (such as CBOR or bincode) always
+/// get the raw bytes.
+///
+/// The `Serialize` and `Deserialize` implementations of `Bin` and `SBin` (as well as the
+/// re-integration functions such as `ri_deserialize_bin`) always use the default encoding
+/// (`Base64(Base64Variant::Standard)`; see `default_encoding`). To choose another encoding,
+/// use `serde_base64`, `serde_base64_url` or `serde_hex` on the field.
+///
+/// When de-serializing human-readable formats, arrays of numbers (the representation used
+/// before text encoding was introduced) are accepted too.
+///
+/// ```rust
+/// use abin::{BinTextEncoding, Base64Variant};
+/// assert_eq!(
+///     BinTextEncoding::Base64(Base64Variant::Standard),
+///     BinTextEncoding::default_encoding()
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BinTextEncoding {
+    /// Base64; see `AnyBin::to_base64_str`.
+    Base64(Base64Variant),
+    /// Lowercase hex (upper- and lowercase digits are accepted when decoding); see
+    /// `AnyBin::to_hex_str`.
+    Hex,
+}
+
+impl BinTextEncoding {
+    /// The encoding used by the `Serialize` / `Deserialize` implementations of `Bin` and `SBin`
+    /// in human-readable formats.
+    pub const fn default_encoding() -> Self {
+        BinTextEncoding::Base64(Base64Variant::Standard)
+    }
+
+    /// Encodes the given binary.
+    pub(crate) fn encode(self, bin: &impl AnyBin) -> Str {
+        match self {
+            BinTextEncoding::Base64(variant) => bin.to_base64_str(variant),
+            BinTextEncoding::Hex => bin.to_hex_str(),
+        }
+    }
+
+    /// Decodes the given text (the output is sized exactly).
+    pub(crate) fn decode<TCf: CommonFactory>(
+        self,
+        text: &str,
+    ) -> Result<<TCf::TAnyRc as AnyRc>::T, DecodeError> {
+        match self {
+            BinTextEncoding::Base64(variant) => decode_base64::<TCf>(text.as_bytes(), variant),
+            BinTextEncoding::Hex => decode_hex::<TCf>(text.as_bytes()),
+        }
+    }
+
+    /// Decodes the given text and appends the bytes to `out` (`out` is unchanged on error).
+    pub(crate) fn decode_into(self, text: &str, out: &mut Vec<u8>) -> Result<(), DecodeError> {
+        let input = text.as_bytes();
+        let start = out.len();
+        match self {
+            BinTextEncoding::Base64(variant) => {
+                let (symbols, len) = base64_validate(input, variant)?;
+                out.resize(start + len, 0);
+                base64_decode(&input[..symbols], &mut out[start..], variant);
+            }
+            BinTextEncoding::Hex => {
+                let len = hex_decoded_len(input)?;
+                out.resize(start + len, 0);
+                hex_decode(input, &mut out[start..]);
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,30 @@
-use crate::Bin;
+use crate::{Bin, StorageKind};
 
 /// The function table to be implemented for `Bin` types. This is only required if you
 /// implement your own binary type.
+///
+/// Use `FnTable::new` (with struct update syntax) instead of listing all fields: New optional
+/// functions might be added in future versions; those are `None` when using `FnTable::new`.
+///
+/// ```rust
+/// use abin::spi::{BinData, FnTable, UnsafeBin};
+/// use abin::Bin;
+///
+/// const FN_TABLE: FnTable = FnTable {
+///     is_empty: Some(|_| true),
+///     ..FnTable::new(
+///         |_| unsafe { Bin::_new(BinData::empty(), &FN_TABLE) },
+///         |_| Vec::new(),
+///         |_, start, end_excluded| {
+///             if start == 0 && end_excluded == 0 {
+///                 Some(unsafe { Bin::_new(BinData::empty(), &FN_TABLE) })
+///             } else {
+///                 None
+///             }
+///         },
+///     )
+/// };
+/// ```
 pub struct FnTable {
     /// Drop function. It's `None` if dropping is not required.
     pub drop: Option<fn(bin: &mut Bin)>,
@@ -61,8 +84,135 @@ pub struct FnTable {
     /// IMPORTANT: If `bin` is a synchronized binary, the returned binary has to be
     /// synchronized too.
     pub try_re_integrate: Option<TryReIntegrateFn>,
+
+    /// Converts this binary into a vector, but only if this is possible without copying memory
+    /// (for example if this is a reference-counted binary and there are no other references
+    /// to the buffer). Returns the binary unchanged (`Err`) otherwise.
+    ///
+    /// This is `None` if the binary type never owns a buffer that could be handed out (for
+    /// example static binaries or stack binaries). This is used for `IntoBinMut::try_into_mut`.
+    pub try_into_unique_vec: Option<TryIntoUniqueVecFn>,
+
+    /// Returns a mutable slice of this binary, but only if the binary exclusively owns its
+    /// bytes (for example if this is a reference-counted binary and there are no other
+    /// references to the buffer). Returns `None` otherwise. Must never allocate or copy memory.
+    ///
+    /// This is `None` if the binary type never exclusively owns its bytes (for example static
+    /// binaries). This is used for `AnyBin::get_mut` and `AnyBin::make_mut`.
+    pub get_mut: Option<GetMutFn>,
+
+    /// Splits the binary into two at the given index: Afterwards `bin` contains the bytes
+    /// `[at, len)`; the returned binary contains the bytes `[0, at)`. Returns `None` (and leaves
+    /// `bin` unchanged) if `at` is out of bounds.
+    ///
+    /// This is `None` if the binary type has no specialised implementation; in this case
+    /// `slice` is used. This is purely an optimization.
+    ///
+    /// Important: If `bin` is synchronized, the returned `Bin` MUST be synchronized too (and
+    /// `bin` must stay synchronized).
+    pub split_to: Option<SplitFn>,
+
+    /// Splits the binary into two at the given index: Afterwards `bin` contains the bytes
+    /// `[0, at)`; the returned binary contains the bytes `[at, len)`. Returns `None` (and leaves
+    /// `bin` unchanged) if `at` is out of bounds.
+    ///
+    /// This is `None` if the binary type has no specialised implementation; in this case
+    /// `slice` is used. This is purely an optimization.
+    ///
+    /// Important: If `bin` is synchronized, the returned `Bin` MUST be synchronized too (and
+    /// `bin` must stay synchronized).
+    pub split_off: Option<SplitFn>,
+
+    /// Removes the first `cnt` bytes. Returns `false` (and leaves `bin` unchanged) if `cnt`
+    /// is out of bounds.
+    ///
+    /// This is `None` if the binary type has no specialised implementation; in this case
+    /// `slice` is used. This is purely an optimization.
+    pub advance: Option<AdvanceFn>,
+
+    /// Returns how the bytes of this binary are stored. This is used for
+    /// `AnyBin::storage_kind`.
+    ///
+    /// This is `None` if the binary type does not report its storage kind (in this case
+    /// `StorageKind::Custom` is reported).
+    pub storage_kind: Option<StorageKindFn>,
+
+    /// Returns the number of references to the shared buffer (including `bin` itself). This is
+    /// used for `AnyBin::strong_count`.
+    ///
+    /// This is `None` if the binary type is not reference-counted.
+    pub strong_count: Option<StrongCountFn>,
+
+    /// Returns the number of bytes kept alive by this binary: This is the size of the whole
+    /// buffer (for example a small slice of a large reference-counted buffer keeps the entire
+    /// buffer alive), not just the length of the binary. Returns 0 if the binary does not
+    /// hold any buffer (for example stack binaries or static binaries). This is used for
+    /// `AnyBin::retained_bytes`.
+    ///
+    /// This is `None` if the binary type does not report retained memory (in this case the
+    /// length of the binary is reported).
+    pub retained_bytes: Option<RetainedBytesFn>,
+}
+
+impl FnTable {
+    /// Creates a function table with the required functions; all optional functions are
+    /// `None` (no drop, always empty, no specialised implementations). Use struct update syntax
+    /// to provide optional functions.
+    pub const fn new(
+        clone: fn(bin: &Bin) -> Bin,
+        into_vec: fn(bin: Bin) -> Vec<u8>,
+        slice: fn(bin: &Bin, start: usize, end_excluded: usize) -> Option<Bin>,
+    ) -> Self {
+        Self {
+            drop: None,
+            as_slice: None,
+            is_empty: None,
+            clone,
+            into_vec,
+            slice,
+            convert_into_un_sync: None,
+            convert_into_sync: None,
+            try_re_integrate: None,
+            try_into_unique_vec: None,
+            get_mut: None,
+            split_to: None,
+            split_off: None,
+            advance: None,
+            storage_kind: None,
+            strong_count: None,
+            retained_bytes: None,
+        }
+    }
 }
 
 /// Re-integrate function; see `FnTable`. This is only required if you implement your
 /// own binary type.
 pub type TryReIntegrateFn = fn(bin: &Bin, slice: &[u8]) -> Option<Bin>;
+
+/// Function to convert a binary into a vector without copying; see `FnTable`. This is only
+/// required if you implement your own binary type.
+pub type TryIntoUniqueVecFn = fn(bin: Bin) -> Result<Vec<u8>, Bin>;
+
+/// Function to get a mutable slice of a binary; see `FnTable`. This is only required if you
+/// implement your own binary type.
+pub type GetMutFn = fn(bin: &mut Bin) -> Option<&mut [u8]>;
+
+/// Function to split a binary; see `FnTable`. This is only required if you implement your
+/// own binary type.
+pub type SplitFn = fn(bin: &mut Bin, at: usize) -> Option<Bin>;
+
+/// Function to remove bytes from the front of a binary; see `FnTable`. This is only required
+/// if you implement your own binary type.
+pub type AdvanceFn = fn(bin: &mut Bin, cnt: usize) -> bool;
+
+/// Function to get the storage kind of a binary; see `FnTable`. This is only required if you
+/// implement your own binary type.
+pub type StorageKindFn = fn(bin: &Bin) -> StorageKind;
+
+/// Function to get the number of references to the shared buffer; see `FnTable`. This is only
+/// required if you implement your own binary type.
+pub type StrongCountFn = fn(bin: &Bin) -> usize;
+
+/// Function to get the number of bytes kept alive by a binary; see `FnTable`. This is only
+/// required if you implement your own binary type.
+pub type RetainedBytesFn = fn(bin: &Bin) -> usize;
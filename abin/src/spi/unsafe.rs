@@ -7,6 +7,10 @@ use crate::SBin;
 /// Note: Naming (the `_`) is a bit awkward: This is intentional: This trait is implemented
 /// for `Bin` and we want to make sure the IDE does not auto-complete; or the user uses these
 /// functions accidentally.
+///
+/// # Safety
+///
+/// This trait is only implemented by `Bin`; there's no need to implement it yourself.
 pub unsafe trait UnsafeBin {
     /// New binary from given data with given function table.
     ///
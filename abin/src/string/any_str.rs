@@ -3,10 +3,14 @@ use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::{Bound, Deref, RangeBounds};
+use std::ops::{Deref, RangeBounds};
 use std::str::Utf8Error;
 
-use crate::{AnyBin, Bin, IntoSync, IntoUnSync, IntoUnSyncView, SBin};
+use crate::binary::normalize_range;
+use crate::{
+    AnyBin, Bin, IntoSync, IntoUnSync, IntoUnSyncView, SBin, SliceError, SlicePolicy, StrLines,
+    StrPattern, StrSplit, StrSplitN, StrSplitWhitespace,
+};
 
 /// A utf-8 string backed by `AnyBin` (`Bin` or `SBin`), see also `Str` and `SStr`.
 pub struct AnyStr<TBin>(TBin);
@@ -162,40 +166,395 @@ where
     where
         TRange: RangeBounds<usize>,
     {
-        let start = match range.start_bound() {
-            Bound::Included(start) => *start,
-            Bound::Excluded(start) => *start + 1,
-            Bound::Unbounded => 0,
-        };
-        let end_excluded = match range.end_bound() {
-            Bound::Included(end) => *end - 1,
-            Bound::Excluded(end) => *end,
-            Bound::Unbounded => self.len(),
-        };
-        // use str::get to check whether we're within range and lie on UTF-8 boundaries
-        if self.as_str().get(start..end_excluded).is_some() {
-            // ok
-            let sliced_bin = self.as_bin().slice(start..end_excluded);
-            if let Some(sliced_bin) = sliced_bin {
-                // we know it's valid UTF-8 (confirmed by `str::get`).
-                Some(unsafe { Self::from_utf8_unchecked(sliced_bin) })
-            } else {
-                None
-            }
+        self.try_slice(range).ok()
+    }
+
+    /// Returns a slice if the given range is within bounds and lies on UTF-8 boundaries; like
+    /// `slice` but returns a `SliceError` describing why the range is invalid. See also
+    /// `AnyBin::try_slice`.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str, SliceError};
+    /// let str : Str = NewStr::from_static("🗻∈🌏");
+    ///
+    /// assert_eq!("∈", str.try_slice(4..=6).unwrap().as_str());
+    /// assert_eq!(Err(SliceError::NotCharBoundary { index: 1 }), str.try_slice(1..));
+    /// assert_eq!(
+    ///     Err(SliceError::OutOfBounds { len: 11, start: 0, end: 42 }),
+    ///     str.try_slice(..42)
+    /// );
+    /// ```
+    pub fn try_slice<TRange>(&self, range: TRange) -> Result<Self, SliceError>
+    where
+        TRange: RangeBounds<usize>,
+    {
+        let range = normalize_range(&range, self.len())?;
+        let string = self.as_str();
+        if !string.is_char_boundary(range.start) {
+            Err(SliceError::NotCharBoundary { index: range.start })
+        } else if !string.is_char_boundary(range.end) {
+            Err(SliceError::NotCharBoundary { index: range.end })
+        } else {
+            let sliced_bin = self.as_bin().try_slice(range)?;
+            // we know it's valid UTF-8 (confirmed by `str::is_char_boundary`).
+            Ok(unsafe { Self::from_utf8_unchecked(sliced_bin) })
+        }
+    }
+
+    /// Returns a slice (see `slice`); the given policy decides whether the slice shares the
+    /// buffer with `self` or whether the bytes are copied. See `AnyBin::slice_with_policy`.
+    ///
+    /// ```rust
+    /// use abin::{AnyBin, DefaultSlicePolicy, NewStr, StrFactory, Str};
+    ///
+    /// let response : Str = NewStr::copy_from_str("x".repeat(100_000).as_str());
+    /// let small : Str = response.slice_with_policy::<DefaultSlicePolicy, _>(10..100).unwrap();
+    /// assert_eq!(Some(1), response.as_bin().strong_count());
+    /// assert_eq!("x".repeat(90), small.as_str());
+    /// ```
+    pub fn slice_with_policy<TPolicy, TRange>(&self, range: TRange) -> Option<Self>
+    where
+        TPolicy: SlicePolicy,
+        TRange: RangeBounds<usize>,
+    {
+        let range = normalize_range(&range, self.len()).ok()?;
+        let string = self.as_str();
+        if !string.is_char_boundary(range.start) || !string.is_char_boundary(range.end) {
+            None
+        } else {
+            let sliced_bin = self.0.slice_with_policy::<TPolicy, _>(range)?;
+            // we know it's valid UTF-8 (confirmed by `str::is_char_boundary`).
+            Some(unsafe { Self::from_utf8_unchecked(sliced_bin) })
+        }
+    }
+
+    /// Copies the content to a new exact-fit buffer if this string keeps a much larger buffer
+    /// alive. See `AnyBin::compact`.
+    #[inline]
+    pub fn compact(&mut self) -> bool {
+        self.0.compact()
+    }
+
+    /// Like `compact` but the given policy decides whether the content is copied. See
+    /// `AnyBin::compact_with_policy`.
+    #[inline]
+    pub fn compact_with_policy<TPolicy: SlicePolicy>(&mut self) -> bool {
+        self.0.compact_with_policy::<TPolicy>()
+    }
+
+    /// Splits the string into two at the given index. Afterwards `self` contains the bytes
+    /// `[at, len)`, the returned string contains the bytes `[0, at)`. See `AnyBin::split_to`.
+    ///
+    /// Returns `None` (and leaves `self` unchanged) if `at` is out of bounds or if `at` does
+    /// not lie on a UTF-8 boundary (see also `str::is_char_boundary`).
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let mut str : Str = NewStr::from_static("🗻∈🌏");
+    ///
+    /// // not on a UTF-8 boundary
+    /// assert!(str.split_to(1).is_none());
+    ///
+    /// let head : Str = str.split_to(4).unwrap();
+    /// assert_eq!("🗻", head.as_str());
+    /// assert_eq!("∈🌏", str.as_str());
+    /// ```
+    #[inline]
+    pub fn split_to(&mut self, at: usize) -> Option<Self> {
+        if self.as_str().is_char_boundary(at) {
+            // we know it's valid UTF-8 (confirmed by `str::is_char_boundary`).
+            self.0
+                .split_to(at)
+                .map(|bin| unsafe { Self::from_utf8_unchecked(bin) })
         } else {
             None
         }
     }
+
+    /// Splits the string into two at the given index. Afterwards `self` contains the bytes
+    /// `[0, at)`, the returned string contains the bytes `[at, len)`. See `AnyBin::split_off`.
+    ///
+    /// Returns `None` (and leaves `self` unchanged) if `at` is out of bounds or if `at` does
+    /// not lie on a UTF-8 boundary (see also `str::is_char_boundary`).
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let mut str : Str = NewStr::from_static("key=value");
+    /// let value : Str = str.split_off(4).unwrap();
+    /// assert_eq!("key=", str.as_str());
+    /// assert_eq!("value", value.as_str());
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Option<Self> {
+        if self.as_str().is_char_boundary(at) {
+            // we know it's valid UTF-8 (confirmed by `str::is_char_boundary`).
+            self.0
+                .split_off(at)
+                .map(|bin| unsafe { Self::from_utf8_unchecked(bin) })
+        } else {
+            None
+        }
+    }
+
+    /// Removes the first `cnt` bytes. See `AnyBin::advance`.
+    ///
+    /// Returns `false` (and leaves `self` unchanged) if `cnt` is out of bounds or if `cnt` does
+    /// not lie on a UTF-8 boundary (see also `str::is_char_boundary`).
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let mut str : Str = NewStr::from_static("∈🌏");
+    /// assert!(!str.advance(1));
+    /// assert!(str.advance(3));
+    /// assert_eq!("🌏", str.as_str());
+    /// ```
+    #[inline]
+    pub fn advance(&mut self, cnt: usize) -> bool {
+        // we know it's valid UTF-8 (confirmed by `str::is_char_boundary`).
+        self.as_str().is_char_boundary(cnt) && self.0.advance(cnt)
+    }
+
+    /// Returns an iterator over the parts of this string, separated by the given pattern
+    /// (see `StrPattern`; `char`, `&str`, `&[char]` or a closure). Like `str::split` but the
+    /// parts are slices of this string (see `slice`); so no bytes are copied for
+    /// reference-counted or static strings.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("a,b;c");
+    /// let parts : Vec<Str> = str.split_str(|chr| chr == ',' || chr == ';').collect();
+    /// let parts : Vec<&str> = parts.iter().map(Str::as_str).collect();
+    /// assert_eq!(vec!["a", "b", "c"], parts);
+    /// let parts : Vec<Str> = str.split_str(",").collect();
+    /// let parts : Vec<&str> = parts.iter().map(Str::as_str).collect();
+    /// assert_eq!(vec!["a", "b;c"], parts);
+    /// ```
+    pub fn split_str<TPattern>(&self, pattern: TPattern) -> StrSplit<TBin, TPattern>
+    where
+        TPattern: StrPattern,
+    {
+        StrSplit::new(self.clone(), pattern)
+    }
+
+    /// Like `split_str` but returns at most `n` parts; the last part contains the remaining
+    /// string. See also `str::splitn`.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("GET /index.html HTTP/1.1");
+    /// let parts : Vec<Str> = str.splitn_str(2, ' ').collect();
+    /// let parts : Vec<&str> = parts.iter().map(Str::as_str).collect();
+    /// assert_eq!(vec!["GET", "/index.html HTTP/1.1"], parts);
+    /// ```
+    pub fn splitn_str<TPattern>(&self, n: usize, pattern: TPattern) -> StrSplitN<TBin, TPattern>
+    where
+        TPattern: StrPattern,
+    {
+        StrSplitN::new(self.clone(), n, pattern)
+    }
+
+    /// Splits the string at the first occurrence of the given pattern; returns the part before
+    /// and the part after the match. See also `str::split_once`.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("key=value=1");
+    /// let (key, value) = str.split_once_str('=').unwrap();
+    /// assert_eq!("key", key.as_str());
+    /// assert_eq!("value=1", value.as_str());
+    /// assert!(str.split_once_str(':').is_none());
+    /// ```
+    pub fn split_once_str<TPattern>(&self, mut pattern: TPattern) -> Option<(Self, Self)>
+    where
+        TPattern: StrPattern,
+    {
+        let (start, end) = pattern.find_in(self.as_str())?;
+        Some((self.slice(..start)?, self.slice(end..)?))
+    }
+
+    /// Splits the string at the last occurrence of the given pattern; returns the part before
+    /// and the part after the match. See also `str::rsplit_once`.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("key=value=1");
+    /// let (key, value) = str.rsplit_once_str('=').unwrap();
+    /// assert_eq!("key=value", key.as_str());
+    /// assert_eq!("1", value.as_str());
+    /// ```
+    pub fn rsplit_once_str<TPattern>(&self, mut pattern: TPattern) -> Option<(Self, Self)>
+    where
+        TPattern: StrPattern,
+    {
+        let (start, end) = pattern.rfind_in(self.as_str())?;
+        Some((self.slice(..start)?, self.slice(end..)?))
+    }
+
+    /// Returns an iterator over the lines of this string (separated by `\n` or `\r\n`; the
+    /// final line ending is optional). Like `str::lines` but the lines are slices of this
+    /// string.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("first\r\nsecond\n\nlast\n");
+    /// let lines : Vec<Str> = str.lines_str().collect();
+    /// let lines : Vec<&str> = lines.iter().map(Str::as_str).collect();
+    /// assert_eq!(vec!["first", "second", "", "last"], lines);
+    /// ```
+    pub fn lines_str(&self) -> StrLines<TBin> {
+        StrLines::new(self.clone())
+    }
+
+    /// Returns an iterator over the parts of this string separated by whitespace. Like
+    /// `str::split_whitespace` but the parts are slices of this string.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("  Mary   had\ta little  \n lamb ");
+    /// let words : Vec<Str> = str.split_whitespace_str().collect();
+    /// let words : Vec<&str> = words.iter().map(Str::as_str).collect();
+    /// assert_eq!(vec!["Mary", "had", "a", "little", "lamb"], words);
+    /// ```
+    pub fn split_whitespace_str(&self) -> StrSplitWhitespace<TBin> {
+        StrSplitWhitespace::new(self.clone())
+    }
+
+    /// Returns the string with leading and trailing whitespace removed; see `str::trim`. The
+    /// returned string is a slice of this string.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static(" \tHello, world!\n");
+    /// assert_eq!("Hello, world!", str.trim_str().as_str());
+    /// assert_eq!("Hello, world!\n", str.trim_start_str().as_str());
+    /// assert_eq!(" \tHello, world!", str.trim_end_str().as_str());
+    /// ```
+    pub fn trim_str(&self) -> Self {
+        self.sub_str(self.as_str().trim())
+    }
+
+    /// Returns the string with leading whitespace removed; see `trim_str` and `str::trim_start`.
+    pub fn trim_start_str(&self) -> Self {
+        self.sub_str(self.as_str().trim_start())
+    }
+
+    /// Returns the string with trailing whitespace removed; see `trim_str` and `str::trim_end`.
+    pub fn trim_end_str(&self) -> Self {
+        self.sub_str(self.as_str().trim_end())
+    }
+
+    /// Returns the string with all prefixes and suffixes that match the given pattern
+    /// repeatedly removed; see `str::trim_matches`. The returned string is a slice of this
+    /// string.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("xxHelloxyx");
+    /// assert_eq!("Hello", str.trim_matches_str(&['x', 'y'][..]).as_str());
+    /// assert_eq!("Helloxyx", str.trim_start_matches_str("x").as_str());
+    /// assert_eq!("xxHelloxy", str.trim_end_matches_str('x').as_str());
+    /// ```
+    pub fn trim_matches_str<TPattern>(&self, mut pattern: TPattern) -> Self
+    where
+        TPattern: StrPattern,
+    {
+        let start = trimmed_start(self.as_str(), &mut pattern);
+        let end = trimmed_end(&self.as_str()[start..], &mut pattern);
+        self.sub_str(&self.as_str()[start..start + end])
+    }
+
+    /// Returns the string with all prefixes that match the given pattern repeatedly removed;
+    /// see `trim_matches_str`.
+    pub fn trim_start_matches_str<TPattern>(&self, mut pattern: TPattern) -> Self
+    where
+        TPattern: StrPattern,
+    {
+        let start = trimmed_start(self.as_str(), &mut pattern);
+        self.sub_str(&self.as_str()[start..])
+    }
+
+    /// Returns the string with all suffixes that match the given pattern repeatedly removed;
+    /// see `trim_matches_str`.
+    pub fn trim_end_matches_str<TPattern>(&self, mut pattern: TPattern) -> Self
+    where
+        TPattern: StrPattern,
+    {
+        let end = trimmed_end(self.as_str(), &mut pattern);
+        self.sub_str(&self.as_str()[..end])
+    }
+
+    /// Returns the string without the given prefix; `None` if the string does not start
+    /// with the prefix. See `str::strip_prefix`.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("Bearer token");
+    /// assert_eq!("token", str.strip_prefix_str("Bearer ").unwrap().as_str());
+    /// assert!(str.strip_prefix_str("Basic ").is_none());
+    /// assert_eq!("Bearer toke", str.strip_suffix_str('n').unwrap().as_str());
+    /// ```
+    pub fn strip_prefix_str<TPattern>(&self, mut prefix: TPattern) -> Option<Self>
+    where
+        TPattern: StrPattern,
+    {
+        let len = prefix.prefix_len(self.as_str())?;
+        self.slice(len..)
+    }
+
+    /// Returns the string without the given suffix; `None` if the string does not end
+    /// with the suffix. See `str::strip_suffix`.
+    pub fn strip_suffix_str<TPattern>(&self, mut suffix: TPattern) -> Option<Self>
+    where
+        TPattern: StrPattern,
+    {
+        let len = suffix.suffix_len(self.as_str())?;
+        self.slice(..self.len() - len)
+    }
+
+    /// Returns the slice of this string that corresponds to the given sub-string (must be a
+    /// sub-string of `self.as_str()`).
+    fn sub_str(&self, sub_str: &str) -> Self {
+        let start = sub_str.as_ptr() as usize - self.as_str().as_ptr() as usize;
+        self.slice(start..start + sub_str.len()).expect(
+            "There's an implementation error: The sub-string must be a slice of this string.",
+        )
+    }
+}
+
+/// Returns the index after all prefixes that match the given pattern.
+fn trimmed_start(string: &str, pattern: &mut impl StrPattern) -> usize {
+    let mut start = 0;
+    while let Some(len) = pattern.prefix_len(&string[start..]) {
+        if len == 0 {
+            // empty pattern
+            break;
+        }
+        start += len;
+    }
+    start
+}
+
+/// Returns the index before all suffixes that match the given pattern.
+fn trimmed_end(string: &str, pattern: &mut impl StrPattern) -> usize {
+    let mut end = string.len();
+    while let Some(len) = pattern.suffix_len(&string[..end]) {
+        if len == 0 {
+            // empty pattern
+            break;
+        }
+        end -= len;
+    }
+    end
 }
 
 /// See `AnyStr::into_string`.
-impl<TBin> Into<String> for AnyStr<TBin>
+impl<TBin> From<AnyStr<TBin>> for String
 where
     TBin: AnyBin,
 {
     #[inline]
-    fn into(self) -> String {
-        self.into_string()
+    fn from(string: AnyStr<TBin>) -> Self {
+        string.into_string()
     }
 }
 
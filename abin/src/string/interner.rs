@@ -0,0 +1,266 @@
+use core::cell::RefCell;
+use core::cmp::max;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use crate::{AnyBin, AnyStr, NewSStr, NewStr, SStr, StackBin, Str, StrFactory};
+
+std::thread_local! {
+  static THREAD_LOCAL_INTERNER: Interner = const { Interner::new_purging() };
+}
+
+/// Purging interners purge unused entries when the number of entries reaches this (or twice the
+/// number of entries after the last purge).
+const MIN_PURGE_AT: usize = 64;
+
+/// Interns strings: Returns a shared clone for repeated content (instead of allocating a new
+/// reference-counted string for every value). Use this for data with massive repetition (field
+/// names, enum-like strings, tag values). This is not `Send + Sync`; see `SyncInterner` for a
+/// synchronized version (and a global interner).
+///
+/// Small strings (not larger than the stack capacity) are not interned; those are stored on
+/// the stack anyway (no allocation).
+///
+/// The interner always holds a (strong) reference to its entries; so entries are kept alive by
+/// the interner. Interners created using `new` keep all entries until `clear` is called.
+/// Purging interners (see `new_purging`) remove entries that are no longer used (the interner
+/// holds the only reference) from time to time (see `intern`) and when calling `purge`.
+///
+/// There's also integration for serde: See `ScopedRiSetup::with_interner`.
+///
+/// ```rust
+/// use abin::{AnyBin, Interner};
+///
+/// let interner = Interner::new();
+/// let first = interner.intern("a tag value that's too long for the stack");
+/// let second = interner.intern("a tag value that's too long for the stack");
+/// assert_eq!(first, second);
+/// // shared: `first`, `second` and the interner.
+/// assert_eq!(Some(3), second.as_bin().strong_count());
+/// assert_eq!(1, interner.len());
+/// ```
+pub struct Interner {
+    table: RefCell<InternerTable<Str>>,
+}
+
+impl Interner {
+    /// New interner; entries are kept until the interner is dropped or `clear` is called.
+    pub const fn new() -> Self {
+        Self {
+            table: RefCell::new(InternerTable::new(false)),
+        }
+    }
+
+    /// New purging interner: Entries that are no longer used (the interner holds the only
+    /// reference) are removed from time to time; see `intern`. Note: Those entries are kept alive
+    /// until then (the interner does not hold weak references).
+    pub const fn new_purging() -> Self {
+        Self {
+            table: RefCell::new(InternerTable::new(true)),
+        }
+    }
+
+    /// Calls the function with the thread-local interner (it's purging; see `new_purging`).
+    ///
+    /// ```rust
+    /// use abin::{AnyBin, Interner};
+    ///
+    /// let value = "a value that's too long for the stack";
+    /// let first = Interner::with_thread_local(|interner| interner.intern(value));
+    /// let second = Interner::with_thread_local(|interner| interner.intern(value));
+    /// assert_eq!(Some(3), second.as_bin().strong_count());
+    /// ```
+    pub fn with_thread_local<TFn, TRet>(fun: TFn) -> TRet
+    where
+        TFn: FnOnce(&Interner) -> TRet,
+    {
+        THREAD_LOCAL_INTERNER.with(fun)
+    }
+
+    /// Returns a shared clone if there's already an entry with the same content; otherwise
+    /// creates a new string (and adds it to the interner).
+    ///
+    /// Purging interners (see `new_purging`): Before a new entry is added, entries that are no
+    /// longer used are removed if the number of entries has reached a threshold. The threshold
+    /// is 64 entries or twice the number of entries that were left after the last purge
+    /// (whichever is larger); so purging is amortized.
+    pub fn intern(&self, string: &str) -> Str {
+        self.table
+            .borrow_mut()
+            .intern(string, |string| NewStr::copy_from_str(string))
+    }
+
+    /// The number of entries (for purging interners, this includes unused entries that have not
+    /// been removed yet).
+    pub fn len(&self) -> usize {
+        self.table.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all entries that are no longer used (the interner holds the only reference). This
+    /// is also done automatically for purging interners (see `intern`).
+    pub fn purge(&self) {
+        self.table.borrow_mut().purge()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.table.borrow_mut().clear()
+    }
+}
+
+impl Default for Interner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Like `Interner` but synchronized (`Send + Sync`; produces `SStr`). There's also a global
+/// interner (see `global`).
+///
+/// ```rust
+/// use abin::{AnyBin, SyncInterner};
+///
+/// let value = "a value that's too long for the stack";
+/// let first = SyncInterner::global().intern(value);
+/// let second = std::thread::spawn(move || SyncInterner::global().intern(value))
+///     .join()
+///     .unwrap();
+/// assert_eq!(first, second);
+/// assert_eq!(Some(3), first.as_bin().strong_count());
+/// ```
+pub struct SyncInterner {
+    table: Mutex<InternerTable<SStr>>,
+}
+
+static GLOBAL_INTERNER: SyncInterner = SyncInterner::new_purging();
+
+impl SyncInterner {
+    /// See `Interner::new`.
+    pub const fn new() -> Self {
+        Self {
+            table: Mutex::new(InternerTable::new(false)),
+        }
+    }
+
+    /// See `Interner::new_purging`.
+    pub const fn new_purging() -> Self {
+        Self {
+            table: Mutex::new(InternerTable::new(true)),
+        }
+    }
+
+    /// The global (process-wide) interner; it's purging (see `new_purging`).
+    pub fn global() -> &'static SyncInterner {
+        &GLOBAL_INTERNER
+    }
+
+    /// See `Interner::intern`.
+    pub fn intern(&self, string: &str) -> SStr {
+        self.with_table(|table| table.intern(string, |string| NewSStr::copy_from_str(string)))
+    }
+
+    /// See `Interner::len`.
+    pub fn len(&self) -> usize {
+        self.with_table(|table| table.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// See `Interner::purge`.
+    pub fn purge(&self) {
+        self.with_table(InternerTable::purge)
+    }
+
+    /// See `Interner::clear`.
+    pub fn clear(&self) {
+        self.with_table(InternerTable::clear)
+    }
+
+    fn with_table<TFn, TRet>(&self, fun: TFn) -> TRet
+    where
+        TFn: FnOnce(&mut InternerTable<SStr>) -> TRet,
+    {
+        // a panic while holding the lock can't leave the table in an inconsistent state.
+        let mut table = self
+            .table
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        fun(&mut table)
+    }
+}
+
+impl Default for SyncInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct InternerTable<TStr> {
+    /// `None` until the first entry is added (so the table can be created in const context).
+    entries: Option<HashSet<TStr>>,
+    purging: bool,
+    /// purging tables: unused entries are removed when the number of entries reaches this.
+    purge_at: usize,
+}
+
+impl<TBin> InternerTable<AnyStr<TBin>>
+where
+    TBin: AnyBin,
+{
+    const fn new(purging: bool) -> Self {
+        Self {
+            entries: None,
+            purging,
+            purge_at: MIN_PURGE_AT,
+        }
+    }
+
+    fn intern<TFn>(&mut self, string: &str, create: TFn) -> AnyStr<TBin>
+    where
+        TFn: FnOnce(&str) -> AnyStr<TBin>,
+    {
+        if string.len() <= StackBin::max_len() {
+            // no need to intern: small strings do not allocate.
+            return create(string);
+        }
+        if let Some(existing) = self
+            .entries
+            .as_ref()
+            .and_then(|entries| entries.get(string))
+        {
+            return existing.clone();
+        }
+        if self.purging && self.len() >= self.purge_at {
+            self.purge();
+        }
+        let value = create(string);
+        self.entries
+            .get_or_insert_with(HashSet::new)
+            .insert(value.clone());
+        value
+    }
+
+    fn len(&self) -> usize {
+        self.entries.as_ref().map_or(0, HashSet::len)
+    }
+
+    fn purge(&mut self) {
+        if let Some(entries) = self.entries.as_mut() {
+            // safe (even for synchronized strings): the table is the only reference, so nobody
+            // else can clone the entry.
+            entries.retain(|entry| entry.as_bin().strong_count() != Some(1));
+        }
+        self.purge_at = max(MIN_PURGE_AT, self.len() * 2);
+    }
+
+    fn clear(&mut self) {
+        self.entries = None;
+        self.purge_at = MIN_PURGE_AT;
+    }
+}
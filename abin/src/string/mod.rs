@@ -1,14 +1,23 @@
 pub use {
-    any_str::*, bin_str::*, eq_ord::*, sbin_str::*, str_builder::*, str_factory::*, str_segment::*,
+    any_str::*, bin_str::*, interner::*, sbin_str::*, str_builder::*, str_encoding::*,
+    str_factory::*, str_pattern::*, str_segment::*, str_split::*, str_transform::TransformBin,
+    str_writer::*, utf8_str_builder::*,
 };
 
 mod any_str;
 mod bin_str;
 mod eq_ord;
+mod interner;
 mod sbin_str;
 mod segment_iterator_converter;
 mod str_builder;
+mod str_encoding;
 mod str_factory;
+mod str_pattern;
 mod str_segment;
+mod str_split;
+mod str_transform;
+mod str_writer;
+mod utf8_str_builder;
 
 pub(crate) use segment_iterator_converter::*;
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::EncodeUtf16;
+
+use crate::{AnyStr, TransformBin};
+
+impl<TBin> AnyStr<TBin>
+where
+    TBin: TransformBin,
+{
+    /// Encodes this string as UTF-16 (little-endian, without byte order mark). The output is
+    /// sized exactly (small results are stored on the stack); it's created using the factory
+    /// matching the binary (see `TransformBin`). See also `StrFactory::from_utf16`.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str, AnyBin};
+    /// let str : Str = NewStr::from_static("A€");
+    /// assert_eq!(&[0x41, 0x00, 0xAC, 0x20], str.encode_utf16_bin().as_slice());
+    /// ```
+    pub fn encode_utf16_bin(&self) -> TBin {
+        TBin::from_bytes(Utf16LeBytes::new(self.as_str()))
+    }
+}
+
+/// Error returned by `StrFactory::from_utf16` if the input contains an unpaired surrogate.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FromUtf16Error {
+    index: usize,
+}
+
+impl FromUtf16Error {
+    pub(crate) fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// The index (in code units, not bytes) of the unpaired surrogate.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Error for FromUtf16Error {}
+
+impl Display for FromUtf16Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid UTF-16: unpaired surrogate at index {}.",
+            self.index
+        )
+    }
+}
+
+/// Iterator over the UTF-8 bytes of the given chars; the number of bytes must be known in
+/// advance (so the factory can size the output exactly, see `Iterator::size_hint`).
+pub(crate) struct Utf8Bytes<TChars> {
+    chars: TChars,
+    buf: [u8; 4],
+    buf_pos: usize,
+    buf_len: usize,
+    remaining: usize,
+}
+
+impl<TChars> Utf8Bytes<TChars>
+where
+    TChars: Iterator<Item = char>,
+{
+    /// `len` is the number of UTF-8 bytes of `chars`.
+    pub(crate) fn new(chars: TChars, len: usize) -> Self {
+        Self {
+            chars,
+            buf: [0; 4],
+            buf_pos: 0,
+            buf_len: 0,
+            remaining: len,
+        }
+    }
+}
+
+impl<TChars> Iterator for Utf8Bytes<TChars>
+where
+    TChars: Iterator<Item = char>,
+{
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.buf_pos == self.buf_len {
+            let chr = self.chars.next()?;
+            self.buf_len = chr.encode_utf8(&mut self.buf).len();
+            self.buf_pos = 0;
+        }
+        let byte = self.buf[self.buf_pos];
+        self.buf_pos += 1;
+        self.remaining = self.remaining.saturating_sub(1);
+        Some(byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Iterator over the UTF-16 (little-endian) bytes of a string; see `Str::encode_utf16_bin`.
+pub(crate) struct Utf16LeBytes<'a> {
+    units: EncodeUtf16<'a>,
+    /// the high byte of the last code unit.
+    pending: Option<u8>,
+    remaining: usize,
+}
+
+impl<'a> Utf16LeBytes<'a> {
+    pub(crate) fn new(string: &'a str) -> Self {
+        Self {
+            units: string.encode_utf16(),
+            pending: None,
+            remaining: string.encode_utf16().count() * 2,
+        }
+    }
+}
+
+impl<'a> Iterator for Utf16LeBytes<'a> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        let byte = if let Some(byte) = self.pending.take() {
+            byte
+        } else {
+            let [low, high] = self.units.next()?.to_le_bytes();
+            self.pending = Some(high);
+            low
+        };
+        self.remaining = self.remaining.saturating_sub(1);
+        Some(byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
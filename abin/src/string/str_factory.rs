@@ -1,5 +1,9 @@
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use crate::string::str_encoding::Utf8Bytes;
 use crate::{
-    AnyStr, AnyStrUtf8Error, BinFactory, SegmentIterator, SegmentIteratorConverter, StrSegment,
+    AnyStr, AnyStrUtf8Error, BinFactory, FromUtf16Error, SegmentIterator, SegmentIteratorConverter,
+    SlicePolicy, StrSegment,
 };
 
 /// The result produced by `from_utf8_iter`. Is either a `AnyStr` or an `AnyStrUtf8Error` on
@@ -53,6 +57,40 @@ pub trait StrFactory {
         unsafe { AnyStr::from_utf8_unchecked(bin) }
     }
 
+    /// Like `from_segments` but strings that are used as they are (a single
+    /// `StrSegment::Str`) are compacted using the given policy; see
+    /// `BinFactory::from_segments_with_policy`.
+    #[inline]
+    fn from_segments_with_policy<'a, TPolicy: SlicePolicy>(
+        iter: impl SegmentIterator<StrSegment<'a, <Self::TBinFactory as BinFactory>::T>>,
+    ) -> AnyStr<<Self::TBinFactory as BinFactory>::T> {
+        let converter = SegmentIteratorConverter::new(iter);
+        let bin = Self::TBinFactory::from_segments_with_policy::<TPolicy>(converter);
+        // we know that it's valid (since each segment is valid; this should result in a valid utf-8 output).
+        unsafe { AnyStr::from_utf8_unchecked(bin) }
+    }
+
+    /// Like `from_segment` but a string (`StrSegment::Str`) is compacted using the given
+    /// policy; see `BinFactory::from_segment_with_policy`.
+    ///
+    /// ```rust
+    /// use abin::{AnyBin, DefaultSlicePolicy, NewStr, Str, StrFactory, StrSegment};
+    ///
+    /// let response : Str = NewStr::copy_from_str("x".repeat(100_000).as_str());
+    /// let segment = StrSegment::Str(response.slice(10..100).unwrap());
+    /// let string = NewStr::from_segment_with_policy::<DefaultSlicePolicy>(segment);
+    /// assert_eq!(Some(1), response.as_bin().strong_count());
+    /// assert_eq!("x".repeat(90), string.as_str());
+    /// ```
+    #[inline]
+    fn from_segment_with_policy<'a, TPolicy: SlicePolicy>(
+        segment: impl Into<StrSegment<'a, <Self::TBinFactory as BinFactory>::T>>,
+    ) -> AnyStr<<Self::TBinFactory as BinFactory>::T> {
+        let bin = Self::TBinFactory::from_segment_with_policy::<TPolicy>(segment.into());
+        // we know that it's valid utf-8.
+        unsafe { AnyStr::from_utf8_unchecked(bin) }
+    }
+
     /// Create a string from an iterator. To be efficient, the iterator should provide correct
     /// hints (see `Iterator::size_hint`).
     ///
@@ -133,4 +171,73 @@ pub trait StrFactory {
         // we know it's valid utf-8
         unsafe { AnyStr::from_utf8_unchecked(bin) }
     }
+
+    /// Decodes UTF-16 (see `String::from_utf16`). Returns an error if the input contains an
+    /// unpaired surrogate.
+    ///
+    /// The output is sized exactly (small strings are stored on the stack).
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory};
+    /// let units : Vec<u16> = "Grüße 🗻".encode_utf16().collect();
+    /// assert_eq!("Grüße 🗻", NewStr::from_utf16(&units).unwrap().as_str());
+    /// assert_eq!(1, NewStr::from_utf16(&[0x61, 0xD800]).unwrap_err().index());
+    /// ```
+    fn from_utf16(
+        units: &[u16],
+    ) -> Result<AnyStr<<Self::TBinFactory as BinFactory>::T>, FromUtf16Error> {
+        let mut len = 0;
+        let mut index = 0;
+        for chr in decode_utf16(units.iter().copied()) {
+            match chr {
+                Ok(chr) => {
+                    len += chr.len_utf8();
+                    index += chr.len_utf16();
+                }
+                Err(_) => return Err(FromUtf16Error::new(index)),
+            }
+        }
+        let chars = decode_utf16(units.iter().copied()).map(|chr| {
+            chr.expect("There's an implementation error: The input has already been validated.")
+        });
+        let bin = Self::TBinFactory::from_iter(Utf8Bytes::new(chars, len));
+        // we know it's valid utf-8 (it has been encoded from chars).
+        Ok(unsafe { AnyStr::from_utf8_unchecked(bin) })
+    }
+
+    /// Decodes UTF-16, replacing unpaired surrogates with `U+FFFD REPLACEMENT CHARACTER` (see
+    /// `String::from_utf16_lossy`). The output is sized exactly.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory};
+    /// assert_eq!("a\u{FFFD}b", NewStr::from_utf16_lossy(&[0x61, 0xD800, 0x62]).as_str());
+    /// ```
+    fn from_utf16_lossy(units: &[u16]) -> AnyStr<<Self::TBinFactory as BinFactory>::T> {
+        let chars =
+            || decode_utf16(units.iter().copied()).map(|chr| chr.unwrap_or(REPLACEMENT_CHARACTER));
+        let len = chars().map(char::len_utf8).sum();
+        let bin = Self::TBinFactory::from_iter(Utf8Bytes::new(chars(), len));
+        // we know it's valid utf-8 (it has been encoded from chars).
+        unsafe { AnyStr::from_utf8_unchecked(bin) }
+    }
+
+    /// Decodes Latin-1 (ISO-8859-1; each byte is one char `U+0000` to `U+00FF`). ASCII input
+    /// is copied as-is (no transcoding); otherwise the output is sized exactly.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory};
+    /// assert_eq!("Grüße", NewStr::from_latin1(b"Gr\xFC\xDFe").as_str());
+    /// ```
+    fn from_latin1(bytes: &[u8]) -> AnyStr<<Self::TBinFactory as BinFactory>::T> {
+        let bin = if bytes.is_ascii() {
+            Self::TBinFactory::copy_from_slice(bytes)
+        } else {
+            // non-ASCII chars (`U+0080` to `U+00FF`) need 2 bytes in UTF-8.
+            let len = bytes.len() + bytes.iter().filter(|byte| !byte.is_ascii()).count();
+            let chars = bytes.iter().map(|byte| char::from(*byte));
+            Self::TBinFactory::from_iter(Utf8Bytes::new(chars, len))
+        };
+        // ASCII is valid utf-8; otherwise it has been encoded from chars.
+        unsafe { AnyStr::from_utf8_unchecked(bin) }
+    }
 }
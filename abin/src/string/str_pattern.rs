@@ -0,0 +1,150 @@
+/// A pattern that can be searched for in a string; used by `AnyStr::split`, `AnyStr::trim_matches`
+/// and similar methods. This mirrors `str::pattern::Pattern` (which is not stable) and is
+/// implemented for `char`, `&str`, `&String`, `&[char]` and closures `FnMut(char) -> bool`.
+///
+/// All returned indices are byte indices and lie on UTF-8 boundaries.
+pub trait StrPattern {
+    /// Returns the byte range `(start, end)` of the first match in `haystack`.
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range `(start, end)` of the last match in `haystack`.
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the length of the match if `haystack` starts with this pattern.
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize>;
+
+    /// Returns the length of the match if `haystack` ends with this pattern.
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize>;
+}
+
+impl StrPattern for char {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.find(*self)?;
+        Some((start, start + self.len_utf8()))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.rfind(*self)?;
+        Some((start, start + self.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.starts_with(*self) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.ends_with(*self) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+}
+
+impl StrPattern for &str {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.find(*self)?;
+        Some((start, start + self.len()))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.rfind(*self)?;
+        Some((start, start + self.len()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.starts_with(*self) {
+            Some(self.len())
+        } else {
+            None
+        }
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.ends_with(*self) {
+            Some(self.len())
+        } else {
+            None
+        }
+    }
+}
+
+impl StrPattern for &String {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().rfind_in(haystack)
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().prefix_len(haystack)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().suffix_len(haystack)
+    }
+}
+
+impl StrPattern for &[char] {
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let chars = *self;
+        (|chr| chars.contains(&chr)).find_in(haystack)
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let chars = *self;
+        (|chr| chars.contains(&chr)).rfind_in(haystack)
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        let chars = *self;
+        (|chr| chars.contains(&chr)).prefix_len(haystack)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        let chars = *self;
+        (|chr| chars.contains(&chr)).suffix_len(haystack)
+    }
+}
+
+impl<F> StrPattern for F
+where
+    F: FnMut(char) -> bool,
+{
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|(_, chr)| self(*chr))
+            .map(|(start, chr)| (start, start + chr.len_utf8()))
+    }
+
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .rev()
+            .find(|(_, chr)| self(*chr))
+            .map(|(start, chr)| (start, start + chr.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next()
+            .filter(|chr| self(*chr))
+            .map(char::len_utf8)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next_back()
+            .filter(|chr| self(*chr))
+            .map(char::len_utf8)
+    }
+}
@@ -23,9 +23,9 @@ pub enum StrSegment<'a, TBin: AnyBin> {
     Empty,
 }
 
-impl<'a, TBin: AnyBin> Into<BinSegment<'a, TBin>> for StrSegment<'a, TBin> {
-    fn into(self) -> BinSegment<'a, TBin> {
-        match self {
+impl<'a, TBin: AnyBin> From<StrSegment<'a, TBin>> for BinSegment<'a, TBin> {
+    fn from(segment: StrSegment<'a, TBin>) -> Self {
+        match segment {
             StrSegment::Slice(slice) => BinSegment::Slice(slice.as_bytes()),
             StrSegment::Static(slice) => BinSegment::Static(slice.as_bytes()),
             StrSegment::Str(string) => BinSegment::Bin(string.into_bin()),
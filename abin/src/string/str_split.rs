@@ -0,0 +1,204 @@
+use crate::{AnyBin, AnyStr, BinLines, StrPattern};
+
+/// Iterator over the parts of a string separated by a pattern; see `AnyStr::split_str`.
+///
+/// The parts are slices of the string (see `AnyStr::slice`), so no bytes are copied for
+/// reference-counted or static strings.
+#[derive(Debug, Clone)]
+pub struct StrSplit<TBin, TPattern>
+where
+    TBin: AnyBin,
+{
+    string: AnyStr<TBin>,
+    pattern: TPattern,
+    // start of the current part
+    pos: usize,
+    // where to search for the next match (differs from `pos` after an empty match)
+    search_pos: usize,
+    finished: bool,
+}
+
+impl<TBin, TPattern> StrSplit<TBin, TPattern>
+where
+    TBin: AnyBin,
+    TPattern: StrPattern,
+{
+    pub(crate) fn new(string: AnyStr<TBin>, pattern: TPattern) -> Self {
+        Self {
+            string,
+            pattern,
+            pos: 0,
+            search_pos: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the remaining part of the string (the part that has not been returned yet)
+    /// and finishes the iterator.
+    fn remainder(&mut self) -> Option<AnyStr<TBin>> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(slice(&self.string, self.pos, self.string.len()))
+        }
+    }
+}
+
+impl<TBin, TPattern> Iterator for StrSplit<TBin, TPattern>
+where
+    TBin: AnyBin,
+    TPattern: StrPattern,
+{
+    type Item = AnyStr<TBin>;
+
+    fn next(&mut self) -> Option<AnyStr<TBin>> {
+        if self.finished {
+            return None;
+        }
+        let string = self.string.as_str();
+        let search_pos = self.search_pos;
+        if search_pos > string.len() {
+            return self.remainder();
+        }
+        match self.pattern.find_in(&string[search_pos..]) {
+            Some((match_start, match_end)) => {
+                let start = self.pos;
+                let end = search_pos + match_start;
+                self.pos = search_pos + match_end;
+                self.search_pos = if match_start == match_end {
+                    // empty match (like `str::split`): continue after the next char.
+                    let next_char_len = string[self.pos..]
+                        .chars()
+                        .next()
+                        .map(char::len_utf8)
+                        .unwrap_or(1);
+                    self.pos + next_char_len
+                } else {
+                    self.pos
+                };
+                Some(slice(&self.string, start, end))
+            }
+            None => self.remainder(),
+        }
+    }
+}
+
+/// Iterator over at most `n` parts of a string separated by a pattern; the last part
+/// contains the remaining string. See `AnyStr::splitn_str`.
+#[derive(Debug, Clone)]
+pub struct StrSplitN<TBin, TPattern>
+where
+    TBin: AnyBin,
+{
+    split: StrSplit<TBin, TPattern>,
+    remaining: usize,
+}
+
+impl<TBin, TPattern> StrSplitN<TBin, TPattern>
+where
+    TBin: AnyBin,
+    TPattern: StrPattern,
+{
+    pub(crate) fn new(string: AnyStr<TBin>, n: usize, pattern: TPattern) -> Self {
+        Self {
+            split: StrSplit::new(string, pattern),
+            remaining: n,
+        }
+    }
+}
+
+impl<TBin, TPattern> Iterator for StrSplitN<TBin, TPattern>
+where
+    TBin: AnyBin,
+    TPattern: StrPattern,
+{
+    type Item = AnyStr<TBin>;
+
+    fn next(&mut self) -> Option<AnyStr<TBin>> {
+        match self.remaining {
+            0 => None,
+            1 => {
+                self.remaining = 0;
+                self.split.remainder()
+            }
+            _ => {
+                self.remaining -= 1;
+                self.split.next()
+            }
+        }
+    }
+}
+
+/// Iterator over the lines of a string; see `AnyStr::lines_str` (and `AnyBin::lines_bin`).
+#[derive(Debug, Clone)]
+pub struct StrLines<TBin> {
+    lines: BinLines<TBin>,
+}
+
+impl<TBin> StrLines<TBin>
+where
+    TBin: AnyBin,
+{
+    pub(crate) fn new(string: AnyStr<TBin>) -> Self {
+        Self {
+            lines: string.into_bin().lines_bin(),
+        }
+    }
+}
+
+impl<TBin> Iterator for StrLines<TBin>
+where
+    TBin: AnyBin,
+{
+    type Item = AnyStr<TBin>;
+
+    fn next(&mut self) -> Option<AnyStr<TBin>> {
+        let line = self.lines.next()?;
+        // we know it's valid UTF-8: lines are separated by ASCII characters (`\n`, `\r`).
+        Some(unsafe { AnyStr::from_utf8_unchecked(line) })
+    }
+}
+
+/// Iterator over the whitespace-separated parts of a string; see `AnyStr::split_whitespace_str`.
+#[derive(Debug, Clone)]
+pub struct StrSplitWhitespace<TBin>
+where
+    TBin: AnyBin,
+{
+    string: AnyStr<TBin>,
+    pos: usize,
+}
+
+impl<TBin> StrSplitWhitespace<TBin>
+where
+    TBin: AnyBin,
+{
+    pub(crate) fn new(string: AnyStr<TBin>) -> Self {
+        Self { string, pos: 0 }
+    }
+}
+
+impl<TBin> Iterator for StrSplitWhitespace<TBin>
+where
+    TBin: AnyBin,
+{
+    type Item = AnyStr<TBin>;
+
+    fn next(&mut self) -> Option<AnyStr<TBin>> {
+        let remaining = &self.string.as_str()[self.pos..];
+        let word = remaining.split_whitespace().next()?;
+        let start = self.pos + (word.as_ptr() as usize - remaining.as_ptr() as usize);
+        let end = start + word.len();
+        self.pos = end;
+        Some(slice(&self.string, start, end))
+    }
+}
+
+#[inline]
+fn slice<TBin: AnyBin>(string: &AnyStr<TBin>, start: usize, end: usize) -> AnyStr<TBin> {
+    string.slice(start..end).expect(
+        "There's an implementation error: The range must be within the bounds of the string \
+        and lie on UTF-8 boundaries.",
+    )
+}
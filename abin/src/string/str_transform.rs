@@ -0,0 +1,368 @@
+use crate::{
+    AnyStr, Bin, BinFactory, NewBin, NewSBin, NewSStr, NewStr, SBin, StrBuilder, StrSegment,
+};
+
+impl<TBin> AnyStr<TBin>
+where
+    TBin: TransformBin,
+{
+    /// Returns the lowercase equivalent of this string (see `str::to_lowercase`).
+    ///
+    /// Returns a clone of this string (cheap; the backend is unchanged) if the string is
+    /// already lowercase. Otherwise the result is built using the builder of the matching
+    /// factory (`NewStr` or `NewSStr`); unchanged runs are added as slices of this string
+    /// (`StrSegment::Str`).
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("Content-Type");
+    /// assert_eq!("content-type", str.to_lowercase_str().as_str());
+    /// let lowercase : Str = NewStr::from_static("content-type");
+    /// assert_eq!(lowercase, lowercase.to_lowercase_str());
+    /// ```
+    pub fn to_lowercase_str(&self) -> Self {
+        self.transform(TBin::lowercase)
+    }
+
+    /// Returns the uppercase equivalent of this string (see `str::to_uppercase`). See
+    /// `to_lowercase_str` on how the result is built.
+    pub fn to_uppercase_str(&self) -> Self {
+        self.transform(TBin::uppercase)
+    }
+
+    /// Returns this string with ASCII characters mapped to lowercase (see
+    /// `str::to_ascii_lowercase`). See `to_lowercase_str` on how the result is built.
+    pub fn to_ascii_lowercase_str(&self) -> Self {
+        self.transform(TBin::ascii_lowercase)
+    }
+
+    /// Returns this string with ASCII characters mapped to uppercase (see
+    /// `str::to_ascii_uppercase`). See `to_lowercase_str` on how the result is built.
+    pub fn to_ascii_uppercase_str(&self) -> Self {
+        self.transform(TBin::ascii_uppercase)
+    }
+
+    /// Replaces all matches of `from` with `to` (see `str::replace`). Returns a clone of this
+    /// string (cheap; the backend is unchanged) if there's no match. See `to_lowercase_str` on
+    /// how the result is built.
+    ///
+    /// ```rust
+    /// use abin::{NewStr, StrFactory, Str};
+    /// let str : Str = NewStr::from_static("a-b-c");
+    /// assert_eq!("a+b+c", str.replace_str("-", "+").as_str());
+    /// assert_eq!("a+b-c", str.replacen_str("-", "+", 1).as_str());
+    /// assert_eq!(str, str.replace_str("x", "y"));
+    /// ```
+    pub fn replace_str(&self, from: &str, to: &str) -> Self {
+        self.replacen_str(from, to, usize::MAX)
+    }
+
+    /// Replaces the first `count` matches of `from` with `to` (see `str::replacen` and
+    /// `replace_str`).
+    pub fn replacen_str(&self, from: &str, to: &str, count: usize) -> Self {
+        self.transform(|string| TBin::replacen(string, from, to, count))
+    }
+
+    /// Returns a clone of this string if the transformation does not change the string.
+    #[inline]
+    fn transform<TFn>(&self, transform: TFn) -> Self
+    where
+        TFn: FnOnce(&Self) -> Option<Self>,
+    {
+        transform(self).unwrap_or_else(|| self.clone())
+    }
+}
+
+/// Implemented for the binaries backing `Str` and `SStr` (`Bin` and `SBin`): The methods of
+/// `AnyStr` that create new strings or binaries (such as `AnyStr::to_lowercase_str`,
+/// `AnyStr::replace_str` or `AnyStr::encode_utf16_bin`) are available for strings backed by those binaries; the new strings
+/// are built using the matching factories (`NewStr` and `NewBin`; or `NewSStr` and `NewSBin`).
+///
+/// Use this as bound to call those methods in generic code:
+///
+/// ```rust
+/// use abin::{AnyStr, NewSStr, NewStr, StrFactory, TransformBin};
+///
+/// fn normalize<T: TransformBin>(header: &AnyStr<T>) -> AnyStr<T> {
+///     header.to_ascii_lowercase_str()
+/// }
+///
+/// assert_eq!("content-type", normalize(&NewStr::from_static("Content-Type")).as_str());
+/// assert_eq!("accept", normalize(&NewSStr::from_static("Accept")).as_str());
+/// ```
+///
+/// This trait is sealed: It can't be implemented outside of this crate.
+pub trait TransformBin: sealed::TransformBinSealed {}
+
+mod sealed {
+    use crate::{AnyBin, AnyStr};
+
+    /// Not nameable outside of this crate (so `TransformBin` can't be implemented outside of
+    /// this crate). Each method returns `None` if the transformation does not change the string.
+    pub trait TransformBinSealed: AnyBin {
+        fn lowercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>>;
+        fn uppercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>>;
+        fn ascii_lowercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>>;
+        fn ascii_uppercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>>;
+        fn replacen(
+            string: &AnyStr<Self>,
+            from: &str,
+            to: &str,
+            count: usize,
+        ) -> Option<AnyStr<Self>>;
+
+        /// See `BinFactory::from_iter`.
+        fn from_bytes(iter: impl IntoIterator<Item = u8>) -> Self;
+    }
+}
+
+impl sealed::TransformBinSealed for Bin {
+    fn lowercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_lowercase(string, &mut NewStr::builder())
+    }
+
+    fn uppercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_uppercase(string, &mut NewStr::builder())
+    }
+
+    fn ascii_lowercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_ascii_lowercase(string, &mut NewStr::builder())
+    }
+
+    fn ascii_uppercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_ascii_uppercase(string, &mut NewStr::builder())
+    }
+
+    fn replacen(string: &AnyStr<Self>, from: &str, to: &str, count: usize) -> Option<AnyStr<Self>> {
+        replacen(string, &mut NewStr::builder(), from, to, count)
+    }
+
+    fn from_bytes(iter: impl IntoIterator<Item = u8>) -> Self {
+        NewBin::from_iter(iter)
+    }
+}
+
+impl TransformBin for Bin {}
+
+impl sealed::TransformBinSealed for SBin {
+    fn lowercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_lowercase(string, &mut NewSStr::builder())
+    }
+
+    fn uppercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_uppercase(string, &mut NewSStr::builder())
+    }
+
+    fn ascii_lowercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_ascii_lowercase(string, &mut NewSStr::builder())
+    }
+
+    fn ascii_uppercase(string: &AnyStr<Self>) -> Option<AnyStr<Self>> {
+        to_ascii_uppercase(string, &mut NewSStr::builder())
+    }
+
+    fn replacen(string: &AnyStr<Self>, from: &str, to: &str, count: usize) -> Option<AnyStr<Self>> {
+        replacen(string, &mut NewSStr::builder(), from, to, count)
+    }
+
+    fn from_bytes(iter: impl IntoIterator<Item = u8>) -> Self {
+        NewSBin::from_iter(iter)
+    }
+}
+
+impl TransformBin for SBin {}
+
+/// Converts the string to lowercase (like `str::to_lowercase`); returns `None` if the string is
+/// already lowercase.
+fn to_lowercase<'a, TBuilder>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+) -> Option<AnyStr<TBuilder::T>>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    if string.as_str().contains('Σ') {
+        // the final sigma is context-sensitive (this is handled by `str::to_lowercase` but not
+        // by `char::to_lowercase`)... it's rare, so we just take the slow path.
+        let lowercase = string.as_str().to_lowercase();
+        if lowercase == string.as_str() {
+            None
+        } else {
+            builder.push_given_string(lowercase);
+            Some(builder.build())
+        }
+    } else {
+        map_chars(string, builder, |chr| {
+            if chr.is_ascii() {
+                if chr.is_ascii_uppercase() {
+                    Some(Mapped::One(chr.to_ascii_lowercase()))
+                } else {
+                    None
+                }
+            } else {
+                changed(chr, chr.to_lowercase()).map(Mapped::Lower)
+            }
+        })
+    }
+}
+
+/// Converts the string to uppercase (like `str::to_uppercase`); returns `None` if the string is
+/// already uppercase.
+fn to_uppercase<'a, TBuilder>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+) -> Option<AnyStr<TBuilder::T>>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    map_chars(string, builder, |chr| {
+        if chr.is_ascii() {
+            if chr.is_ascii_lowercase() {
+                Some(Mapped::One(chr.to_ascii_uppercase()))
+            } else {
+                None
+            }
+        } else {
+            changed(chr, chr.to_uppercase()).map(Mapped::Upper)
+        }
+    })
+}
+
+/// Converts ASCII characters to lowercase (like `str::to_ascii_lowercase`); returns `None` if
+/// nothing changes.
+fn to_ascii_lowercase<'a, TBuilder>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+) -> Option<AnyStr<TBuilder::T>>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    map_chars(string, builder, |chr| {
+        if chr.is_ascii_uppercase() {
+            Some(Mapped::One(chr.to_ascii_lowercase()))
+        } else {
+            None
+        }
+    })
+}
+
+/// Converts ASCII characters to uppercase (like `str::to_ascii_uppercase`); returns `None` if
+/// nothing changes.
+fn to_ascii_uppercase<'a, TBuilder>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+) -> Option<AnyStr<TBuilder::T>>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    map_chars(string, builder, |chr| {
+        if chr.is_ascii_lowercase() {
+            Some(Mapped::One(chr.to_ascii_uppercase()))
+        } else {
+            None
+        }
+    })
+}
+
+/// Replaces the first `count` matches of `from` with `to` (like `str::replacen`); returns `None`
+/// if nothing changes.
+fn replacen<'a, TBuilder>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+    from: &str,
+    to: &'a str,
+    count: usize,
+) -> Option<AnyStr<TBuilder::T>>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    if from == to {
+        return None;
+    }
+    let str = string.as_str();
+    let mut changed = false;
+    let mut run_start = 0;
+    for (index, matched) in str.match_indices(from).take(count) {
+        changed = true;
+        push_run(string, builder, run_start, index);
+        builder.push(StrSegment::Slice(to));
+        run_start = index + matched.len();
+    }
+    if changed {
+        push_run(string, builder, run_start, str.len());
+        Some(builder.build())
+    } else {
+        None
+    }
+}
+
+enum Mapped {
+    One(char),
+    Lower(std::char::ToLowercase),
+    Upper(std::char::ToUppercase),
+}
+
+/// Returns `None` if the mapping does not change the character.
+#[inline]
+fn changed<TIter>(chr: char, mapped: TIter) -> Option<TIter>
+where
+    TIter: ExactSizeIterator<Item = char> + Clone,
+{
+    if mapped.len() == 1 && mapped.clone().next() == Some(chr) {
+        None
+    } else {
+        Some(mapped)
+    }
+}
+
+/// Maps all characters; unchanged runs are added to the builder as slices of the string.
+fn map_chars<'a, TBuilder, TFn>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+    mut map: TFn,
+) -> Option<AnyStr<TBuilder::T>>
+where
+    TBuilder: StrBuilder<'a>,
+    TFn: FnMut(char) -> Option<Mapped>,
+{
+    let str = string.as_str();
+    let mut changed = false;
+    let mut run_start = 0;
+    for (index, chr) in str.char_indices() {
+        if let Some(mapped) = map(chr) {
+            changed = true;
+            push_run(string, builder, run_start, index);
+            match mapped {
+                Mapped::One(chr) => builder.push_char(chr),
+                Mapped::Lower(chars) => chars.for_each(|chr| builder.push_char(chr)),
+                Mapped::Upper(chars) => chars.for_each(|chr| builder.push_char(chr)),
+            }
+            run_start = index + chr.len_utf8();
+        }
+    }
+    if changed {
+        push_run(string, builder, run_start, str.len());
+        Some(builder.build())
+    } else {
+        None
+    }
+}
+
+/// Adds the unchanged run `[start, end)` to the builder (shares the buffer with `string`).
+#[inline]
+fn push_run<'a, TBuilder>(
+    string: &AnyStr<TBuilder::T>,
+    builder: &mut TBuilder,
+    start: usize,
+    end: usize,
+) where
+    TBuilder: StrBuilder<'a>,
+{
+    if start < end {
+        let run = string.slice(start..end).expect(
+            "There's an implementation error: The run must be within the bounds of the string \
+            and lie on UTF-8 boundaries.",
+        );
+        builder.push(StrSegment::Str(run));
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use crate::{AnyStr, IntoBinMut, StackBin, StrBuilder, StrSegment};
+
+/// Adapter to use a `StrBuilder` as `std::fmt::Write` (so it can be used with `write!`).
+///
+/// Every write is pushed to the builder (so the builder decides how to store the output; small
+/// outputs stay on the stack): Small writes are pushed char by char (no allocation); larger
+/// writes are copied to a string that also reserves the excess capacity required for
+/// reference-counting (so a single large write does not need to be copied again when
+/// building). See also `BinWriter`.
+///
+/// ```rust
+/// use std::fmt::Write;
+/// use abin::{NewStr, Str, StrWriter};
+///
+/// let mut writer = StrWriter::new(NewStr::builder());
+/// write!(writer, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// let string : Str = writer.build();
+/// assert_eq!("1 + 2 = 3", string.as_str());
+/// ```
+pub struct StrWriter<TBuilder> {
+    builder: TBuilder,
+    /// number of bytes pushed to the builder.
+    len: usize,
+}
+
+impl<'a, TBuilder> StrWriter<TBuilder>
+where
+    TBuilder: StrBuilder<'a>,
+    TBuilder::T: IntoBinMut,
+{
+    /// Creates a new writer; note: the builder should be empty.
+    #[inline]
+    pub fn new(builder: TBuilder) -> Self {
+        Self { builder, len: 0 }
+    }
+
+    /// The number of UTF-8 bytes written so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if nothing has been written so far.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends the given string.
+    pub fn write_slice(&mut self, string: &str) {
+        if string.len() <= StackBin::max_len() {
+            for chr in string.chars() {
+                self.builder.push(StrSegment::Char(chr));
+            }
+        } else {
+            let overhead = <TBuilder::T as IntoBinMut>::mut_overhead_bytes();
+            let mut given = String::with_capacity(string.len().checked_add(overhead).unwrap());
+            given.push_str(string);
+            self.builder.push(StrSegment::GivenString(given));
+        }
+        self.len = self.len.checked_add(string.len()).unwrap();
+    }
+
+    /// Builds the string. Note: After calling this method, the writer will be empty again and
+    /// can be re-used (see `StrBuilder::build`).
+    pub fn build(&mut self) -> AnyStr<TBuilder::T> {
+        self.len = 0;
+        self.builder.build()
+    }
+}
+
+impl<'a, TBuilder> fmt::Write for StrWriter<TBuilder>
+where
+    TBuilder: StrBuilder<'a>,
+    TBuilder::T: IntoBinMut,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_slice(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let mut buf = [0u8; 4];
+        self.write_slice(c.encode_utf8(&mut buf));
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use crate::{AnyBin, AnyStr, StrBuilder, StrSegment};
+
+/// Builds a string from UTF-8 encoded bytes that arrive in chunks (for streaming decoders).
+///
+/// The chunks can be split anywhere (also within a UTF-8 sequence): incomplete sequences at the
+/// end of a chunk are kept until the next chunk arrives. Complete parts are validated and then
+/// pushed to the wrapped `StrBuilder` (`push_bin` does not copy the bytes if the builder supports
+/// that).
+///
+/// ```rust
+/// use abin::{NewStr, Str, Utf8StrBuilder};
+///
+/// let mut builder = Utf8StrBuilder::new(NewStr::builder());
+/// // "🗻" is split across two chunks.
+/// builder.push_slice(b"Mount Fuji: \xF0\x9F").unwrap();
+/// builder.push_slice(b"\x97\xBB").unwrap();
+/// let string : Str = builder.build().unwrap();
+/// assert_eq!("Mount Fuji: 🗻", string.as_str());
+/// ```
+pub struct Utf8StrBuilder<TBuilder> {
+    builder: TBuilder,
+    /// incomplete UTF-8 sequence at the end of the last chunk.
+    pending: [u8; 4],
+    pending_len: usize,
+    /// number of bytes accepted so far (including the pending bytes).
+    len: usize,
+}
+
+impl<'a, TBuilder> Utf8StrBuilder<TBuilder>
+where
+    TBuilder: StrBuilder<'a>,
+{
+    /// Creates a new builder; note: the given builder should be empty.
+    #[inline]
+    pub fn new(builder: TBuilder) -> Self {
+        Self {
+            builder,
+            pending: [0; 4],
+            pending_len: 0,
+            len: 0,
+        }
+    }
+
+    /// The number of bytes accepted so far (this includes bytes of an incomplete UTF-8 sequence
+    /// at the end).
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if no bytes have been accepted so far.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends the given chunk (the bytes are copied when building).
+    ///
+    /// Returns an error if the chunk contains invalid UTF-8; in this case, nothing is appended
+    /// (the builder is still usable).
+    pub fn push_slice(&mut self, chunk: &'a [u8]) -> Result<(), Utf8BuildError> {
+        let plan = self.plan(chunk)?;
+        if !plan.body.is_empty() {
+            // we know it's valid UTF-8 (see `plan`).
+            let body = unsafe { core::str::from_utf8_unchecked(&chunk[plan.body.clone()]) };
+            self.apply(plan, chunk.len(), Some(StrSegment::Slice(body)));
+        } else {
+            self.apply(plan, chunk.len(), None);
+        }
+        Ok(())
+    }
+
+    /// Appends the given chunk; like `push_slice`, but the valid part of the chunk is pushed as
+    /// a slice of the binary (so no bytes are copied for reference-counted binaries).
+    pub fn push_bin(&mut self, chunk: TBuilder::T) -> Result<(), Utf8BuildError> {
+        let plan = self.plan(chunk.as_slice())?;
+        let chunk_len = chunk.len();
+        if !plan.body.is_empty() {
+            let body = if plan.body.len() == chunk_len {
+                chunk
+            } else {
+                chunk.slice(plan.body.clone()).expect(
+                    "There's an implementation error: The valid part must be within the bounds of \
+                    the chunk.",
+                )
+            };
+            // we know it's valid UTF-8 (see `plan`).
+            let body = unsafe { AnyStr::from_utf8_unchecked(body) };
+            self.apply(plan, chunk_len, Some(StrSegment::Str(body)));
+        } else {
+            self.apply(plan, chunk_len, None);
+        }
+        Ok(())
+    }
+
+    /// Builds the string.
+    ///
+    /// Returns an error if the bytes end with an incomplete UTF-8 sequence (`error_len` is `None`
+    /// in this case); the builder is left unchanged (so more chunks can be pushed). On success,
+    /// the builder is empty again and can be re-used.
+    pub fn build(&mut self) -> Result<AnyStr<TBuilder::T>, Utf8BuildError> {
+        if self.pending_len > 0 {
+            Err(Utf8BuildError {
+                valid_up_to: self.len - self.pending_len,
+                error_len: None,
+            })
+        } else {
+            self.len = 0;
+            Ok(self.builder.build())
+        }
+    }
+
+    /// Validates the chunk (does not modify the builder).
+    fn plan(&self, chunk: &[u8]) -> Result<Plan, Utf8BuildError> {
+        let mut pending = self.pending;
+        let mut pending_len = self.pending_len;
+        let mut head = None;
+        let mut consumed = 0;
+        if pending_len > 0 {
+            // try to complete the sequence from the last chunk
+            let width = utf8_width(pending[0]);
+            consumed = (width - pending_len).min(chunk.len());
+            pending[pending_len..pending_len + consumed].copy_from_slice(&chunk[..consumed]);
+            pending_len += consumed;
+            match core::str::from_utf8(&pending[..pending_len]) {
+                Ok(str) => {
+                    head = str.chars().next();
+                }
+                Err(err) => {
+                    if let Some(error_len) = err.error_len() {
+                        return Err(Utf8BuildError {
+                            valid_up_to: self.len - self.pending_len,
+                            error_len: Some(error_len),
+                        });
+                    } else {
+                        // still incomplete (the chunk is too short)
+                        return Ok(Plan {
+                            head: None,
+                            body: 0..0,
+                            pending,
+                            pending_len,
+                        });
+                    }
+                }
+            }
+        }
+
+        let rest = &chunk[consumed..];
+        match core::str::from_utf8(rest) {
+            Ok(_) => Ok(Plan {
+                head,
+                body: consumed..chunk.len(),
+                pending,
+                pending_len: 0,
+            }),
+            Err(err) => {
+                let valid_up_to = consumed + err.valid_up_to();
+                if let Some(error_len) = err.error_len() {
+                    Err(Utf8BuildError {
+                        valid_up_to: self.len + valid_up_to,
+                        error_len: Some(error_len),
+                    })
+                } else {
+                    // incomplete sequence at the end of the chunk
+                    let tail = &chunk[valid_up_to..];
+                    pending[..tail.len()].copy_from_slice(tail);
+                    Ok(Plan {
+                        head,
+                        body: consumed..valid_up_to,
+                        pending,
+                        pending_len: tail.len(),
+                    })
+                }
+            }
+        }
+    }
+
+    fn apply(&mut self, plan: Plan, chunk_len: usize, body: Option<StrSegment<'a, TBuilder::T>>) {
+        if let Some(head) = plan.head {
+            self.builder.push_char(head);
+        }
+        if let Some(body) = body {
+            self.builder.push(body);
+        }
+        self.pending = plan.pending;
+        self.pending_len = plan.pending_len;
+        self.len += chunk_len;
+    }
+}
+
+/// The result of validating a chunk.
+struct Plan {
+    /// the char completed using the pending bytes from the last chunk.
+    head: Option<char>,
+    /// the valid part of the chunk (excluding the bytes used for `head` and `pending`).
+    body: Range<usize>,
+    pending: [u8; 4],
+    pending_len: usize,
+}
+
+/// The number of bytes of the UTF-8 sequence with the given lead byte (the lead byte must be
+/// valid).
+#[inline]
+fn utf8_width(lead: u8) -> usize {
+    match lead {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// Error returned by `Utf8StrBuilder` if the bytes are not valid UTF-8. Mirrors
+/// `std::str::Utf8Error`; the offsets are relative to the start of the string (not to the start of
+/// the chunk).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Utf8BuildError {
+    valid_up_to: usize,
+    error_len: Option<usize>,
+}
+
+impl Utf8BuildError {
+    /// The number of bytes (from the start of the string) that are valid UTF-8.
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// The length of the invalid byte sequence; `None` if the bytes end with an incomplete
+    /// UTF-8 sequence (only returned by `Utf8StrBuilder::build`).
+    #[inline]
+    pub fn error_len(&self) -> Option<usize> {
+        self.error_len
+    }
+}
+
+impl Error for Utf8BuildError {}
+
+impl Display for Utf8BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(error_len) = self.error_len {
+            write!(
+                f,
+                "Invalid UTF-8 sequence of {} bytes from index {}.",
+                error_len, self.valid_up_to
+            )
+        } else {
+            write!(
+                f,
+                "Incomplete UTF-8 byte sequence from index {}.",
+                self.valid_up_to
+            )
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, Base64Variant, BinFactory, DecodeError, NewBin, NewSBin, StorageKind};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+const VARIANTS: &[Base64Variant] = &[
+    Base64Variant::Standard,
+    Base64Variant::StandardNoPad,
+    Base64Variant::UrlSafe,
+    Base64Variant::UrlSafeNoPad,
+];
+
+/// Test vectors from RFC 4648.
+#[test]
+fn rfc_4648() {
+    let vectors = &[
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ];
+    for (input, base64) in vectors {
+        let bin = NewBin::from_static(input.as_bytes());
+        assert_eq!(*base64, bin.to_base64_str(Base64Variant::Standard).as_str());
+        assert_eq!(
+            base64.trim_end_matches('='),
+            bin.to_base64_str(Base64Variant::StandardNoPad).as_str()
+        );
+        assert_eq!(
+            input.as_bytes(),
+            NewBin::from_base64(base64, Base64Variant::Standard)
+                .unwrap()
+                .as_slice()
+        );
+        assert_eq!(
+            input.as_bytes(),
+            NewSBin::from_base64(base64.trim_end_matches('='), Base64Variant::UrlSafeNoPad)
+                .unwrap()
+                .as_slice()
+        );
+    }
+    let hex_vectors = &[("", ""), ("f", "66"), ("foobar", "666f6f626172")];
+    for (input, hex) in hex_vectors {
+        let bin = NewBin::from_static(input.as_bytes());
+        assert_eq!(*hex, bin.to_hex_str().as_str());
+        assert_eq!(format!("{:x}", bin), bin.to_hex_str().as_str());
+        assert_eq!(bin, NewBin::from_hex(hex).unwrap());
+        assert_eq!(bin, NewBin::from_hex(hex.to_uppercase()).unwrap());
+    }
+}
+
+#[test]
+fn round_trip() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for len in 0..300 {
+            let input: Vec<u8> = (0..len).map(|index| (index * 7 + len) as u8).collect();
+            let bin = NewSBin::copy_from_slice(&input);
+            for variant in VARIANTS {
+                let encoded = bin.to_base64_str(*variant);
+                assert_eq!(
+                    bin,
+                    NewSBin::from_base64(encoded.as_str(), *variant).unwrap()
+                );
+            }
+            let hex = bin.to_hex_str();
+            assert_eq!(format!("{:X}", bin), hex.as_str().to_uppercase());
+            assert_eq!(bin, NewSBin::from_hex(hex.as_str()).unwrap());
+        }
+        // URL-safe alphabet
+        let bin = NewBin::from_static(&[0xFB, 0xEF, 0xFF]);
+        assert_eq!("++//", bin.to_base64_str(Base64Variant::Standard).as_str());
+        assert_eq!("--__", bin.to_base64_str(Base64Variant::UrlSafe).as_str());
+    });
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        DecodeError::InvalidByte {
+            offset: 3,
+            byte: b'g'
+        },
+        NewBin::from_hex("abcg").unwrap_err()
+    );
+    assert_eq!(
+        DecodeError::InvalidLength { len: 3 },
+        NewBin::from_hex("abc").unwrap_err()
+    );
+
+    let standard = Base64Variant::Standard;
+    let no_pad = Base64Variant::StandardNoPad;
+    assert_eq!(
+        DecodeError::InvalidByte {
+            offset: 2,
+            byte: b'-'
+        },
+        NewBin::from_base64("Zm-v", standard).unwrap_err()
+    );
+    assert_eq!(
+        DecodeError::InvalidByte {
+            offset: 2,
+            byte: b'+'
+        },
+        NewBin::from_base64("Zm+v", Base64Variant::UrlSafe).unwrap_err()
+    );
+    assert_eq!(
+        DecodeError::InvalidLength { len: 5 },
+        NewBin::from_base64("Zm9vY", no_pad).unwrap_err()
+    );
+    // missing padding
+    assert_eq!(
+        DecodeError::InvalidPadding { offset: 2 },
+        NewBin::from_base64("Zg", standard).unwrap_err()
+    );
+    assert_eq!(
+        DecodeError::InvalidPadding { offset: 2 },
+        NewBin::from_base64("Zg=", standard).unwrap_err()
+    );
+    // padding not allowed
+    assert_eq!(
+        DecodeError::InvalidPadding { offset: 2 },
+        NewBin::from_base64("Zg==", no_pad).unwrap_err()
+    );
+    // data after padding
+    assert_eq!(
+        DecodeError::InvalidByte {
+            offset: 3,
+            byte: b'A'
+        },
+        NewBin::from_base64("Zg=A", standard).unwrap_err()
+    );
+    // non-canonical
+    assert_eq!(
+        DecodeError::InvalidTrailingBits { offset: 1 },
+        NewBin::from_base64("Zh==", standard).unwrap_err()
+    );
+    assert_eq!(
+        DecodeError::InvalidTrailingBits { offset: 2 },
+        NewBin::from_base64("Zm9", no_pad).unwrap_err()
+    );
+}
+
+/// Small outputs are stored on the stack; large outputs need exactly one allocation (no
+/// re-allocation).
+#[test]
+fn sized_exactly() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let small = NewBin::from_static(&[1, 2, 3, 4]);
+        let hex = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || small.to_hex_str());
+        assert_eq!(StorageKind::Stack, hex.as_bin().storage_kind());
+        let decoded = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            NewBin::from_hex(hex.as_str()).unwrap()
+        });
+        assert_eq!(StorageKind::Stack, decoded.storage_kind());
+
+        let large = NewSBin::copy_from_slice(&[0xAB; 1000]);
+        let one_allocation = MaAnd(&[
+            &MaExactNumberOfAllocations(1),
+            &MaExactNumberOfReAllocations(0),
+        ]);
+        let hex = mem_scoped(GLOBAL, &one_allocation, || large.to_hex_str());
+        assert_eq!(StorageKind::Rc, hex.as_bin().storage_kind());
+        let base64 = mem_scoped(GLOBAL, &one_allocation, || {
+            large.to_base64_str(Base64Variant::Standard)
+        });
+        let decoded = mem_scoped(GLOBAL, &one_allocation, || {
+            NewSBin::from_base64(base64.as_str(), Base64Variant::Standard).unwrap()
+        });
+        assert_eq!(StorageKind::Arc, decoded.storage_kind());
+        assert_eq!(large, decoded);
+        let decoded = mem_scoped(GLOBAL, &one_allocation, || {
+            NewBin::from_hex(hex.as_str()).unwrap()
+        });
+        assert_eq!(large.as_slice(), decoded.as_slice());
+    });
+}
@@ -0,0 +1,168 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, AnyBinMut, BinFactory, BinMut, IntoBinMut, NewBin, NewSBin, SBinMut};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn bin_mut() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        freeze_does_not_allocate::<NewBin>();
+        freeze_does_not_allocate::<NewSBin>();
+        try_into_mut_unique::<NewBin>();
+        try_into_mut_unique::<NewSBin>();
+        try_into_mut_shared::<NewBin>();
+        try_into_mut_shared::<NewSBin>();
+        try_into_mut_slice_with_offset::<NewBin>();
+        try_into_mut_slice_with_offset::<NewSBin>();
+        try_into_mut_not_owned::<NewBin>();
+        try_into_mut_not_owned::<NewSBin>();
+        try_into_mut_given_vec::<NewBin>();
+        try_into_mut_given_vec::<NewSBin>();
+        growing::<NewBin>();
+        growing::<NewSBin>();
+    });
+}
+
+/// Freezing must not allocate nor re-allocate (the buffer is just handed over).
+fn freeze_does_not_allocate<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let content = BinGen::new(0, 1024).generate_to_vec();
+    let mut bin_mut = AnyBinMut::<T::T>::with_capacity(content.len());
+    bin_mut.extend_from_slice(&content);
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || bin_mut.freeze());
+    assert_eq!(content.as_slice(), bin.as_slice());
+    // this is now a reference-counted binary: cloning must not allocate
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        assert_eq!(bin, bin.clone());
+    });
+
+    // freezing an empty binary does not allocate
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let bin = AnyBinMut::<T::T>::new().freeze();
+        assert!(bin.is_empty());
+    });
+}
+
+/// If there's only one reference, we get the buffer back without copying.
+fn try_into_mut_unique<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let content = BinGen::new(5, 300).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let mut bin_mut = bin.try_into_mut().unwrap();
+        assert_eq!(ptr, bin_mut.as_slice().as_ptr());
+        bin_mut[0] = 200;
+        bin_mut.freeze()
+    });
+    assert_eq!(ptr, bin.as_slice().as_ptr());
+    assert_eq!(200, bin.as_slice()[0]);
+    assert_eq!(&content[1..], &bin.as_slice()[1..]);
+}
+
+/// Shared binaries cannot be converted (this would require a copy).
+fn try_into_mut_shared<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let content = BinGen::new(5, 300).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let clone = bin.clone();
+    let bin = bin.try_into_mut().unwrap_err();
+    assert_eq!(bin, clone);
+
+    // but works again as soon as the other reference has been dropped.
+    drop(clone);
+    let bin_mut = bin.try_into_mut().unwrap();
+    assert_eq!(content.as_slice(), bin_mut.as_slice());
+}
+
+/// A slice with an offset can be converted too (content is moved to the start of the buffer).
+fn try_into_mut_slice_with_offset<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let content = BinGen::new(5, 300).generate_to_vec();
+    let bin = T::copy_from_slice(&content).slice(100..250).unwrap();
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let mut bin_mut = bin.try_into_mut().unwrap();
+        bin_mut.truncate(100);
+        bin_mut.freeze()
+    });
+    assert_eq!(&content[100..200], bin.as_slice());
+}
+
+/// Static binaries and stack binaries do not own a buffer that can be handed out.
+fn try_into_mut_not_owned<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let static_bin = T::from_static("This is a static binary".as_bytes());
+    assert!(static_bin.try_into_mut().is_err());
+
+    let stack_bin = T::copy_from_slice(&[1, 2, 3]);
+    assert!(stack_bin.try_into_mut().is_err());
+
+    // empty: does not allocate (the caller might never write).
+    let empty = T::empty();
+    let mut bin_mut = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        empty.try_into_mut().unwrap()
+    });
+    assert!(bin_mut.is_empty());
+    assert_eq!(0, bin_mut.capacity());
+    bin_mut.extend_from_slice(&[1u8; 100]);
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || bin_mut.freeze());
+    assert_eq!(&[1u8; 100][..], bin.as_slice());
+}
+
+fn try_into_mut_given_vec<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let mut vec = BinGen::new(5, 300).generate_to_vec();
+    vec.reserve_exact(<T::T as IntoBinMut>::mut_overhead_bytes());
+    let bin = T::from_given_vec(vec.clone());
+    let bin_mut = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || bin.try_into_mut().unwrap());
+    assert_eq!(vec, bin_mut.into_vec());
+}
+
+fn growing<T: BinFactory>()
+where
+    T::T: IntoBinMut,
+{
+    let mut bin_mut = AnyBinMut::<T::T>::new();
+    for index in 0..1000u32 {
+        bin_mut.push(index as u8);
+        assert!(bin_mut.capacity() >= bin_mut.len());
+    }
+    bin_mut.extend(0..10u8);
+    bin_mut.resize(2000, 7);
+    assert_eq!(2000, bin_mut.len());
+
+    let expected = bin_mut.clone();
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || bin_mut.freeze());
+    assert_eq!(expected.as_slice(), bin.as_slice());
+}
+
+#[test]
+fn type_aliases() {
+    let mut bin_mut = BinMut::copy_from_slice(&[1, 2, 3]);
+    bin_mut.as_mut_slice().reverse();
+    assert_eq!(NewBin::copy_from_slice(&[3, 2, 1]), bin_mut.freeze());
+
+    let mut sbin_mut = SBinMut::from(vec![1u8, 2, 3]);
+    sbin_mut.clear();
+    assert_eq!(NewSBin::empty(), sbin_mut.freeze());
+}
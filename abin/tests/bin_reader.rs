@@ -0,0 +1,147 @@
+use std::alloc::System;
+use std::io::{BufRead, ErrorKind, Read, Seek, SeekFrom};
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, BinFactory, BinReader, NewBin, NewSBin};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn bin_reader() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        read::<NewBin>();
+        read::<NewSBin>();
+        buf_read::<NewBin>();
+        buf_read::<NewSBin>();
+        seek::<NewBin>();
+        seek::<NewSBin>();
+        read_bin_does_not_copy::<NewBin>();
+        read_bin_does_not_copy::<NewSBin>();
+        length_prefixed::<NewBin>();
+        length_prefixed::<NewSBin>();
+    });
+}
+
+fn read<T: BinFactory>() {
+    let content = BinGen::new(0, 1000).generate_to_vec();
+    let mut reader = BinReader::new(T::copy_from_slice(&content));
+
+    let mut buf = [0u8; 300];
+    assert_eq!(300, reader.read(&mut buf).unwrap());
+    assert_eq!(&content[0..300], &buf[..]);
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&content[300..600], &buf[..]);
+
+    let mut rest = Vec::new();
+    assert_eq!(400, reader.read_to_end(&mut rest).unwrap());
+    assert_eq!(&content[600..], rest.as_slice());
+
+    // at the end
+    assert_eq!(0, reader.read(&mut buf).unwrap());
+    assert_eq!(
+        ErrorKind::UnexpectedEof,
+        reader.read_exact(&mut buf).unwrap_err().kind()
+    );
+    assert_eq!(1000, reader.position());
+}
+
+fn buf_read<T: BinFactory>() {
+    let mut reader = BinReader::new(T::from_static("first\nsecond\n\nlast".as_bytes()));
+    assert_eq!(
+        "first\nsecond\n\nlast".as_bytes(),
+        reader.fill_buf().unwrap()
+    );
+    let lines: Vec<String> = reader.by_ref().lines().map(|line| line.unwrap()).collect();
+    assert_eq!(vec!["first", "second", "", "last"], lines);
+    assert!(reader.fill_buf().unwrap().is_empty());
+
+    let mut reader = BinReader::new(T::from_static(&[1, 2, 3, 4]));
+    reader.consume(3);
+    assert_eq!(&[4], reader.fill_buf().unwrap());
+    reader.consume(100);
+    assert!(reader.fill_buf().unwrap().is_empty());
+}
+
+fn seek<T: BinFactory>() {
+    let mut reader = BinReader::new(T::from_static(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    assert_eq!(3, reader.seek(SeekFrom::Start(3)).unwrap());
+    assert_eq!(&[3, 4], reader.read_bin(2).as_slice());
+    assert_eq!(4, reader.seek(SeekFrom::Current(-1)).unwrap());
+    assert_eq!(&[4], reader.read_bin(1).as_slice());
+    assert_eq!(8, reader.seek(SeekFrom::End(-2)).unwrap());
+    assert_eq!(&[8, 9], reader.read_bin(5).as_slice());
+
+    // beyond the end is ok (nothing to read)
+    assert_eq!(20, reader.seek(SeekFrom::End(10)).unwrap());
+    let mut buf = [0u8; 4];
+    assert_eq!(0, reader.read(&mut buf).unwrap());
+    assert!(reader.read_bin(1).is_empty());
+    assert_eq!(0, reader.remaining());
+
+    // negative is an error (position unchanged)
+    assert_eq!(
+        ErrorKind::InvalidInput,
+        reader.seek(SeekFrom::Current(-21)).unwrap_err().kind()
+    );
+    assert_eq!(20, reader.position());
+    assert_eq!(0, reader.seek(SeekFrom::Current(-20)).unwrap());
+}
+
+/// `read_bin` returns a slice of the underlying binary (for reference-counted binaries this
+/// does not allocate).
+fn read_bin_does_not_copy<T: BinFactory>() {
+    let content = BinGen::new(0, 1000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+    let mut reader = BinReader::new(bin);
+    reader.set_position(100);
+    let (slice, exact) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        (reader.read_bin(200), reader.read_bin_exact(300).unwrap())
+    });
+    assert_eq!(unsafe { ptr.add(100) }, slice.as_slice().as_ptr());
+    assert_eq!(&content[100..300], slice.as_slice());
+    assert_eq!(unsafe { ptr.add(300) }, exact.as_slice().as_ptr());
+    assert_eq!(&content[300..600], exact.as_slice());
+
+    // not enough bytes
+    assert_eq!(
+        ErrorKind::UnexpectedEof,
+        reader.read_bin_exact(401).unwrap_err().kind()
+    );
+    assert_eq!(600, reader.position());
+    assert_eq!(
+        &content[600..],
+        reader.read_bin_exact(400).unwrap().as_slice()
+    );
+    assert_eq!(&content[..], reader.into_inner().as_slice());
+}
+
+/// Typical use case: read length-prefixed payloads.
+fn length_prefixed<T: BinFactory>() {
+    let mut buffer = Vec::new();
+    for index in 0..50u16 {
+        let payload = BinGen::new(index as u8, index as usize * 10).generate_to_vec();
+        buffer.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+        buffer.extend_from_slice(&payload);
+    }
+    let mut reader = BinReader::new(T::copy_from_slice(&buffer));
+    let mut index = 0u16;
+    while reader.remaining() > 0 {
+        let mut len = [0u8; 2];
+        reader.read_exact(&mut len).unwrap();
+        let payload = reader
+            .read_bin_exact(u16::from_be_bytes(len) as usize)
+            .unwrap();
+        assert_eq!(
+            BinGen::new(index as u8, index as usize * 10).generate_to_vec(),
+            payload.as_slice()
+        );
+        index += 1;
+    }
+    assert_eq!(50, index);
+}
@@ -0,0 +1,187 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, BinFactory, NewBin, NewSBin};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+const INPUTS: &[&str] = &[
+    "",
+    ",",
+    ",,",
+    "a",
+    "a,b,c",
+    ",a,b,",
+    "a,,b",
+    "key: value\r\nother: value 2\r\n\r\nbody",
+    "first\nsecond\n\nlast\n",
+    "\r\n",
+    "\n\n",
+    "line\r",
+    "no delimiter at all",
+    "abababab",
+];
+
+const DELIMITERS: &[&str] = &[",", ", ", ": ", "\r\n", "\n", "ab", "aba", "b", "x"];
+
+#[test]
+fn same_as_str() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        same_as_str_for::<NewBin>();
+        same_as_str_for::<NewSBin>();
+    });
+}
+
+#[test]
+fn find() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 500).generate_to_vec();
+        let bin = NewBin::copy_from_slice(&content);
+        let sbin = NewSBin::copy_from_slice(&content);
+        for start in (0..500).step_by(37) {
+            for len in &[1, 2, 3, 17, 100] {
+                let end = (start + len).min(content.len());
+                let needle = &content[start..end];
+                assert_eq!(naive_find(&content, needle), bin.find(needle));
+                assert_eq!(naive_rfind(&content, needle), bin.rfind(needle));
+                assert_eq!(naive_find(&content, needle), sbin.find(needle));
+                assert_eq!(naive_rfind(&content, needle), sbin.rfind(needle));
+                assert!(bin.contains_subslice(needle));
+            }
+        }
+
+        let bin = NewBin::from_static("abcabc".as_bytes());
+        assert_eq!(Some(0), bin.find(b""));
+        assert_eq!(Some(6), bin.rfind(b""));
+        assert_eq!(Some(1), bin.find(b"bc"));
+        assert_eq!(Some(4), bin.rfind(b"bc"));
+        assert_eq!(Some(0), bin.find(b"abcabc"));
+        assert_eq!(Some(0), bin.rfind(b"abcabc"));
+        assert_eq!(None, bin.find(b"abcabca"));
+        assert_eq!(None, bin.rfind(b"abcabca"));
+        assert_eq!(None, bin.find(b"cb"));
+        assert_eq!(None, bin.rfind(b"cb"));
+        assert!(!bin.contains_subslice(b"d"));
+        assert_eq!(None, NewBin::empty().find(b"a"));
+        assert_eq!(Some(0), NewBin::empty().find(b""));
+        assert_eq!(Some(0), NewBin::empty().rfind(b""));
+    });
+}
+
+/// The slice methods (see `Deref`) are not shadowed.
+#[test]
+fn slice_methods_still_resolve() {
+    let bin = NewBin::from_static("a,b,,c".as_bytes());
+    let parts: Vec<&[u8]> = bin.split(|byte| *byte == b',').collect();
+    assert_eq!(vec![&b"a"[..], b"b", b"", b"c"], parts);
+    let parts: Vec<&[u8]> = bin.splitn(2, |byte| *byte == b',').collect();
+    assert_eq!(vec![&b"a"[..], b"b,,c"], parts);
+    let sbin = NewSBin::from_static("a b".as_bytes());
+    assert_eq!(2, sbin.split(|byte| *byte == b' ').count());
+}
+
+#[test]
+fn special_cases() {
+    let bin = NewBin::from_static("a,b".as_bytes());
+    // empty delimiter does not split
+    let parts: Vec<_> = bin.split_bin(b"").collect();
+    assert_eq!(vec![bin.clone()], parts);
+    assert_eq!(0, bin.splitn_bin(0, b",").count());
+    // delimiter types
+    assert_eq!(2, bin.split_bin(&[b','][..]).count());
+    assert_eq!(2, bin.split_bin(vec![b',']).count());
+    assert_eq!(2, bin.split_bin(",").count());
+    // empty binary
+    assert_eq!(1, NewBin::empty().split_bin(b",").count());
+    assert_eq!(0, NewBin::empty().lines_bin().count());
+    assert_eq!(None, NewBin::empty().split_once_bin(b","));
+}
+
+/// Parts are slices of the original binary (no allocation, no copy).
+#[test]
+fn zero_copy() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        zero_copy_for::<NewBin>();
+        zero_copy_for::<NewSBin>();
+    });
+}
+
+fn same_as_str_for<T: BinFactory>() {
+    for input in INPUTS {
+        let bin = T::copy_from_slice(input.as_bytes());
+        for delimiter in DELIMITERS {
+            let expected: Vec<&str> = input.split(delimiter).collect();
+            let actual: Vec<_> = bin.split_bin(delimiter.as_bytes()).collect();
+            assert_eq!(expected.len(), actual.len());
+            for (expected, actual) in expected.iter().zip(actual.iter()) {
+                assert_eq!(expected.as_bytes(), actual.as_slice());
+            }
+
+            for n in 0..5 {
+                let expected: Vec<&str> = input.splitn(n, delimiter).collect();
+                let actual: Vec<_> = bin.splitn_bin(n, delimiter.as_bytes()).collect();
+                assert_eq!(expected.len(), actual.len());
+                for (expected, actual) in expected.iter().zip(actual.iter()) {
+                    assert_eq!(expected.as_bytes(), actual.as_slice());
+                }
+            }
+
+            let expected = input.split_once(delimiter);
+            let actual = bin.split_once_bin(delimiter.as_bytes());
+            assert_eq!(
+                expected.map(|(a, b)| (a.as_bytes(), b.as_bytes())),
+                actual.as_ref().map(|(a, b)| (a.as_slice(), b.as_slice()))
+            );
+
+            assert_eq!(input.find(delimiter), bin.find(delimiter.as_bytes()));
+            assert_eq!(input.rfind(delimiter), bin.rfind(delimiter.as_bytes()));
+        }
+
+        let expected: Vec<&str> = input.lines().collect();
+        let actual: Vec<_> = bin.lines_bin().collect();
+        assert_eq!(expected.len(), actual.len(), "input: {:?}", input);
+        for (expected, actual) in expected.iter().zip(actual.iter()) {
+            assert_eq!(expected.as_bytes(), actual.as_slice());
+        }
+    }
+}
+
+fn zero_copy_for<T: BinFactory>() {
+    let mut content = Vec::new();
+    for index in 0..100 {
+        content.extend_from_slice(format!("line number {}, some text\r\n", index).as_bytes());
+    }
+    for bin in [
+        T::copy_from_slice(&content),
+        T::from_static("first line\nsecond line\nthird line".as_bytes()),
+    ] {
+        let range = bin.as_slice().as_ptr_range();
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            for line in bin.lines_bin() {
+                assert!(range.contains(&line.as_slice().as_ptr()));
+                for part in line.split_bin(b", ") {
+                    assert!(range.contains(&part.as_slice().as_ptr()));
+                }
+                let (first, _) = line.split_once_bin(b" ").unwrap();
+                assert!(range.contains(&first.as_slice().as_ptr()));
+            }
+        });
+    }
+}
+
+fn naive_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn naive_rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .rposition(|window| window == needle)
+}
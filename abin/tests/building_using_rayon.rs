@@ -27,7 +27,7 @@ fn send_sync_string_concat_using_rayon() {
     }
 
     // ... and must not leak memory, of course.
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         // compute without rayon
         let string_without_rayon: String = (0u16..end)
             .map(|number| {
@@ -44,10 +44,6 @@ fn send_sync_string_concat_using_rayon() {
                     .collect();
                 NewSStr::from_given_string(string)
             })
-            .map(|string| {
-                // we clone it - just for fun :-) na... to test reference-counting
-                string.clone()
-            })
             .map(|string| string.into_string())
             .collect();
 
@@ -59,8 +55,7 @@ fn send_sync_string_concat_using_rayon() {
     // around (I guess it's some static data related to thread-pools).
 
     // and now this time compute using rayon
-    let iter = (0u16..end).into_iter();
-    let par_iter = iter.into_par_iter();
+    let par_iter = (0u16..end).into_par_iter();
     let string_rayon: String = par_iter
         .map(|number| {
             let string: String = test_data
@@ -112,6 +107,8 @@ impl SrcItem {
     }
 
     /// Include: 50% (even/odd) or remainder is 0.
+    // note: `%` instead of `is_multiple_of` (not available on older toolchains).
+    #[allow(clippy::manual_is_multiple_of)]
     fn is_include(&self, number: u16) -> bool {
         let even_odd_same = self.number % 2 == 0 && number % 2 == 0;
         if even_odd_same {
@@ -123,30 +120,30 @@ impl SrcItem {
 }
 
 fn create_test_data() -> SrcList {
-    let mut vec = Vec::<SrcItem>::new();
-
-    vec.push(SrcItem::new(0, NewSStr::empty()));
-    vec.push(SrcItem::new(
-        1,
-        NewSStr::from_static(
-            "This is some static text (too long for stack... too long, too long...)",
+    let mut vec = vec![
+        SrcItem::new(0, NewSStr::empty()),
+        SrcItem::new(
+            1,
+            NewSStr::from_static(
+                "This is some static text (too long for stack... too long, too long...)",
+            ),
+        ),
+        SrcItem::new(2, NewSStr::empty()),
+        SrcItem::new(3, NewSStr::empty()),
+        SrcItem::new(4, NewSStr::from_static("small1")),
+        SrcItem::new(5, NewSStr::from_static("small2")),
+        SrcItem::new(6, NewSStr::from_static("small3")),
+        SrcItem::new(7, NewSStr::copy_from_str("short")),
+        SrcItem::new(8, NewSStr::copy_from_str("short")),
+        SrcItem::new(
+            9,
+            NewSStr::copy_from_str("Some non-static text. Longer, longer, longer. Even longer."),
+        ),
+        SrcItem::new(
+            10,
+            NewSStr::copy_from_str("Some non-static text. Longer, longer, longer. Even longer."),
         ),
-    ));
-    vec.push(SrcItem::new(2, NewSStr::empty()));
-    vec.push(SrcItem::new(3, NewSStr::empty()));
-    vec.push(SrcItem::new(4, NewSStr::from_static("small1")));
-    vec.push(SrcItem::new(5, NewSStr::from_static("small2")));
-    vec.push(SrcItem::new(6, NewSStr::from_static("small3")));
-    vec.push(SrcItem::new(7, NewSStr::copy_from_str("short")));
-    vec.push(SrcItem::new(8, NewSStr::copy_from_str("short")));
-    vec.push(SrcItem::new(
-        9,
-        NewSStr::copy_from_str("Some non-static text. Longer, longer, longer. Even longer."),
-    ));
-    vec.push(SrcItem::new(
-        10,
-        NewSStr::copy_from_str("Some non-static text. Longer, longer, longer. Even longer."),
-    ));
+    ];
 
     for num in 10u8..35u8 {
         vec.push(SrcItem::new(num, NewSStr::empty()));
@@ -0,0 +1,261 @@
+#![cfg(feature = "bytes")]
+
+use std::alloc::System;
+
+use bytes::{Buf, BufMut, Bytes};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AnyBin, AnyBinMut, Bin, BinBuilder, BinFactory, BinReader, BinWriter, IntoBinMut, IntoSync,
+    NewBin, NewSBin, SBin, StorageKind,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn bytes_into_bin() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        bytes_into_bin_does_not_copy::<Bin>();
+        bytes_into_bin_does_not_copy::<SBin>();
+        bytes_into_bin_small_and_empty::<Bin>();
+        bytes_into_bin_small_and_empty::<SBin>();
+    });
+}
+
+#[test]
+fn bin_into_bytes() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        bin_into_bytes_does_not_copy::<NewBin>();
+        bin_into_bytes_does_not_copy::<NewSBin>();
+        shared_sbin_into_bytes_does_not_copy();
+        bin_into_bytes_other_types::<NewBin>();
+        bin_into_bytes_other_types::<NewSBin>();
+        round_trip::<Bin>();
+        round_trip::<SBin>();
+    });
+}
+
+#[test]
+fn buf() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        buf_reader::<NewBin>();
+        buf_reader::<NewSBin>();
+        buf_reader_shared_bin();
+    });
+}
+
+#[test]
+fn buf_mut() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        buf_mut_freeze::<NewBin>();
+        buf_mut_freeze::<NewSBin>();
+        buf_mut_builder(NewBin::builder);
+        buf_mut_builder(NewSBin::builder);
+    });
+}
+
+fn bytes_into_bin_does_not_copy<T: AnyBin + From<Bytes>>() {
+    let content = BinGen::new(0, 300).generate_to_vec();
+    let bytes = Bytes::from(content.clone());
+    let ptr = bytes.as_ptr();
+    let bin = T::from(bytes);
+    assert_eq!(ptr, bin.as_slice().as_ptr());
+    assert_eq!(content.as_slice(), bin.as_slice());
+    assert_eq!(StorageKind::Bytes, bin.storage_kind());
+    assert_eq!(Some(1), bin.strong_count());
+    assert_eq!(300, bin.slice(10..20).unwrap().retained_bytes());
+
+    // clone and slice do not allocate
+    let (clone, slice) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        (bin.clone(), bin.slice(10..200).unwrap())
+    });
+    assert_eq!(bin, clone);
+    assert_eq!(&content[10..200], slice.as_slice());
+    assert_eq!(unsafe { ptr.add(10) }, slice.as_slice().as_ptr());
+
+    // re-integration works
+    let re_integrated = bin.try_to_re_integrate(&bin.as_slice()[5..50]).unwrap();
+    assert_eq!(&content[5..50], re_integrated.as_slice());
+
+    // into vec: copies if shared, does not copy if unique.
+    assert_eq!(content, clone.into_vec());
+    drop(slice);
+    drop(re_integrated);
+    let vec = bin.into_vec();
+    assert_eq!(ptr, vec.as_ptr());
+    assert_eq!(content, vec);
+}
+
+fn bytes_into_bin_small_and_empty<T: AnyBin + From<Bytes>>() {
+    let bin = T::from(Bytes::new());
+    assert!(bin.is_empty());
+    let bin = T::from(Bytes::from_static(&[1, 2, 3]));
+    assert_eq!(&[1, 2, 3], bin.as_slice());
+}
+
+/// `Bin` is converted using `into_sync` (does not copy if there are no other references).
+fn bin_into_bytes_does_not_copy<T: BinFactory>() {
+    let content = BinGen::new(0, 300).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+    let bytes = Bytes::from(bin.into_sync());
+    assert_eq!(ptr, bytes.as_ptr());
+    assert_eq!(content.as_slice(), bytes.as_ref());
+}
+
+/// Synchronized binaries can be shared with `Bytes` (this is not possible for un-synchronized
+/// binaries, since `Bytes` is `Send + Sync`: `into_sync` copies if there are other references).
+fn shared_sbin_into_bytes_does_not_copy() {
+    let content = BinGen::new(0, 300).generate_to_vec();
+    let bin = NewSBin::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+    let slice = bin.slice(10..100).unwrap();
+    let bytes = Bytes::from(bin);
+    assert_eq!(ptr, bytes.as_ptr());
+    let bytes_slice = Bytes::from(slice);
+    assert_eq!(unsafe { ptr.add(10) }, bytes_slice.as_ptr());
+    assert_eq!(&content[10..100], bytes_slice.as_ref());
+
+    // shared un-synchronized binaries are copied
+    let bin = NewBin::copy_from_slice(&content);
+    let clone = bin.clone();
+    let bytes = Bytes::from(bin.into_sync());
+    assert_ne!(clone.as_slice().as_ptr(), bytes.as_ptr());
+    assert_eq!(clone.as_slice(), bytes.as_ref());
+}
+
+fn bin_into_bytes_other_types<T: BinFactory>() {
+    assert!(Bytes::from(T::empty().into_sync()).is_empty());
+    assert_eq!(
+        "static".as_bytes(),
+        Bytes::from(T::from_static("static".as_bytes()).into_sync()).as_ref()
+    );
+    assert_eq!(
+        &[1u8, 2, 3],
+        Bytes::from(T::copy_from_slice(&[1, 2, 3]).into_sync()).as_ref()
+    );
+}
+
+/// `Bytes` -> `Bin` -> `Bytes` returns the original `Bytes` (no wrapping).
+fn round_trip<T: AnyBin + From<Bytes>>() {
+    let content = BinGen::new(0, 300).generate_to_vec();
+    let bytes = <Bytes as From<Vec<u8>>>::from(content);
+    let ptr = bytes.as_ptr();
+    let bin = T::from(bytes);
+    let bytes = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || Bytes::from(bin.into_sync()));
+    assert_eq!(ptr, bytes.as_ptr());
+    assert_eq!(300, bytes.len());
+}
+
+fn buf_reader<T: BinFactory>()
+where
+    T::T: From<SBin>,
+{
+    let mut vec = Vec::new();
+    vec.put_u32(300);
+    vec.extend(BinGen::new(0, 300).generate());
+    vec.put_u16(0xABCD);
+    vec.extend(BinGen::new(1, 100).generate());
+    let bin = T::copy_from_slice(&vec);
+    let ptr = bin.as_slice().as_ptr();
+
+    let mut reader = BinReader::new(bin);
+    assert_eq!(406, reader.remaining());
+    let len = reader.get_u32() as usize;
+    assert_eq!(300, len);
+    let payload = reader.copy_to_bytes(len);
+    // zero-copy
+    assert_eq!(unsafe { ptr.add(4) }, payload.as_ptr());
+    assert_eq!(BinGen::new(0, 300).generate_to_vec(), payload.as_ref());
+    assert_eq!(0xABCD, reader.get_u16());
+    // the binary has already been converted: no allocation.
+    let rest = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || reader.copy_to_bytes(100));
+    assert_eq!(unsafe { ptr.add(306) }, rest.as_ptr());
+    assert!(!reader.has_remaining());
+    assert!(reader.chunk().is_empty());
+}
+
+/// Un-synchronized binaries with other references can't be shared with `Bytes` (copied).
+fn buf_reader_shared_bin() {
+    let content = BinGen::new(0, 300).generate_to_vec();
+    let bin = NewBin::copy_from_slice(&content);
+    let clone = bin.clone();
+    let mut reader = BinReader::new(bin);
+    let bytes = reader.copy_to_bytes(200);
+    assert_ne!(clone.as_slice().as_ptr(), bytes.as_ptr());
+    assert_eq!(&content[..200], bytes.as_ref());
+    assert_eq!(100, reader.remaining());
+}
+
+fn buf_mut_freeze<T: BinFactory>()
+where
+    T::T: IntoBinMut + From<SBin>,
+{
+    let mut bin_mut = AnyBinMut::<T::T>::new();
+    for index in 0..1000u32 {
+        bin_mut.put_u32(index);
+    }
+    bin_mut.put_bytes(7, 100);
+    bin_mut.put_slice(&[1, 2, 3]);
+    assert_eq!(4103, bin_mut.len());
+
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || bin_mut.freeze());
+    let mut reader = BinReader::new(bin);
+    for index in 0..1000u32 {
+        assert_eq!(index, reader.get_u32());
+    }
+    assert_eq!(&[7u8; 100][..], &reader.chunk()[..100]);
+    reader.advance(100);
+    assert_eq!(&[1, 2, 3], reader.chunk());
+}
+
+fn buf_mut_builder<'a, TBuilder>(new_builder: impl Fn() -> TBuilder)
+where
+    TBuilder: BinBuilder<'a>,
+    TBuilder::T: IntoBinMut + From<SBin>,
+{
+    // small: stays on the stack.
+    let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let mut writer = BinWriter::new(new_builder());
+        writer.put_u16(1);
+        writer.put_slice(&[2, 3]);
+        writer.build()
+    });
+    assert_eq!(&[0u8, 1, 2, 3], bin.as_slice());
+
+    // chunk_mut: the builder adopts the vector (single allocation, no copy when building).
+    let bin = mem_scoped(
+        GLOBAL,
+        &MaAnd(&[
+            &MaExactNumberOfAllocations(1),
+            &MaExactNumberOfReAllocations(0),
+        ]),
+        || {
+            let mut writer = BinWriter::new(new_builder());
+            writer.put_bytes(7, 40);
+            assert_eq!(40, writer.len());
+            writer.build()
+        },
+    );
+    assert_eq!(&[7u8; 40][..], bin.as_slice());
+
+    // mixed: keeps the order.
+    let mut writer = BinWriter::new(new_builder());
+    for index in 0..1000u32 {
+        writer.put_u32(index);
+        writer.put_bytes(index as u8, 3);
+    }
+    assert_eq!(7000, writer.len());
+    let mut reader = BinReader::new(writer.build());
+    for index in 0..1000u32 {
+        assert_eq!(index, reader.get_u32());
+        assert_eq!(&[index as u8; 3], &reader.chunk()[..3]);
+        reader.advance(3);
+    }
+    assert!(!reader.has_remaining());
+    assert!(writer.is_empty());
+}
@@ -0,0 +1,118 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{format_sstr, format_str, AnyStr, SStr, Str};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn format_static_does_not_allocate() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let (string, sstring) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let string: Str = format_str!("A static string that's too long for the stack.");
+            let sstring: SStr = format_sstr!("A static string that's too long for the stack.");
+            (string, sstring)
+        });
+        assert_eq!(
+            "A static string that's too long for the stack.",
+            string.as_str()
+        );
+        assert_eq!(string.as_str(), sstring.as_str());
+    });
+}
+
+#[test]
+fn format_small_does_not_allocate() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let (string, sstring) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let string: Str = format_str!("{}-{}", 'a', 7);
+            let sstring: SStr = format_sstr!("[{:>3}]", 42);
+            (string, sstring)
+        });
+        assert_eq!("a-7", string.as_str());
+        assert_eq!("[ 42]", sstring.as_str());
+    });
+}
+
+/// When large, the overhead required for reference-counting is reserved up front: so there's
+/// no re-allocation when converting the buffer to a reference-counted string.
+#[test]
+fn format_large_single_allocation() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let name = "🗻∈🌏".repeat(10);
+        let expected = name.clone();
+
+        let string = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(1),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || format_str!("{}", name),
+        );
+        assert_eq!(expected.as_str(), string.as_str());
+
+        let sstring = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(1),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || format_sstr!("{}", name),
+        );
+        assert_eq!(expected.as_str(), sstring.as_str());
+
+        // the result is reference-counted: clone does not allocate.
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            assert_eq!(string, string.clone());
+            assert_eq!(sstring, sstring.clone());
+        });
+    });
+}
+
+#[test]
+fn format_large() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let name = "🗻∈🌏".repeat(10);
+        let expected = format!("Hello, {}! Number {:08}, {:?}.", name, 42, Some(1.5));
+        // many pieces: still a single allocation each (enough capacity is reserved for the rest).
+        let (string, sstring) = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(2),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || {
+                let string: Str =
+                    format_str!("Hello, {}! Number {:08}, {:?}.", name, 42, Some(1.5));
+                let sstring: SStr =
+                    format_sstr!("Hello, {}! Number {:08}, {:?}.", name, 42, Some(1.5));
+                (string, sstring)
+            },
+        );
+        assert_eq!(expected.as_str(), string.as_str());
+        assert_eq!(expected.as_str(), sstring.as_str());
+    });
+}
+
+#[test]
+fn format_same_as_std_format() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for index in 0..200usize {
+            let text = "x".repeat(index);
+            let expected = format!("{}|{:#x}|{text}", index, index);
+            let string: Str = format_str!("{}|{:#x}|{text}", index, index);
+            let sstring: SStr = format_sstr!("{}|{:#x}|{text}", index, index);
+            assert_eq!(expected.as_str(), string.as_str());
+            assert_eq!(expected.as_str(), sstring.as_str());
+        }
+        // trailing comma is accepted
+        let string: AnyStr<_> = format_str!("{}", 1,);
+        assert_eq!("1", string.as_str());
+    });
+}
@@ -0,0 +1,176 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AnyBin, BinFactory, DefaultExcessShrink, GivenVecConfig, GivenVecOptimization, NewBin, NewSBin,
+    StorageKind,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn get_mut() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        get_mut_unique::<NewBin>();
+        get_mut_unique::<NewSBin>();
+        get_mut_shared::<NewBin>();
+        get_mut_shared::<NewSBin>();
+        get_mut_slice::<NewBin>();
+        get_mut_slice::<NewSBin>();
+        get_mut_vec::<NewBin>();
+        get_mut_vec::<NewSBin>();
+        get_mut_small_and_empty::<NewBin>();
+        get_mut_small_and_empty::<NewSBin>();
+        get_mut_static::<NewBin>();
+        get_mut_static::<NewSBin>();
+    });
+}
+
+#[test]
+fn make_mut() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        make_mut_unique::<NewBin>();
+        make_mut_unique::<NewSBin>();
+        make_mut_shared::<NewBin>();
+        make_mut_shared::<NewSBin>();
+        make_mut_static::<NewBin>();
+        make_mut_static::<NewSBin>();
+        make_mut_small::<NewBin>();
+        make_mut_small::<NewSBin>();
+    });
+}
+
+/// A uniquely owned reference-counted binary can be mutated without allocation.
+fn get_mut_unique<T: BinFactory>() {
+    let content = BinGen::new(0, 500).generate_to_vec();
+    let mut bin = T::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let slice = bin.get_mut().unwrap();
+        assert_eq!(ptr, slice.as_ptr());
+        slice[0..4].copy_from_slice(&[1, 2, 3, 4]);
+    });
+    assert_eq!(&[1, 2, 3, 4], &bin.as_slice()[0..4]);
+    assert_eq!(&content[4..], &bin.as_slice()[4..]);
+}
+
+/// Shared binaries cannot be mutated (the other references would see the change).
+fn get_mut_shared<T: BinFactory>() {
+    let content = BinGen::new(0, 500).generate_to_vec();
+    let mut bin = T::copy_from_slice(&content);
+    let clone = bin.clone();
+    assert!(bin.get_mut().is_none());
+    drop(clone);
+    assert!(bin.get_mut().is_some());
+}
+
+/// Only the sliced part is returned.
+fn get_mut_slice<T: BinFactory>() {
+    let content = BinGen::new(0, 500).generate_to_vec();
+    let mut bin = T::copy_from_slice(&content).slice(100..200).unwrap();
+    let slice = bin.get_mut().unwrap();
+    assert_eq!(&content[100..200], slice);
+    slice[0] = slice[0].wrapping_add(1);
+    assert_eq!(content[100].wrapping_add(1), bin.as_slice()[0]);
+}
+
+struct ConstructionConfig;
+
+impl GivenVecConfig for ConstructionConfig {
+    type TExcessShrink = DefaultExcessShrink;
+
+    fn optimization() -> GivenVecOptimization {
+        GivenVecOptimization::Construction
+    }
+}
+
+/// Vector binaries are never shared.
+fn get_mut_vec<T: BinFactory>() {
+    let mut content = BinGen::new(0, 500).generate_to_vec();
+    content.shrink_to_fit();
+    let mut bin = T::from_given_vec_with_config::<ConstructionConfig>(content.clone());
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let slice = bin.get_mut().unwrap();
+        slice.reverse();
+    });
+    content.reverse();
+    assert_eq!(content.as_slice(), bin.as_slice());
+}
+
+/// Small binaries (stored on the stack) and empty binaries are never shared.
+fn get_mut_small_and_empty<T: BinFactory>() {
+    let mut bin = T::copy_from_slice(&[1, 2, 3]);
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        bin.get_mut().unwrap()[1] = 20;
+    });
+    assert_eq!(&[1, 20, 3], bin.as_slice());
+
+    let mut empty = T::empty();
+    assert!(empty.get_mut().unwrap().is_empty());
+}
+
+fn get_mut_static<T: BinFactory>() {
+    let mut bin = T::from_static("This is a static binary".as_bytes());
+    assert!(bin.get_mut().is_none());
+}
+
+/// Does not copy if the binary is unique.
+fn make_mut_unique<T: BinFactory>() {
+    let content = BinGen::new(0, 500).generate_to_vec();
+    let mut bin = T::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let slice = bin.make_mut();
+        assert_eq!(ptr, slice.as_ptr());
+        slice[0] = slice[0].wrapping_add(1);
+    });
+    assert_eq!(content[0].wrapping_add(1), bin.as_slice()[0]);
+}
+
+/// Copies if shared; the other references still see the original content.
+fn make_mut_shared<T: BinFactory>() {
+    let content = BinGen::new(0, 500).generate_to_vec();
+    let mut bin = T::copy_from_slice(&content);
+    let clone = bin.clone();
+    bin.make_mut()[0..2].copy_from_slice(&[7, 7]);
+
+    assert_eq!(&[7, 7], &bin.as_slice()[0..2]);
+    assert_eq!(&content[2..], &bin.as_slice()[2..]);
+    assert_eq!(content.as_slice(), clone.as_slice());
+    // now both are unique
+    assert!(bin.get_mut().is_some());
+    drop(bin);
+    let mut clone = clone;
+    assert!(clone.get_mut().is_some());
+}
+
+fn make_mut_static<T: BinFactory>() {
+    let mut bin = T::from_static("This is a static binary".as_bytes());
+    bin.make_mut()[0] = b't';
+    assert_eq!("this is a static binary".as_bytes(), bin.as_slice());
+    assert!(bin.get_mut().is_some());
+}
+
+/// Small content is copied to the stack (no allocation).
+fn make_mut_small<T: BinFactory>() {
+    let mut bin = T::from_static("static".as_bytes());
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        bin.make_mut()[0] = b'S';
+    });
+    assert_eq!("Static".as_bytes(), bin.as_slice());
+    assert_eq!(StorageKind::Stack, bin.storage_kind());
+
+    let content = BinGen::new(0, 500).generate_to_vec();
+    let large = T::copy_from_slice(&content);
+    let mut slice = large.slice(10..20).unwrap();
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        slice.make_mut()[0] = 0;
+    });
+    assert_eq!(StorageKind::Stack, slice.storage_kind());
+    assert_eq!(&content[11..20], &slice.as_slice()[1..]);
+}
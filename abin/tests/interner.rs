@@ -0,0 +1,153 @@
+use std::alloc::System;
+use std::sync::Arc;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, Interner, StorageKind, SyncInterner};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+fn long_value(index: usize) -> String {
+    format!("a value that is too long for the stack #{}", index)
+}
+
+/// Repeated content is shared (no allocation).
+#[test]
+fn repeated_content_is_shared() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let interner = Interner::new();
+        let value = long_value(1);
+        let first = interner.intern(&value);
+        let second = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || interner.intern(&value));
+        assert_eq!(first, second);
+        assert_eq!(value, second.as_str());
+        assert_eq!(StorageKind::Rc, second.as_bin().storage_kind());
+        assert_eq!(Some(3), second.as_bin().strong_count());
+        assert_eq!(1, interner.len());
+
+        let other = interner.intern(&long_value(2));
+        assert_eq!(Some(2), other.as_bin().strong_count());
+        assert_eq!(2, interner.len());
+    });
+}
+
+/// Small strings are stored on the stack (they are not added to the interner).
+#[test]
+fn small_strings_are_not_interned() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let interner = Interner::new();
+        let small = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || interner.intern("small"));
+        assert_eq!("small", small.as_str());
+        assert_eq!(StorageKind::Stack, small.as_bin().storage_kind());
+        assert_eq!("", interner.intern("").as_str());
+        assert!(interner.is_empty());
+    });
+}
+
+#[test]
+fn strong_entries_are_kept() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let interner = Interner::new();
+        for index in 0..1000 {
+            interner.intern(&long_value(index));
+        }
+        assert_eq!(1000, interner.len());
+        // still there: no allocation.
+        let value = long_value(500);
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || interner.intern(&value));
+
+        interner.clear();
+        assert!(interner.is_empty());
+    });
+}
+
+/// Purging interners remove unused entries (automatically or using `purge`).
+#[test]
+fn purging_removes_unused_entries() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let interner = Interner::new_purging();
+        let used: Vec<_> = (0..10)
+            .map(|index| interner.intern(&long_value(index)))
+            .collect();
+        for index in 10..1000 {
+            interner.intern(&long_value(index));
+        }
+        // unused entries are removed from time to time.
+        assert!(interner.len() < 200);
+
+        interner.purge();
+        assert_eq!(used.len(), interner.len());
+        for value in &used {
+            assert_eq!(Some(2), value.as_bin().strong_count());
+        }
+
+        drop(used);
+        interner.purge();
+        assert!(interner.is_empty());
+    });
+}
+
+#[test]
+fn thread_local() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let value = long_value(1);
+        let first = Interner::with_thread_local(|interner| interner.intern(&value));
+        let second = Interner::with_thread_local(|interner| interner.intern(&value));
+        assert_eq!(Some(3), second.as_bin().strong_count());
+        drop(first);
+        drop(second);
+        Interner::with_thread_local(|interner| {
+            interner.purge();
+            assert!(interner.is_empty());
+            // releases the memory of the table.
+            interner.clear();
+        });
+    });
+}
+
+#[test]
+fn synchronized() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let interner = Arc::new(SyncInterner::new());
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let interner = interner.clone();
+                std::thread::spawn(move || {
+                    (0..100)
+                        .map(|index| interner.intern(&long_value(index)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let results: Vec<_> = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect();
+        assert_eq!(100, interner.len());
+        for values in &results {
+            for (index, value) in values.iter().enumerate() {
+                assert_eq!(long_value(index), value.as_str());
+                // shared by all threads (and the interner).
+                assert_eq!(Some(5), value.as_bin().strong_count());
+            }
+        }
+        drop(results);
+        interner.purge();
+        assert!(interner.is_empty());
+    });
+}
+
+#[test]
+fn global() {
+    let value = "a value for the global interner (too long for the stack)";
+    let first = SyncInterner::global().intern(value);
+    let second = std::thread::spawn(move || SyncInterner::global().intern(value))
+        .join()
+        .unwrap();
+    assert_eq!(first, second);
+    assert_eq!(Some(3), first.as_bin().strong_count());
+}
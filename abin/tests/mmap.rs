@@ -0,0 +1,175 @@
+#![cfg(feature = "mmap")]
+
+use std::alloc::System;
+use std::fs::File;
+use std::io::Write;
+use std::thread;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, MmapBin, SBin, StorageKind};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+fn temp_file(content: &[u8]) -> File {
+    let mut file = tempfile::tempfile().unwrap();
+    file.write_all(content).unwrap();
+    file.flush().unwrap();
+    file
+}
+
+fn map(content: &[u8]) -> SBin {
+    let file = temp_file(content);
+    // the file can be closed: the mapping stays valid.
+    unsafe { MmapBin::map_file(&file) }.unwrap()
+}
+
+#[test]
+fn mmap_content() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 10_000).generate_to_vec();
+        let bin = map(&content);
+        assert_eq!(content.len(), bin.len());
+        assert_eq!(content.as_slice(), bin.as_slice());
+        assert_eq!(StorageKind::Mmap, bin.storage_kind());
+        assert_eq!(Some(1), bin.strong_count());
+        assert_eq!(10_000, bin.slice(10..20).unwrap().retained_bytes());
+
+        // empty and small files
+        assert!(map(&[]).is_empty());
+        assert_eq!(&[1, 2, 3], map(&[1, 2, 3]).as_slice());
+    });
+}
+
+#[test]
+fn mmap_clone_and_slice_do_not_allocate() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 10_000).generate_to_vec();
+        let bin = map(&content);
+        let ptr = bin.as_slice().as_ptr();
+        let (clone, slice, slice_of_slice, empty) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let clone = bin.clone();
+            let slice = bin.slice(1000..9000).unwrap();
+            let slice_of_slice = slice.slice(1000..2000).unwrap();
+            let empty = bin.slice(500..500).unwrap();
+            (clone, slice, slice_of_slice, empty)
+        });
+        assert_eq!(bin, clone);
+        assert_eq!(ptr, clone.as_slice().as_ptr());
+        assert_eq!(&content[1000..9000], slice.as_slice());
+        assert_eq!(unsafe { ptr.add(1000) }, slice.as_slice().as_ptr());
+        assert_eq!(&content[2000..3000], slice_of_slice.as_slice());
+        assert_eq!(unsafe { ptr.add(2000) }, slice_of_slice.as_slice().as_ptr());
+        assert!(empty.is_empty());
+
+        // out of bounds
+        assert!(bin.slice(0..10_001).is_none());
+        assert!(slice.slice(0..8001).is_none());
+
+        // the mapping is kept alive by the slices.
+        drop(bin);
+        drop(clone);
+        assert_eq!(&content[1000..9000], slice.as_slice());
+    });
+}
+
+#[test]
+fn mmap_re_integration() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 10_000).generate_to_vec();
+        let bin = map(&content);
+        let slice = bin.slice(100..5000).unwrap();
+        let re_integrated = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            slice
+                .try_to_re_integrate(&bin.as_slice()[200..4000])
+                .unwrap()
+        });
+        assert_eq!(&content[200..4000], re_integrated.as_slice());
+        assert_eq!(
+            unsafe { bin.as_slice().as_ptr().add(200) },
+            re_integrated.as_slice().as_ptr()
+        );
+
+        // not part of the slice
+        assert!(slice
+            .try_to_re_integrate(&bin.as_slice()[4000..6000])
+            .is_none());
+        assert!(bin.try_to_re_integrate(&content[0..100]).is_none());
+    });
+}
+
+#[test]
+fn mmap_into_vec_copies() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 10_000).generate_to_vec();
+        let bin = map(&content);
+        let ptr = bin.as_slice().as_ptr();
+        let slice = bin.slice(10..100).unwrap();
+        let vec = bin.into_vec();
+        assert_ne!(ptr, vec.as_ptr());
+        assert_eq!(content, vec);
+        assert_eq!(&content[10..100], slice.into_vec().as_slice());
+    });
+}
+
+#[test]
+fn mmap_send_sync() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 10_000).generate_to_vec();
+        let bin = map(&content);
+        let handles: Vec<_> = (0..4)
+            .map(|index| {
+                let slice = bin.slice(index * 1000..(index + 1) * 1000).unwrap();
+                thread::spawn(move || slice.clone())
+            })
+            .collect();
+        for (index, handle) in handles.into_iter().enumerate() {
+            assert_eq!(
+                &content[index * 1000..(index + 1) * 1000],
+                handle.join().unwrap().as_slice()
+            );
+        }
+    });
+}
+
+/// The serde re-integration scope can hand out sub-slices of the mapping.
+#[cfg(feature = "serde")]
+#[test]
+fn mmap_serde_re_integration() {
+    use abin::{BinFactory, DefaultScopes, NewSBin, NewSStr, SStr, StrFactory};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize)]
+    struct Entry {
+        #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
+        key: SStr,
+        #[serde(deserialize_with = "abin::ri_deserialize_sbin")]
+        value: SBin,
+    }
+
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let key = "A key that's too long to be stored on the stack";
+        let value = BinGen::new(0, 5000).generate_to_vec();
+        let serialized = serde_cbor::to_vec(&Entry {
+            key: NewSStr::from_static(key),
+            value: NewSBin::copy_from_slice(&value),
+        })
+        .unwrap();
+        let bin = map(&serialized);
+        let range = bin.as_slice().as_ptr() as usize..bin.as_slice().as_ptr() as usize + bin.len();
+
+        let entry = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            DefaultScopes::sync(&bin)
+                .scoped(|| serde_cbor::from_slice::<Entry>(bin.as_slice()).unwrap())
+        });
+        assert_eq!(key, entry.key.as_str());
+        assert_eq!(value.as_slice(), entry.value.as_slice());
+        // both point into the mapping
+        assert!(range.contains(&(entry.key.as_str().as_ptr() as usize)));
+        assert!(range.contains(&(entry.value.as_slice().as_ptr() as usize)));
+    });
+}
@@ -17,7 +17,7 @@ const STACK_BIN_LEN: usize = 3 * core::mem::size_of::<usize>() - 1;
 #[test]
 fn no_alloc_guarantees() {
     // and of course no leaks
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         empty();
         small_binaries_are_stack_allocated();
         vec_can_be_extracted_without_allocation();
@@ -30,7 +30,7 @@ fn no_alloc_guarantees() {
 
 /// The empty binary is stored on the stack; so no allocation here.
 fn empty() {
-    mem_scoped(&GLOBAL, &MaNoAllocNoDealloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoDealloc, || {
         NewBin::empty();
         NewSBin::empty();
     });
@@ -46,7 +46,7 @@ fn small_binaries_are_stack_allocated() {
     let max_stack_alloc_vec_1 = generate_small_vec_that_fits_on_stack();
     let max_stack_alloc_vec_2 = generate_small_vec_that_fits_on_stack();
 
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         NewBin::from_given_vec(empty_vec_1);
         NewBin::copy_from_slice(empty_slice);
         NewSBin::from_given_vec(empty_vec_2);
@@ -68,7 +68,7 @@ fn vec_can_be_extracted_without_allocation() {
     let bin_1 = NewBin::from_given_vec(vec);
     let vec_from_bin = bin_1.into_vec();
 
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         let bin_2 = NewBin::from_given_vec(vec_from_bin);
         // and get the vector back
         let vec_from_bin_2 = bin_2.into_vec();
@@ -90,7 +90,7 @@ fn convert_into_sync_un_sync() {
     let bin_5 = NewBin::from_given_vec(generate_small_vec_that_fits_on_stack());
     let bin_6 = NewSBin::from_given_vec(generate_small_vec_that_fits_on_stack()).un_sync();
 
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         // convert to sync
         let sync_bin1 = bin_1.into_sync();
         let sync_bin2 = bin_2.into_sync();
@@ -123,7 +123,7 @@ fn no_alloc_clone() {
     let bin_5 = NewBin::from_given_vec(generate_small_vec_that_fits_on_stack());
     let bin_6 = NewSBin::from_given_vec(generate_small_vec_that_fits_on_stack()).un_sync();
 
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         let _ignored = bin_1.clone();
         let _ignored = bin_2.clone();
         let _ignored = bin_3.clone();
@@ -146,7 +146,7 @@ fn slice_does_not_allocate() {
     let bin_5 = NewBin::from_given_vec(generate_small_vec_that_fits_on_stack());
     let bin_6 = NewSBin::from_given_vec(generate_small_vec_that_fits_on_stack()).un_sync();
 
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         // empty bin is stack-only; so never allocates.
         assert_eq!(&[] as &[u8], bin_1.slice(0..0).unwrap().as_slice());
         assert_eq!("ello".as_bytes(), bin_2.slice(1..5).unwrap().as_slice());
@@ -173,7 +173,7 @@ fn into_vec_does_not_allocate() {
     let bin_6_allocates =
         NewSBin::from_given_vec(generate_small_vec_that_fits_on_stack()).un_sync();
 
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         // returns an empty vec
         assert_eq!(bin_1.into_vec(), Vec::new());
         // reference-counted binaries do not allocate unless there are multiple references pointing to them.
@@ -182,17 +182,17 @@ fn into_vec_does_not_allocate() {
         bin_4.into_vec();
     });
 
-    mem_scoped(&GLOBAL, &MaDoesAllocate, || {
+    mem_scoped(GLOBAL, &MaDoesAllocate, || {
         // THIS DOES ALLOCATE
         // it's static, so we have to allocate.
         bin_2_allocates.into_vec();
     });
-    mem_scoped(&GLOBAL, &MaDoesAllocate, || {
+    mem_scoped(GLOBAL, &MaDoesAllocate, || {
         // THIS DOES ALLOCATE
         // it's saved on the stack, so we have to allocate.
         bin_5_allocates.into_vec();
     });
-    mem_scoped(&GLOBAL, &MaDoesAllocate, || {
+    mem_scoped(GLOBAL, &MaDoesAllocate, || {
         // THIS DOES ALLOCATE
         // it's saved on the stack, so we have to allocate.
         bin_6_allocates.into_vec();
@@ -19,7 +19,7 @@ fn no_leak_tests() {
 
 fn basic_rc_memory<T: BinFactory>() {
     // some simple leak tests
-    no_leak_1::<T>();
+    no_leak_1();
     no_leak_2::<T>();
     no_leak_3::<T>();
     no_leak_4::<T>();
@@ -28,9 +28,9 @@ fn basic_rc_memory<T: BinFactory>() {
 }
 
 /// Simple test that there's no leak.
-fn no_leak_1<T: BinFactory>() {
+fn no_leak_1() {
     let vec_len = 1024 * 1024 * 32;
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         let _vec1 = create_huge_allocation(vec_len);
     })
 }
@@ -38,7 +38,7 @@ fn no_leak_1<T: BinFactory>() {
 /// Simple test that there's no leak.
 fn no_leak_2<T: BinFactory>() {
     let vec_len = 1024 * 1024 * 32;
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         let vec1 = create_huge_allocation(vec_len);
         let _bin1 = T::from_given_vec(vec1);
     })
@@ -47,7 +47,7 @@ fn no_leak_2<T: BinFactory>() {
 /// Simple test that there's no leak.
 fn no_leak_3<T: BinFactory>() {
     let vec_len = 1024 * 1024 * 32;
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         let vec1 = create_huge_allocation(vec_len);
         let bin1 = T::from_given_vec(vec1);
         let _bin11 = bin1.clone();
@@ -57,7 +57,7 @@ fn no_leak_3<T: BinFactory>() {
 /// Simple test that there's no leak.
 fn no_leak_4<T: BinFactory>() {
     let vec_len = 1024 * 255;
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         let vec1 = create_huge_allocation(vec_len);
         let bin1 = T::from_given_vec(vec1);
         {
@@ -76,7 +76,7 @@ fn no_leak_4<T: BinFactory>() {
 }
 
 fn assert_no_leak<T: BinFactory>() {
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         let vec_len = 1024 * 1024 * 32;
 
         let vec1 = create_huge_allocation(vec_len);
@@ -116,7 +116,7 @@ fn create_huge_allocation(number_of_bytes: usize) -> Vec<u8> {
             break;
         }
         huge_vec[index] = index as u8;
-        index = index + step_size;
+        index += step_size;
     }
     huge_vec
 }
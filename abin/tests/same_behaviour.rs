@@ -17,7 +17,7 @@ pub mod utils;
 #[test]
 fn test_same_behaviour_basics() {
     // make sure there are no leaks
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         same_behaviour_basics_static(&[]);
         same_behaviour_basics_static(&[3]);
         same_behaviour_basics_static(&[4, 8]);
@@ -62,22 +62,24 @@ fn same_behaviour(original: &[u8], bin: Bin) {
     same_behaviour_basics(original, &bin_sliced_full);
 }
 
+// reversed ranges are intentional (edge cases for `slice`).
+#[allow(clippy::reversed_empty_ranges)]
 fn same_behaviour_basics(original: &[u8], bin: &Bin) {
     assert_eq!(original.len(), bin.len(), "Same length");
     assert_eq!(original, bin.as_slice(), "Slice equality");
     same_hash(original, bin);
 
     // slice: edge cases
-    check_slice(original, &bin, 0..0);
-    check_slice(original, &bin, 0..1);
-    check_slice(original, &bin, 1..0);
-    check_slice(original, &bin, 1..1);
+    check_slice(original, bin, 0..0);
+    check_slice(original, bin, 0..1);
+    check_slice(original, bin, 1..0);
+    check_slice(original, bin, 1..1);
 
     // slice: out of range
-    check_slice(original, &bin, 0..(bin.len() + 1));
-    check_slice(original, &bin, 0..core::usize::MAX);
+    check_slice(original, bin, 0..(bin.len() + 1));
+    check_slice(original, bin, 0..usize::MAX);
     // just within range
-    check_slice(original, &bin, 0..bin.len());
+    check_slice(original, bin, 0..bin.len());
 
     let cloned_bin = bin.clone();
     assert_eq!(bin, &cloned_bin);
@@ -39,7 +39,7 @@ fn deserialize_serialize_small() {
 
     // note: This should not allocate, since all binaries should be stack-allocated
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(0),
             &MaExactNumberOfReAllocations(0),
@@ -68,7 +68,7 @@ fn deserialize_serialize_large() {
 
     // yes, this will allocate; 2 allocations (one for each binary; and then 2 de-allocations).
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(2),
             &MaExactNumberOfReAllocations(0),
@@ -0,0 +1,123 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    ri_deserialize_struct, AnyBin, BinFactory, DefaultScopes, Interner, NewSBin, NewSStr, NewStr,
+    SStr, Str, StrFactory, SyncInterner,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+struct Tag {
+    #[serde(deserialize_with = "abin::ri_deserialize_str")]
+    name: Str,
+    #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
+    value: SStr,
+}
+ri_deserialize_struct!(Tag { name, value });
+
+/// JSON strings with escapes can't be re-integrated.
+fn create_tags(count: usize) -> Vec<Tag> {
+    (0..count)
+        .map(|index| Tag {
+            name: NewStr::from_static("the \"name\" of the tag (repeated)"),
+            value: NewSStr::copy_from_str(
+                format!("the \"value\" of the tag: {}", index % 2).as_str(),
+            ),
+        })
+        .collect()
+}
+
+/// Strings that can't be re-integrated are interned.
+#[test]
+fn escaped_strings_are_interned() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_tags(100);
+        let json = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let interner = Interner::new();
+        let sync_interner = SyncInterner::new();
+        let restored = DefaultScopes::sync(&json)
+            .with_interner(&interner)
+            .with_sync_interner(&sync_interner)
+            .scoped(|| serde_json::from_slice::<Vec<Tag>>(json.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(1, interner.len());
+        assert_eq!(2, sync_interner.len());
+        for tag in &restored {
+            assert_eq!(Some(101), tag.name.as_bin().strong_count());
+            assert_eq!(Some(51), tag.value.as_bin().strong_count());
+        }
+    });
+}
+
+/// Without interner, every string gets its own allocation.
+#[test]
+fn no_interner() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_tags(10);
+        let json = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let restored = DefaultScopes::sync(&json)
+            .scoped(|| serde_json::from_slice::<Vec<Tag>>(json.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        for tag in &restored {
+            assert_eq!(Some(1), tag.name.as_bin().strong_count());
+            assert_eq!(Some(1), tag.value.as_bin().strong_count());
+        }
+    });
+}
+
+/// Re-integration is preferred (the strings are not interned).
+#[test]
+fn re_integration_is_preferred() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = vec![Tag {
+            name: NewStr::from_static("a name without escapes (long)"),
+            value: NewSStr::from_static("a value without escapes (long)"),
+        }];
+        let json = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let interner = Interner::new();
+        let sync_interner = SyncInterner::new();
+        let restored = DefaultScopes::sync(&json)
+            .with_interner(&interner)
+            .with_sync_interner(&sync_interner)
+            .scoped(|| serde_json::from_slice::<Vec<Tag>>(json.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert!(interner.is_empty());
+        assert!(sync_interner.is_empty());
+    });
+}
+
+/// Strings from a reader (never borrowed) and the explicit setup (see `RiSeed`).
+#[test]
+fn reader_and_seed() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_tags(20);
+        let json = serde_json::to_vec(&original).unwrap();
+        let interner = Interner::new_purging();
+        let sync_interner = SyncInterner::new_purging();
+        let setup = DefaultScopes::sync_bins(&[])
+            .with_interner(&interner)
+            .with_sync_interner(&sync_interner);
+        let mut deserializer = serde_json::Deserializer::from_reader(json.as_slice());
+        let restored: Vec<Tag> = setup.seed().deserialize(&mut deserializer).unwrap();
+        assert_eq!(original, restored);
+        assert_eq!(Some(21), restored[0].name.as_bin().strong_count());
+        assert_eq!(Some(11), restored[0].value.as_bin().strong_count());
+
+        drop(restored);
+        interner.purge();
+        sync_interner.purge();
+        assert!(interner.is_empty());
+        assert!(sync_interner.is_empty());
+    });
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AnyBin, AnyStr, Bin, BinFactory, NewBin, NewSBin, NewSStr, NewStr, SBin, SStr, StorageKind,
+    Str, StrFactory,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+/// See `StackBin::max_len` (64 bit platforms).
+const STACK_MAX_LEN: usize = 23;
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+struct Names {
+    #[serde(deserialize_with = "abin::deserialize_packed")]
+    names: Vec<Str>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+struct Binaries {
+    #[serde(deserialize_with = "abin::deserialize_packed")]
+    binaries: Vec<SBin>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+struct Labels {
+    #[serde(deserialize_with = "abin::deserialize_packed_map")]
+    labels: HashMap<SStr, Bin>,
+}
+
+fn create_names(count: usize) -> Names {
+    Names {
+        names: (0..count)
+            .map(|index| {
+                if index % 3 == 0 {
+                    NewStr::copy_from_str(format!("short {}", index).as_str())
+                } else {
+                    // escaped in JSON: can't be re-integrated (and can't be borrowed).
+                    NewStr::copy_from_str(
+                        format!("a \"long\" name\twith escapes #{}", index).as_str(),
+                    )
+                }
+            })
+            .collect(),
+    }
+}
+
+fn create_binaries(count: usize) -> Binaries {
+    Binaries {
+        binaries: (0..count)
+            .map(|index| NewSBin::copy_from_slice(&vec![index as u8; index % 50]))
+            .collect(),
+    }
+}
+
+/// Large elements share one allocation; small elements are stored on the stack.
+#[test]
+fn escaped_json_strings_share_one_allocation() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_names(3000);
+        let json = serde_json::to_string(&original).unwrap();
+        let restored = mem_scoped(GLOBAL, &MaMaxNumberOfAllocations(50), || {
+            serde_json::from_str::<Names>(&json).unwrap()
+        });
+        assert_eq!(original, restored);
+
+        let long_count = 2000;
+        for name in &restored.names {
+            if name.len() > STACK_MAX_LEN {
+                assert_eq!(StorageKind::Rc, name.as_bin().storage_kind());
+                assert_eq!(Some(long_count), name.as_bin().strong_count());
+            } else {
+                assert_eq!(StorageKind::Stack, name.as_bin().storage_kind());
+            }
+        }
+    });
+}
+
+/// Binaries (not human-readable formats: raw bytes; human-readable formats: text-encoded).
+#[test]
+fn binaries() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_binaries(500);
+        let cbor = serde_cbor::to_vec(&original).unwrap();
+        let restored: Binaries = serde_cbor::from_slice(&cbor).unwrap();
+        assert_eq!(original, restored);
+        let bincode = bincode::serialize(&original).unwrap();
+        let restored: Binaries = bincode::deserialize(&bincode).unwrap();
+        assert_eq!(original, restored);
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Binaries = serde_json::from_str(&json).unwrap();
+        assert_eq!(original, restored);
+
+        let large = restored
+            .binaries
+            .iter()
+            .filter(|bin| bin.len() > STACK_MAX_LEN)
+            .collect::<Vec<_>>();
+        assert!(!large.is_empty());
+        for bin in &large {
+            assert_eq!(StorageKind::Arc, bin.storage_kind());
+            assert_eq!(Some(large.len()), bin.strong_count());
+        }
+    });
+}
+
+/// Binaries serialized as sequences of numbers (`Vec<u8>`).
+#[test]
+fn binaries_as_sequence() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let restored: Vec<Bin> =
+            abin::deserialize_packed(&mut serde_cbor::Deserializer::from_slice(
+                &serde_cbor::to_vec(&vec![vec![1u8; 30], vec![2u8; 2]]).unwrap(),
+            ))
+            .unwrap();
+        assert_eq!(
+            vec![
+                NewBin::copy_from_slice(&[1u8; 30]),
+                NewBin::copy_from_slice(&[2u8; 2])
+            ],
+            restored
+        );
+        assert_eq!(StorageKind::Rc, restored[0].storage_kind());
+        assert_eq!(StorageKind::Stack, restored[1].storage_kind());
+
+        // JSON (legacy form: arrays of numbers instead of text).
+        let json = serde_json::to_string(&vec![vec![1u8; 30], vec![2u8; 2]]).unwrap();
+        let from_json: Vec<Bin> =
+            abin::deserialize_packed(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+        assert_eq!(restored, from_json);
+    });
+}
+
+#[test]
+fn maps() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = Labels {
+            labels: (0..100)
+                .map(|index| {
+                    (
+                        NewSStr::copy_from_str(
+                            format!("label number {} (a long key)", index).as_str(),
+                        ),
+                        NewBin::copy_from_slice(&vec![index as u8; index]),
+                    )
+                })
+                .collect(),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: Labels = serde_json::from_str(&json).unwrap();
+        assert_eq!(original, restored);
+        let cbor = serde_cbor::to_vec(&original).unwrap();
+        let restored: Labels = serde_cbor::from_slice(&cbor).unwrap();
+        assert_eq!(original, restored);
+
+        let large_values = restored
+            .labels
+            .values()
+            .filter(|value| value.len() > STACK_MAX_LEN)
+            .count();
+        for (key, value) in &restored.labels {
+            assert_eq!(Some(100), key.as_bin().strong_count());
+            if value.len() > STACK_MAX_LEN {
+                assert_eq!(Some(large_values), value.strong_count());
+            } else {
+                assert_eq!(None, value.strong_count());
+            }
+        }
+    });
+}
+
+#[test]
+fn empty_and_small_only() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let restored: Names = serde_json::from_str(r#"{"names": []}"#).unwrap();
+        assert!(restored.names.is_empty());
+        let restored: Names = serde_json::from_str(r#"{"names": ["a", "", "b\nc"]}"#).unwrap();
+        assert_eq!(
+            vec!["a", "", "b\nc"],
+            restored
+                .names
+                .iter()
+                .map(AnyStr::as_str)
+                .collect::<Vec<_>>()
+        );
+        assert!(restored
+            .names
+            .iter()
+            .all(|name| name.as_bin().strong_count().is_none()));
+    });
+}
+
+#[test]
+fn invalid_utf8() {
+    let cbor = serde_cbor::to_vec(&vec![NewBin::copy_from_slice(&[0xFF, 0xFE])]).unwrap();
+    let result: Result<Vec<Str>, _> =
+        abin::deserialize_packed(&mut serde_cbor::Deserializer::from_slice(&cbor));
+    assert!(result.is_err());
+}
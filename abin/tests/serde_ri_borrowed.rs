@@ -0,0 +1,160 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+use std::cell::Cell;
+
+use serde::{Deserialize, Serialize};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AnyBin, Bin, BinFactory, Binaries, IntoUnSyncView, NewSBin, NewSStr, SBin, SStr, ScopedRiSetup,
+    StrFactory,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+std::thread_local! {
+    static ATTEMPTS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug)]
+struct Message {
+    id: u64,
+    #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
+    name: SStr,
+    #[serde(deserialize_with = "abin::ri_deserialize_sbin")]
+    payload: SBin,
+}
+
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug)]
+struct TextMessage {
+    #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
+    name: SStr,
+    #[serde(deserialize_with = "abin::ri_deserialize_str")]
+    other_name: abin::Str,
+}
+
+/// Like `DefaultScopes::sync`, but counts the re-integration attempts.
+fn counting_scope(bin: &SBin) -> ScopedRiSetup<'_> {
+    fn re_integrate(binaries: &Binaries, slice: &[u8]) -> Option<Bin> {
+        re_integrate_sync(binaries, slice).map(|bin| bin.un_sync())
+    }
+    fn re_integrate_sync(binaries: &Binaries, slice: &[u8]) -> Option<SBin> {
+        ATTEMPTS.with(|attempts| attempts.set(attempts.get() + 1));
+        binaries.sync_bin()?.try_to_re_integrate(slice)
+    }
+    ScopedRiSetup::new(Binaries::new_sync_bin(bin), re_integrate, re_integrate_sync)
+}
+
+fn take_attempts() -> usize {
+    ATTEMPTS.with(|attempts| attempts.replace(0))
+}
+
+/// Returns true if `part` points into the memory of `whole`.
+fn is_within<T: AnyBin>(whole: &SBin, part: &T) -> bool {
+    let range = whole.as_slice().as_ptr_range();
+    let part = part.as_slice().as_ptr_range();
+    !part.is_empty() && range.start <= part.start && part.end <= range.end
+}
+
+fn create_message() -> Message {
+    Message {
+        id: 42,
+        name: NewSStr::from_static(
+            "a name that is too long to be stored on the stack (so it has to be re-integrated)",
+        ),
+        payload: NewSBin::from_given_vec(BinGen::new(0, 2048).generate_to_vec()),
+    }
+}
+
+/// bincode borrows strings and binaries from the input when de-serializing from a slice; those
+/// get re-integrated (no allocation).
+#[test]
+fn bincode_borrowed_is_re_integrated() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_message();
+        let msg = NewSBin::from_given_vec(bincode::serialize(&original).unwrap());
+        let setup = counting_scope(&msg);
+        take_attempts();
+
+        let restored = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            setup.scoped(|| bincode::deserialize::<Message>(msg.as_slice()).unwrap())
+        });
+        assert_eq!(original, restored);
+        assert_eq!(2, take_attempts());
+        assert!(is_within(&msg, restored.name.as_bin()));
+        assert!(is_within(&msg, &restored.payload));
+    });
+}
+
+/// When de-serializing from a reader, bincode passes transient buffers; re-integration is not
+/// even tried.
+#[test]
+fn bincode_transient_is_copied() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_message();
+        let msg = NewSBin::from_given_vec(bincode::serialize(&original).unwrap());
+        let setup = counting_scope(&msg);
+        take_attempts();
+
+        let restored =
+            setup.scoped(|| bincode::deserialize_from::<_, Message>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(0, take_attempts());
+        assert!(!is_within(&msg, restored.name.as_bin()));
+        assert!(!is_within(&msg, &restored.payload));
+    });
+}
+
+/// serde_json borrows strings without escape sequences from the input; strings with escape
+/// sequences are unescaped into a scratch buffer (those are copied; re-integration is not tried).
+#[test]
+fn json_borrowed_and_transient() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = TextMessage {
+            name: NewSStr::copy_from_str(
+                "a name without escape sequences, long enough for the heap",
+            ),
+            other_name: abin::NewStr::copy_from_str("a name \"with\" escape sequences\n"),
+        };
+        let json = serde_json::to_vec(&original).unwrap();
+        let msg = NewSBin::from_given_vec(json);
+        let setup = counting_scope(&msg);
+        take_attempts();
+
+        let restored =
+            setup.scoped(|| serde_json::from_slice::<TextMessage>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(1, take_attempts());
+        assert!(is_within(&msg, restored.name.as_bin()));
+        assert!(!is_within(&msg, restored.other_name.as_bin()));
+
+        // from a reader: nothing is borrowed
+        let restored =
+            setup.scoped(|| serde_json::from_reader::<_, TextMessage>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(0, take_attempts());
+        assert!(!is_within(&msg, restored.name.as_bin()));
+    });
+}
+
+/// Binaries in JSON are text-encoded; they're decoded (nothing to re-integrate).
+#[test]
+fn json_binaries_are_decoded() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_message();
+        let msg = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let setup = counting_scope(&msg);
+        take_attempts();
+
+        let restored = setup.scoped(|| serde_json::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(1, take_attempts());
+        assert!(is_within(&msg, restored.name.as_bin()));
+        assert!(!is_within(&msg, &restored.payload));
+    });
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+
+use serde::{Deserialize, Serialize};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AlwaysShare, AnyBin, Bin, BinFactory, Binaries, DefaultScopes, NewBin, NewSBin, RiSource, SBin,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug)]
+struct Frame {
+    #[serde(deserialize_with = "abin::ri_deserialize_sbin")]
+    sync_payload: SBin,
+    #[serde(deserialize_with = "abin::ri_deserialize_bin")]
+    payload: Bin,
+}
+
+fn create_frame(index: usize) -> Frame {
+    Frame {
+        sync_payload: NewSBin::from_given_vec(BinGen::new(index as u8, 300).generate_to_vec()),
+        payload: NewBin::from_given_vec(BinGen::new(index as u8 + 1, 500).generate_to_vec()),
+    }
+}
+
+fn is_within<T: AnyBin, TPart: AnyBin>(whole: &T, part: &TPart) -> bool {
+    let range = whole.as_slice().as_ptr_range();
+    let part = part.as_slice().as_ptr_range();
+    !part.is_empty() && range.start <= part.start && part.end <= range.end
+}
+
+fn source_ptr(source: RiSource) -> *const u8 {
+    match source {
+        RiSource::Bin(bin) => bin.as_slice().as_ptr(),
+        RiSource::SBin(bin) => bin.as_slice().as_ptr(),
+    }
+}
+
+#[test]
+fn find_source() {
+    let bins: Vec<SBin> = (0..20)
+        .map(|index| NewSBin::from_given_vec(BinGen::new(index, 100).generate_to_vec()))
+        .collect();
+    let binaries = Binaries::from_sync_bins(&bins);
+    assert_eq!(20, binaries.sources().count());
+    for bin in &bins {
+        let slice = bin.as_slice();
+        for range in &[0..100, 0..1, 99..100, 10..50, 50..50] {
+            let found = binaries.find(&slice[range.clone()]).unwrap();
+            assert_eq!(slice.as_ptr(), source_ptr(found));
+        }
+    }
+    let unrelated = NewBin::copy_from_slice(&[1u8; 100]);
+    assert!(binaries.find(unrelated.as_slice()).is_none());
+    assert!(Binaries::from_bins(&[])
+        .find(unrelated.as_slice())
+        .is_none());
+
+    // overlapping sources (one is a slice of the other)
+    let whole = NewSBin::from_given_vec(BinGen::new(0, 1000).generate_to_vec());
+    let part = whole.slice(100..200).unwrap();
+    let other = NewBin::from_given_vec(BinGen::new(0, 1000).generate_to_vec());
+    let binaries = Binaries::from_sources(vec![
+        RiSource::SBin(&part),
+        RiSource::Bin(&other),
+        RiSource::SBin(&whole),
+    ]);
+    let found = binaries.find(&whole.as_slice()[150..160]).unwrap();
+    assert_eq!(part.as_slice().as_ptr(), source_ptr(found));
+    let found = binaries.find(&whole.as_slice()[150..300]).unwrap();
+    assert_eq!(whole.as_slice().as_ptr(), source_ptr(found));
+    let found = binaries.find(&whole.as_slice()[900..]).unwrap();
+    assert_eq!(whole.as_slice().as_ptr(), source_ptr(found));
+    let found = binaries.find(&other.as_slice()[1..2]).unwrap();
+    assert!(matches!(found, RiSource::Bin(_)));
+}
+
+/// Frames received separately can be de-serialized within one scope.
+#[test]
+fn multiple_frames() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let originals: Vec<Frame> = (0..5).map(create_frame).collect();
+        let frames: Vec<SBin> = originals
+            .iter()
+            .map(|frame| NewSBin::from_given_vec(serde_cbor::to_vec(frame).unwrap()))
+            .collect();
+
+        let setup = DefaultScopes::sync_bins(&frames);
+        let restored: Vec<Frame> = setup.scoped(|| {
+            frames
+                .iter()
+                .rev()
+                .map(|frame| serde_cbor::from_slice(frame.as_slice()).unwrap())
+                .collect()
+        });
+        for (index, restored) in restored.iter().rev().enumerate() {
+            assert_eq!(&originals[index], restored);
+            assert!(is_within(&frames[index], &restored.sync_payload));
+            assert!(is_within(&frames[index], &restored.payload));
+        }
+    });
+}
+
+/// A header received as `Bin` and a body received as `SBin`. `SBin` can't be re-integrated from
+/// a `Bin` source.
+#[test]
+fn mixed_sync_ness() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let header_frame = create_frame(1);
+        let body_frame = create_frame(2);
+        let header = NewBin::from_given_vec(serde_cbor::to_vec(&header_frame).unwrap());
+        let body = NewSBin::from_given_vec(serde_cbor::to_vec(&body_frame).unwrap());
+
+        let binaries = Binaries::new(Some(&header), Some(&body));
+        let setup = DefaultScopes::binaries_with_policy::<AlwaysShare>(binaries);
+        let (restored_header, restored_body) = mem_scoped(
+            GLOBAL,
+            // one allocation: the `SBin` of the header frame must be copied
+            &MaExactNumberOfAllocations(1),
+            || {
+                setup.scoped(|| {
+                    let restored_header: Frame = serde_cbor::from_slice(header.as_slice()).unwrap();
+                    let restored_body: Frame = serde_cbor::from_slice(body.as_slice()).unwrap();
+                    (restored_header, restored_body)
+                })
+            },
+        );
+        assert_eq!(header_frame, restored_header);
+        assert_eq!(body_frame, restored_body);
+        assert!(is_within(&header, &restored_header.payload));
+        assert!(!is_within(&header, &restored_header.sync_payload));
+        assert!(is_within(&body, &restored_body.payload));
+        assert!(is_within(&body, &restored_body.sync_payload));
+    });
+}
@@ -0,0 +1,177 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+
+use bincode::Options;
+use serde::de::DeserializeSeed;
+use serde::Serialize;
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    ri_deserialize_struct, AnyBin, Bin, BinFactory, DefaultScopes, IntoSync, NewBin, NewSBin,
+    NewSStr, NewStr, RiSeed, SBin, SStr, ScopedRiSetup, Str, StrFactory,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[derive(Serialize, Debug, Eq, PartialEq)]
+struct Request {
+    id: u64,
+    sender: SStr,
+    header: Header,
+    payloads: Vec<SBin>,
+    comment: Option<Str>,
+    thumbnail: Option<Bin>,
+}
+ri_deserialize_struct!(Request {
+    id,
+    sender,
+    header,
+    payloads,
+    comment,
+    thumbnail,
+});
+
+#[derive(Serialize, Debug, Eq, PartialEq)]
+struct Header {
+    name: Str,
+    boxed: Box<SBin>,
+}
+ri_deserialize_struct!(Header { name, boxed });
+
+fn create_request() -> Request {
+    Request {
+        id: 77,
+        sender: NewSStr::from_static("sender@some-long-server-name.example.com"),
+        header: Header {
+            name: NewStr::from_static("the header name (too long for the stack)"),
+            boxed: Box::new(NewSBin::copy_from_slice(&[7u8; 300])),
+        },
+        payloads: vec![
+            NewSBin::from_given_vec(BinGen::new(0, 1000).generate_to_vec()),
+            NewSBin::from_given_vec(BinGen::new(5, 2000).generate_to_vec()),
+        ],
+        comment: Some(NewStr::from_static(
+            "a comment that's long enough for the heap",
+        )),
+        thumbnail: None,
+    }
+}
+
+fn is_within<T: AnyBin>(whole: &SBin, part: &T) -> bool {
+    let range = whole.as_slice().as_ptr_range();
+    let part = part.as_slice().as_ptr_range();
+    !part.is_empty() && range.start <= part.start && part.end <= range.end
+}
+
+/// Binaries and strings in nested values (structs, vectors, options, boxes) are re-integrated
+/// without a thread-local scope.
+#[test]
+fn nested_values_are_re_integrated() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_request();
+        let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&original).unwrap());
+        let setup = DefaultScopes::sync(&msg);
+
+        let mut deserializer = serde_cbor::Deserializer::from_slice(msg.as_slice());
+        // only the vector and the box allocate
+        let restored: Request = mem_scoped(GLOBAL, &MaExactNumberOfAllocations(2), || {
+            setup.seed().deserialize(&mut deserializer).unwrap()
+        });
+        assert_eq!(original, restored);
+        assert!(is_within(&msg, restored.sender.as_bin()));
+        assert!(is_within(&msg, restored.header.name.as_bin()));
+        assert!(is_within(&msg, restored.header.boxed.as_ref()));
+        assert!(is_within(&msg, &restored.payloads[0]));
+        assert!(is_within(&msg, &restored.payloads[1]));
+        assert!(is_within(&msg, restored.comment.as_ref().unwrap().as_bin()));
+    });
+}
+
+/// Structs serialized as sequences (bincode) work too.
+#[test]
+fn bincode() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_request();
+        let msg = NewSBin::from_given_vec(bincode::options().serialize(&original).unwrap());
+        let setup = DefaultScopes::sync(&msg);
+        let mut deserializer =
+            bincode::Deserializer::from_slice(msg.as_slice(), bincode::options());
+        let restored: Request = RiSeed::new(&setup).deserialize(&mut deserializer).unwrap();
+        assert_eq!(original, restored);
+        assert!(is_within(&msg, &restored.payloads[1]));
+    });
+}
+
+#[test]
+fn json() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_request();
+        let msg = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let setup = DefaultScopes::sync(&msg);
+        let mut deserializer = serde_json::Deserializer::from_slice(msg.as_slice());
+        let restored: Request = setup.seed().deserialize(&mut deserializer).unwrap();
+        assert_eq!(original, restored);
+        assert!(is_within(&msg, restored.sender.as_bin()));
+
+        // missing options are `None`, unknown fields are ignored
+        let json = r#"{"unknown":[1,2],"id":1,"sender":"a","header":{"name":"b","boxed":""},
+            "payloads":[]}"#;
+        let msg = NewSBin::copy_from_slice(json.as_bytes());
+        let setup = DefaultScopes::sync(&msg);
+        let mut deserializer = serde_json::Deserializer::from_slice(msg.as_slice());
+        let restored: Request = setup.seed().deserialize(&mut deserializer).unwrap();
+        assert_eq!(None, restored.comment);
+        assert_eq!("a", restored.sender.as_str());
+
+        // errors
+        let invalid = &[
+            r#"{"id":1}"#,
+            r#"{"id":1,"id":2,"sender":"a","header":{"name":"b","boxed":""},"payloads":[]}"#,
+        ];
+        for json in invalid {
+            let msg = NewSBin::copy_from_slice(json.as_bytes());
+            let setup = DefaultScopes::sync(&msg);
+            let mut deserializer = serde_json::Deserializer::from_slice(msg.as_slice());
+            assert!(setup
+                .seed::<Request>()
+                .deserialize(&mut deserializer)
+                .is_err());
+        }
+    });
+}
+
+/// The seed uses its own setup; it's independent of the thread-local scope.
+#[test]
+fn independent_of_thread_local_scope() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_request();
+        let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&original).unwrap());
+        let other = NewBin::copy_from_slice(&[1u8; 100]).into_sync();
+        let other_setup = DefaultScopes::sync(&other);
+        let setup: ScopedRiSetup = DefaultScopes::sync(&msg);
+
+        let restored: Request = other_setup.scoped(|| {
+            let mut deserializer = serde_cbor::Deserializer::from_slice(msg.as_slice());
+            setup.seed().deserialize(&mut deserializer).unwrap()
+        });
+        assert_eq!(original, restored);
+        assert!(is_within(&msg, &restored.payloads[0]));
+
+        // can be used from another thread (no thread-local state)
+        let restored = std::thread::spawn(move || {
+            let setup = DefaultScopes::sync(&msg);
+            let mut deserializer = serde_cbor::Deserializer::from_slice(msg.as_slice());
+            let restored: Request = setup.seed().deserialize(&mut deserializer).unwrap();
+            assert!(is_within(&msg, restored.sender.as_bin()));
+            restored.sender
+        })
+        .join()
+        .unwrap();
+        assert_eq!(original.sender, restored);
+    });
+}
@@ -0,0 +1,262 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+use std::panic::{self, AssertUnwindSafe};
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    ri_deserialize_sbin, ri_deserialize_struct, AlwaysCopy, AnyBin, BinFactory, DefaultScopes,
+    NewSBin, NewSStr, RiPathStats, RiStats, SBin, SStr, StrFactory, SyncInterner,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+struct Message {
+    #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
+    name: SStr,
+    #[serde(deserialize_with = "abin::ri_deserialize_sbin")]
+    payload: SBin,
+}
+ri_deserialize_struct!(Message { name, payload });
+
+fn create_message() -> Message {
+    Message {
+        name: NewSStr::from_static("a name that's too long to be stored on the stack"),
+        payload: NewSBin::copy_from_slice(&[42u8; 1000]),
+    }
+}
+
+fn re_integrated(count: usize, bytes: usize) -> RiPathStats {
+    RiPathStats {
+        re_integrated: count,
+        re_integrated_bytes: bytes,
+        ..RiPathStats::default()
+    }
+}
+
+fn copied(count: usize, bytes: usize) -> RiPathStats {
+    RiPathStats {
+        copied: count,
+        copied_bytes: bytes,
+        ..RiPathStats::default()
+    }
+}
+
+#[test]
+fn borrowed_values_are_re_integrated() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_message();
+        let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&original).unwrap());
+        let (restored, stats) = DefaultScopes::sync(&msg)
+            .scoped_with_stats(|| serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(
+            RiStats {
+                borrowed_bytes: re_integrated(1, 1000),
+                borrowed_str: re_integrated(1, original.name.len()),
+                ..RiStats::default()
+            },
+            stats
+        );
+        assert_eq!(re_integrated(2, 1000 + original.name.len()), stats.total());
+    });
+}
+
+/// CBOR indefinite-length byte strings are never borrowed (the chunks are concatenated into a
+/// scratch buffer); binaries serialized as arrays end up in `seq`.
+#[test]
+fn cbor_copies() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        // indefinite-length byte string with two chunks of 100 bytes each.
+        let mut indefinite = vec![0x5F, 0x58, 100];
+        indefinite.extend_from_slice(&[1u8; 100]);
+        indefinite.extend_from_slice(&[0x58, 100]);
+        indefinite.extend_from_slice(&[2u8; 100]);
+        indefinite.push(0xFF);
+        let indefinite = NewSBin::from_given_vec(indefinite);
+        let (restored, stats) = DefaultScopes::sync(&indefinite).scoped_with_stats(|| {
+            let mut deserializer = serde_cbor::Deserializer::from_slice(indefinite.as_slice());
+            ri_deserialize_sbin(&mut deserializer).unwrap()
+        });
+        assert_eq!(200, restored.len());
+        assert_eq!(
+            RiStats {
+                bytes: copied(1, 200),
+                ..RiStats::default()
+            },
+            stats
+        );
+
+        let array = NewSBin::from_given_vec(serde_cbor::to_vec(&vec![7u8; 300]).unwrap());
+        let (restored, stats) = DefaultScopes::sync(&array).scoped_with_stats(|| {
+            let mut deserializer = serde_cbor::Deserializer::from_slice(array.as_slice());
+            ri_deserialize_sbin(&mut deserializer).unwrap()
+        });
+        assert_eq!(&[7u8; 300][..], restored.as_slice());
+        assert_eq!(
+            RiStats {
+                seq: copied(1, 300),
+                ..RiStats::default()
+            },
+            stats
+        );
+    });
+}
+
+/// Slices that are not part of a source binary are misses.
+#[test]
+fn misses() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_message();
+        let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&original).unwrap());
+        let unrelated = NewSBin::copy_from_slice(&[0u8; 100]);
+        let (_, stats) = DefaultScopes::sync(&unrelated)
+            .scoped_with_stats(|| serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(1, stats.borrowed_bytes.misses);
+        assert_eq!(1000, stats.borrowed_bytes.copied_bytes);
+        assert_eq!(2, stats.total().misses);
+        assert_eq!(0, stats.total().re_integrated);
+    });
+}
+
+/// JSON: binaries are text-encoded; strings with escape sequences are transient.
+#[test]
+fn json() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = Message {
+            name: NewSStr::copy_from_str("a\n"),
+            payload: NewSBin::copy_from_slice(&[42u8; 1000]),
+        };
+        let msg = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let setup = DefaultScopes::sync(&msg);
+        let (restored, stats) = setup.with_stats(|| {
+            let mut deserializer = serde_json::Deserializer::from_slice(msg.as_slice());
+            setup
+                .seed::<Message>()
+                .deserialize(&mut deserializer)
+                .unwrap()
+        });
+        assert_eq!(original, restored);
+        assert_eq!(
+            RiStats {
+                str: RiPathStats {
+                    stack: 1,
+                    ..RiPathStats::default()
+                },
+                text_encoded: copied(1, 1000),
+                ..RiStats::default()
+            },
+            stats
+        );
+    });
+}
+
+/// Values copied by the slice policy are not re-integrated (and not misses either).
+#[test]
+fn policy_copies() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = create_message();
+        let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&original).unwrap());
+        let (restored, stats) = DefaultScopes::sync_with_policy::<AlwaysCopy>(&msg)
+            .scoped_with_stats(|| serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(
+            RiStats {
+                borrowed_bytes: copied(1, 1000),
+                borrowed_str: copied(1, original.name.len()),
+                ..RiStats::default()
+            },
+            stats
+        );
+    });
+}
+
+/// Strings served by the interner are counted as `interned` (not as `copied`).
+#[test]
+fn interned() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let original = Message {
+            name: NewSStr::copy_from_str("an \"escaped\" name that's too long for the stack"),
+            payload: NewSBin::copy_from_slice(&[42u8; 10]),
+        };
+        let msg = NewSBin::from_given_vec(serde_json::to_vec(&original).unwrap());
+        let interner = SyncInterner::new();
+        let setup = DefaultScopes::sync(&msg).with_sync_interner(&interner);
+        let (restored, stats) =
+            setup.scoped_with_stats(|| serde_json::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(original, restored);
+        assert_eq!(
+            RiPathStats {
+                interned: 1,
+                ..RiPathStats::default()
+            },
+            stats.str
+        );
+        assert_eq!(0, stats.total().copied);
+
+        // misses are still counted
+        let unrelated = NewSBin::copy_from_slice(&[0u8; 100]);
+        let cbor = serde_cbor::to_vec(&create_message()).unwrap();
+        let (_, stats) = DefaultScopes::sync(&unrelated)
+            .with_sync_interner(&interner)
+            .scoped_with_stats(|| serde_cbor::from_slice::<Message>(&cbor).unwrap());
+        assert_eq!(
+            RiPathStats {
+                interned: 1,
+                misses: 1,
+                ..RiPathStats::default()
+            },
+            stats.borrowed_str
+        );
+    });
+}
+
+/// Nested calls: the outer call gets the stats of the inner call too. Without stats, nothing is
+/// collected.
+#[test]
+fn nested() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&create_message()).unwrap());
+        let setup = DefaultScopes::sync(&msg);
+        let deserialize = || serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap();
+        let ((_, inner), outer) = setup.scoped_with_stats(|| {
+            deserialize();
+            setup.with_stats(deserialize)
+        });
+        assert_eq!(1, inner.borrowed_bytes.re_integrated);
+        assert_eq!(2, outer.borrowed_bytes.re_integrated);
+
+        setup.scoped(deserialize);
+        let (_, stats) = setup.with_stats(|| ());
+        assert_eq!(RiStats::default(), stats);
+    });
+}
+
+/// If the function panics, the previous stats are restored (and get the stats collected so far).
+/// Note: Not checked for leaks (panicking allocates; e.g. the backtrace).
+#[test]
+fn panic_restores_previous_stats() {
+    let msg = NewSBin::from_given_vec(serde_cbor::to_vec(&create_message()).unwrap());
+    let setup = DefaultScopes::sync(&msg);
+    let deserialize = || serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap();
+    let (_, outer) = setup.scoped_with_stats(|| {
+        deserialize();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            setup.with_stats(|| {
+                deserialize();
+                panic!("Panic while collecting stats.");
+            })
+        }));
+        assert!(result.is_err());
+        deserialize();
+    });
+    assert_eq!(3, outer.borrowed_bytes.re_integrated);
+}
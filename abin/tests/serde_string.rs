@@ -27,7 +27,7 @@ fn deserialize_serialize_small() {
 
     // note: no allocation here
     let entity = mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(0),
             &MaExactNumberOfReAllocations(0),
@@ -48,7 +48,7 @@ fn deserialize_serialize_small() {
 
     // note: no allocation here
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(0),
             &MaExactNumberOfReAllocations(0),
@@ -66,7 +66,7 @@ fn deserialize_serialize_small() {
 fn deserialize_serialize_large() {
     // note: no allocation here
     let entity = mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(0),
             &MaExactNumberOfReAllocations(0),
@@ -89,7 +89,7 @@ fn deserialize_serialize_large() {
 
     // note: 2 allocations (& de-allocations) here.
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(2),
             &MaExactNumberOfReAllocations(0),
@@ -0,0 +1,162 @@
+#![cfg(feature = "serde")]
+
+use std::alloc::System;
+
+use serde::{Deserialize, Serialize};
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    ri_deserialize_bin, ri_deserialize_sbin, AnyBin, Base64Variant, Bin, BinFactory,
+    BinTextEncoding, NewBin, NewSBin, SBin,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Entity {
+    id: u32,
+    bin: Bin,
+    s_bin: SBin,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct PerField {
+    #[serde(with = "abin::serde_base64")]
+    standard: Bin,
+    #[serde(with = "abin::serde_base64_url")]
+    url_safe: SBin,
+    #[serde(with = "abin::serde_hex")]
+    hex: Bin,
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct ReIntegrated {
+    #[serde(deserialize_with = "ri_deserialize_bin")]
+    bin: Bin,
+    #[serde(deserialize_with = "ri_deserialize_sbin")]
+    s_bin: SBin,
+}
+
+/// Human-readable formats (JSON) get a Base64 string; binary formats (CBOR) still get the raw
+/// bytes.
+#[test]
+fn human_readable_is_text_encoded() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let entity = Entity {
+            id: 7,
+            bin: NewBin::from_static(b"foobar"),
+            s_bin: NewSBin::copy_from_slice(&[0xFB, 0xEF, 0xFF]),
+        };
+        let json = serde_json::to_string(&entity).unwrap();
+        assert_eq!(r#"{"id":7,"bin":"Zm9vYmFy","s_bin":"++//"}"#, json);
+        assert_eq!(entity, serde_json::from_str::<Entity>(&json).unwrap());
+
+        let cbor = serde_cbor::to_vec(&entity).unwrap();
+        assert!(cbor.windows(6).any(|window| window == b"foobar"));
+        assert_eq!(entity, serde_cbor::from_slice::<Entity>(&cbor).unwrap());
+
+        // large binaries
+        let large = Entity {
+            id: 8,
+            bin: NewBin::copy_from_slice(&BinGen::new(0, 1000).generate_to_vec()),
+            s_bin: NewSBin::copy_from_slice(&BinGen::new(3, 2000).generate_to_vec()),
+        };
+        let json = serde_json::to_vec(&large).unwrap();
+        assert_eq!(large, serde_json::from_slice::<Entity>(&json).unwrap());
+    });
+}
+
+#[test]
+fn per_field_encoding() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let value = PerField {
+            standard: NewBin::from_static(&[0xFB, 0xEF, 0xFF, 0x01]),
+            url_safe: NewSBin::from_static(&[0xFB, 0xEF, 0xFF, 0x01]),
+            hex: NewBin::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            r#"{"standard":"++//AQ==","url_safe":"--__AQ","hex":"deadbeef"}"#,
+            json
+        );
+        assert_eq!(value, serde_json::from_str::<PerField>(&json).unwrap());
+        // hex: uppercase is accepted too
+        let upper = json.replace("deadbeef", "DEADBEEF");
+        assert_eq!(value, serde_json::from_str::<PerField>(&upper).unwrap());
+
+        // binary formats are not affected
+        let cbor = serde_cbor::to_vec(&value).unwrap();
+        assert!(cbor
+            .windows(4)
+            .any(|window| window == [0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(value, serde_cbor::from_slice::<PerField>(&cbor).unwrap());
+    });
+}
+
+#[test]
+fn decode_errors_are_reported() {
+    let error = serde_json::from_str::<Entity>(r#"{"id":1,"bin":"Zm9v!","s_bin":""}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Invalid byte 0x21 at offset 4"), "{}", error);
+    assert!(
+        serde_json::from_str::<PerField>(r#"{"standard":"","url_safe":"","hex":"abc"}"#).is_err()
+    );
+    assert!(serde_json::from_str::<ReIntegrated>(r#"{"bin":"Zg=","s_bin":""}"#).is_err());
+}
+
+/// The re-integration functions accept the text-encoded form (the decoded binary can't be
+/// re-integrated).
+#[test]
+fn re_integration_accepts_encoded_form() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let entity = Entity {
+            id: 1,
+            bin: NewBin::copy_from_slice(&BinGen::new(0, 500).generate_to_vec()),
+            s_bin: NewSBin::from_static(b"foobar"),
+        };
+        let json = serde_json::to_string(&entity).unwrap();
+        let restored: ReIntegrated = serde_json::from_str(&json).unwrap();
+        assert_eq!(entity.bin, restored.bin);
+        assert_eq!(entity.s_bin, restored.s_bin);
+    });
+}
+
+/// The default encoding is fixed (use the per-field modules to choose another encoding).
+#[test]
+fn default_encoding() {
+    assert_eq!(
+        BinTextEncoding::Base64(Base64Variant::Standard),
+        BinTextEncoding::default_encoding()
+    );
+    let bin = NewBin::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(r#""3q2+7w==""#, serde_json::to_string(&bin).unwrap());
+}
+
+/// Arrays of numbers (the form used before binaries were text-encoded) are still accepted.
+#[test]
+fn legacy_array_form() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let legacy = r#"{"id":3,"bin":[102,111,111],"s_bin":[251,239,255]}"#;
+        let entity = serde_json::from_str::<Entity>(legacy).unwrap();
+        assert_eq!(b"foo", entity.bin.as_slice());
+        assert_eq!(&[0xFB, 0xEF, 0xFF], entity.s_bin.as_slice());
+
+        let restored: ReIntegrated =
+            serde_json::from_str(r#"{"bin":[102,111,111],"s_bin":[]}"#).unwrap();
+        assert_eq!(b"foo", restored.bin.as_slice());
+        assert!(restored.s_bin.is_empty());
+
+        let per_field = serde_json::from_str::<PerField>(
+            r#"{"standard":[1,2],"url_safe":[3],"hex":[222,173,190,239]}"#,
+        )
+        .unwrap();
+        assert_eq!(&[1, 2], per_field.standard.as_slice());
+        assert_eq!(&[3], per_field.url_safe.as_slice());
+        assert_eq!(&[0xDE, 0xAD, 0xBE, 0xEF], per_field.hex.as_slice());
+    });
+}
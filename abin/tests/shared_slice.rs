@@ -0,0 +1,179 @@
+use std::alloc::System;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, Bin, IntoSync, NewBin, NewSBin, NewSStr, NewStr, SBin, SStr, Str};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn arc_slice() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 1000).generate_to_vec();
+        let arc: Arc<[u8]> = Arc::from(content.as_slice());
+        let ptr = arc.as_ptr();
+
+        let (bin, sbin) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            (Bin::from(arc.clone()), SBin::from(arc.clone()))
+        });
+        assert_eq!(3, Arc::strong_count(&arc));
+        assert_eq!(ptr, bin.as_slice().as_ptr());
+        assert_eq!(ptr, sbin.as_slice().as_ptr());
+        assert_eq!(content.as_slice(), bin.as_slice());
+
+        // clone and slice share the pointer
+        let (clone, slice, slice_of_slice) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let slice = sbin.slice(100..900).unwrap();
+            let slice_of_slice = slice.slice(100..200).unwrap();
+            (bin.clone(), slice, slice_of_slice)
+        });
+        assert_eq!(6, Arc::strong_count(&arc));
+        assert_eq!(ptr, clone.as_slice().as_ptr());
+        assert_eq!(&content[100..900], slice.as_slice());
+        assert_eq!(unsafe { ptr.add(100) }, slice.as_slice().as_ptr());
+        assert_eq!(&content[200..300], slice_of_slice.as_slice());
+        assert_eq!(unsafe { ptr.add(200) }, slice_of_slice.as_slice().as_ptr());
+
+        // re-integration
+        assert!(slice.try_to_re_integrate(&content[0..10]).is_none());
+        let re_integrated = slice
+            .try_to_re_integrate(&slice_of_slice.as_slice()[10..20])
+            .unwrap();
+        assert_eq!(unsafe { ptr.add(210) }, re_integrated.as_slice().as_ptr());
+
+        // it's already synchronized: no copy
+        let synced = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || clone.into_sync());
+        assert_eq!(ptr, synced.as_slice().as_ptr());
+
+        // into vec copies
+        let vec = slice.into_vec();
+        assert_eq!(&content[100..900], vec.as_slice());
+
+        drop((bin, sbin, slice_of_slice, re_integrated, synced));
+        assert_eq!(1, Arc::strong_count(&arc));
+    });
+}
+
+#[test]
+fn rc_slice() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 1000).generate_to_vec();
+        let rc: Rc<[u8]> = Rc::from(content.as_slice());
+        let ptr = rc.as_ptr();
+
+        let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || Bin::from(rc.clone()));
+        assert_eq!(ptr, bin.as_slice().as_ptr());
+        let (clone, slice) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            (bin.clone(), bin.slice(500..1000).unwrap())
+        });
+        assert_eq!(4, Rc::strong_count(&rc));
+        assert_eq!(ptr, clone.as_slice().as_ptr());
+        assert_eq!(unsafe { ptr.add(500) }, slice.as_slice().as_ptr());
+        assert_eq!(&content[500..], slice.as_slice());
+
+        // synchronizing must copy.
+        let synced = slice.into_sync();
+        assert_ne!(unsafe { ptr.add(500) }, synced.as_slice().as_ptr());
+        assert_eq!(&content[500..], synced.as_slice());
+        assert_eq!(3, Rc::strong_count(&rc));
+
+        drop((bin, clone));
+        assert_eq!(1, Rc::strong_count(&rc));
+    });
+}
+
+#[test]
+fn get_mut_only_if_unique() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 100).generate_to_vec();
+        let mut bin = NewBin::from_arc(Arc::from(content.as_slice()));
+        let mut sliced = bin.slice(10..20).unwrap();
+        assert!(bin.get_mut().is_none());
+        assert!(sliced.get_mut().is_none());
+        drop(bin);
+        sliced.get_mut().unwrap()[0] = 255;
+        assert_eq!(255, sliced.as_slice()[0]);
+        assert_eq!(&content[11..20], &sliced.as_slice()[1..]);
+
+        let rc: Rc<[u8]> = Rc::from(content.as_slice());
+        let mut bin = NewBin::from_rc(rc.clone());
+        assert!(bin.get_mut().is_none());
+        drop(rc);
+        assert_eq!(100, bin.get_mut().unwrap().len());
+    });
+}
+
+#[test]
+fn boxed_slice() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 1000).generate_to_vec();
+        let boxed = content.clone().into_boxed_slice();
+        let ptr = boxed.as_ptr();
+        let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || Bin::from(boxed));
+        assert_eq!(ptr, bin.as_slice().as_ptr());
+        assert_eq!(content.as_slice(), bin.as_slice());
+        // into vec does not copy
+        let vec = bin.into_vec();
+        assert_eq!(ptr, vec.as_ptr());
+
+        let boxed = content.clone().into_boxed_slice();
+        let ptr = boxed.as_ptr();
+        let sbin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || SBin::from(boxed));
+        assert_eq!(ptr, sbin.as_slice().as_ptr());
+        assert_eq!(content.as_slice(), sbin.as_slice());
+    });
+}
+
+#[test]
+fn small_and_empty() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let arc: Arc<[u8]> = Arc::from(&[1u8, 2, 3][..]);
+        let bin = NewSBin::from_arc(arc.clone());
+        assert_eq!(&[1, 2, 3], bin.as_slice());
+        // stored on the stack: the arc is not retained.
+        assert_eq!(1, Arc::strong_count(&arc));
+
+        assert!(NewBin::from_rc(Rc::from(&[][..])).is_empty());
+        assert!(NewBin::from_boxed(Box::from(&[][..])).is_empty());
+        assert_eq!(&[7], NewSBin::from_boxed(Box::from(&[7u8][..])).as_slice());
+    });
+}
+
+#[test]
+fn strings() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        const CONTENT: &str = "🗻∈🌏 A string that's too long to be stored on the stack.";
+        let arc: Arc<str> = Arc::from(CONTENT);
+        let ptr = arc.as_ptr();
+        let (string, sstring) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            (Str::from(arc.clone()), SStr::from(arc.clone()))
+        });
+        assert_eq!(CONTENT, string.as_str());
+        assert_eq!(ptr, string.as_str().as_ptr());
+        assert_eq!(ptr, sstring.as_str().as_ptr());
+        let slice = sstring.slice(4..).unwrap();
+        assert_eq!(unsafe { ptr.add(4) }, slice.as_str().as_ptr());
+        assert_eq!(4, Arc::strong_count(&arc));
+
+        let rc: Rc<str> = Rc::from(CONTENT);
+        let string = NewStr::from_rc(rc.clone());
+        assert_eq!(rc.as_ptr(), string.as_str().as_ptr());
+
+        let boxed: Box<str> = Box::from(CONTENT);
+        let ptr = boxed.as_ptr();
+        let string = NewStr::from_boxed(boxed);
+        assert_eq!(ptr, string.as_str().as_ptr());
+        let boxed: Box<str> = Box::from(CONTENT);
+        let ptr = boxed.as_ptr();
+        let sstring = NewSStr::from_boxed(boxed);
+        assert_eq!(ptr, sstring.as_str().as_ptr());
+        assert_eq!(CONTENT, NewSStr::from_arc(Arc::from(CONTENT)).as_str());
+        assert_eq!("small", Str::from(Box::<str>::from("small")).as_str());
+    });
+}
@@ -22,7 +22,7 @@ pub mod utils;
 #[test]
 fn single_allocation_using_builder() {
     // ... and must not leak memory, of course.
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         let test_data = create_test_data();
         for number in 0u8..25u8 {
             let expected = test_data.compute_expected_result(number);
@@ -35,7 +35,7 @@ fn single_allocation_for(list: SegmentList, number: u8, expected: &str) {
     // one single allocation is allowed; if it's short (fits onto the stack), we expect 0 allocations;
     let number_of_allocations = if expected.len() > STACK_MAX_LEN { 1 } else { 0 };
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(number_of_allocations),
             // no re-allocation.
@@ -88,6 +88,8 @@ impl<'a> SrcSegment<'a> {
     }
 
     /// Include: 50% (even/odd) or remainder is 0.
+    // note: `%` instead of `is_multiple_of` (not available on older toolchains).
+    #[allow(clippy::manual_is_multiple_of)]
     fn is_include(&self, number: u8) -> bool {
         let even_odd_same = self.number % 2 == 0 && number % 2 == 0;
         if even_odd_same {
@@ -99,57 +101,63 @@ impl<'a> SrcSegment<'a> {
 }
 
 fn create_test_data() -> SegmentList<'static> {
-    let mut vec = Vec::<SrcSegment<'static>>::new();
-
-    vec.push(SrcSegment::new(0, StrSegment::Empty));
-    vec.push(SrcSegment::new(1, StrSegment::Empty));
-    vec.push(SrcSegment::new(2, StrSegment::Empty));
-    vec.push(SrcSegment::new(3, StrSegment::Empty));
-    vec.push(SrcSegment::new(4, StrSegment::Empty));
-    vec.push(SrcSegment::new(
-        5,
-        StrSegment::Static("Hello, this is some content in the list"),
-    ));
-    vec.push(SrcSegment::new(6, StrSegment::Empty));
-    vec.push(SrcSegment::new(7, StrSegment::Slice("stack")));
-    vec.push(SrcSegment::new(8, StrSegment::Empty));
-    vec.push(SrcSegment::new(9, StrSegment::Empty));
-    vec.push(SrcSegment::new(
-        10,
-        StrSegment::Static("... and some more content."),
-    ));
-    vec.push(SrcSegment::new(11, StrSegment::Char('A')));
-    vec.push(SrcSegment::new(12, StrSegment::Char('B')));
-    vec.push(SrcSegment::new(
-        13,
-        StrSegment::Str(NewStr::from_static(
-            "Some static content ... make this longer to make sure it does not fit onto the stack.",
-        )),
-    ));
-    vec.push(SrcSegment::new(14, StrSegment::Char('C')));
-    vec.push(SrcSegment::new(15, StrSegment::Char('D')));
-    vec.push(SrcSegment::new(16, StrSegment::Empty));
-    vec.push(SrcSegment::new(17, StrSegment::Empty));
-    vec.push(SrcSegment::new(18, StrSegment::Slice("stack")));
-    vec.push(SrcSegment::new(19, StrSegment::Empty));
-    vec.push(SrcSegment::new(20, StrSegment::Char('E')));
-    vec.push(SrcSegment::new(21, StrSegment::Char('F')));
-    vec.push(SrcSegment::new(22, StrSegment::Char('G')));
-    vec.push(SrcSegment::new(23, StrSegment::Char('G')));
-    vec.push(SrcSegment::new(24, StrSegment::Empty));
-    vec.push(SrcSegment::new(25, StrSegment::Empty));
-    vec.push(SrcSegment::new(26, StrSegment::Slice("stack2")));
-    vec.push(SrcSegment::new(27, StrSegment::Slice("stack3")));
-    vec.push(SrcSegment::new(28, StrSegment::Empty));
-    vec.push(SrcSegment::new(29, StrSegment::GivenString("This is content from a given string. Might be a bit longer. At least it's too long for the stack.".to_owned())));
-    vec.push(SrcSegment::new(
-        30,
-        StrSegment::GivenString(
-            "...and another string... longer ... even longer ... to long for the stack..."
-                .to_owned(),
+    let vec = vec![
+        SrcSegment::new(0, StrSegment::Empty),
+        SrcSegment::new(1, StrSegment::Empty),
+        SrcSegment::new(2, StrSegment::Empty),
+        SrcSegment::new(3, StrSegment::Empty),
+        SrcSegment::new(4, StrSegment::Empty),
+        SrcSegment::new(
+            5,
+            StrSegment::Static("Hello, this is some content in the list"),
+        ),
+        SrcSegment::new(6, StrSegment::Empty),
+        SrcSegment::new(7, StrSegment::Slice("stack")),
+        SrcSegment::new(8, StrSegment::Empty),
+        SrcSegment::new(9, StrSegment::Empty),
+        SrcSegment::new(
+            10,
+            StrSegment::Static("... and some more content."),
+        ),
+        SrcSegment::new(11, StrSegment::Char('A')),
+        SrcSegment::new(12, StrSegment::Char('B')),
+        SrcSegment::new(
+            13,
+            StrSegment::Str(NewStr::from_static(
+                "Some static content ... make this longer to make sure it does not fit onto the stack.",
+            )),
+        ),
+        SrcSegment::new(14, StrSegment::Char('C')),
+        SrcSegment::new(15, StrSegment::Char('D')),
+        SrcSegment::new(16, StrSegment::Empty),
+        SrcSegment::new(17, StrSegment::Empty),
+        SrcSegment::new(18, StrSegment::Slice("stack")),
+        SrcSegment::new(19, StrSegment::Empty),
+        SrcSegment::new(20, StrSegment::Char('E')),
+        SrcSegment::new(21, StrSegment::Char('F')),
+        SrcSegment::new(22, StrSegment::Char('G')),
+        SrcSegment::new(23, StrSegment::Char('G')),
+        SrcSegment::new(24, StrSegment::Empty),
+        SrcSegment::new(25, StrSegment::Empty),
+        SrcSegment::new(26, StrSegment::Slice("stack2")),
+        SrcSegment::new(27, StrSegment::Slice("stack3")),
+        SrcSegment::new(28, StrSegment::Empty),
+        SrcSegment::new(
+            29,
+            StrSegment::GivenString(
+                "This is content from a given string. Might be a bit longer. At least it's too long for the stack."
+                    .to_owned(),
+            ),
+        ),
+        SrcSegment::new(
+            30,
+            StrSegment::GivenString(
+                "...and another string... longer ... even longer ... to long for the stack..."
+                    .to_owned(),
+            ),
         ),
-    ));
-    vec.push(SrcSegment::new(31, StrSegment::Empty));
+        SrcSegment::new(31, StrSegment::Empty),
+    ];
 
     SegmentList(vec)
 }
@@ -16,10 +16,10 @@ const STACK_MAX_LEN: usize = 3 * core::mem::size_of::<usize>() - 1;
 /// that has enough capacity to contain the meta-data).
 #[test]
 fn single_allocation() {
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         // note: Start must be `STACK_MAX_LEN + 1)` (if not, we'd have no allocation).
         for index in (STACK_MAX_LEN + 1)..255 {
-            let vec = BinGen::new(index as u8, index as usize).generate_to_vec();
+            let vec = BinGen::new(index as u8, index).generate_to_vec();
             copy_from_slice::<NewBin>(vec.as_slice());
             from_iter::<NewBin>(vec.as_slice());
             copy_from_slice::<NewSBin>(vec.as_slice());
@@ -33,7 +33,7 @@ fn single_allocation() {
 
 fn copy_from_slice<T: BinFactory>(slice: &[u8]) {
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(1),
             &MaExactNumberOfDeAllocations(1),
@@ -50,7 +50,7 @@ fn copy_from_slice<T: BinFactory>(slice: &[u8]) {
 
 fn from_iter<T: BinFactory>(slice: &[u8]) {
     mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(1),
             &MaExactNumberOfDeAllocations(1),
@@ -58,7 +58,7 @@ fn from_iter<T: BinFactory>(slice: &[u8]) {
         ]),
         || {
             // one single allocation
-            let bin = T::from_iter(slice.iter().map(|item| *item));
+            let bin = T::from_iter(slice.iter().copied());
             assert_eq!(bin.as_slice(), slice);
             // and one single de-allocation for `bin.drop()`
         },
@@ -90,17 +90,12 @@ fn from_multiple_parts<T: BinFactory>() {
         item_8.as_slice(),
         item_9.as_slice(),
     ];
-    let expected_result: Vec<u8> = items
-        .iter()
-        .map(|item| item.iter())
-        .flatten()
-        .map(|item| *item)
-        .collect();
+    let expected_result: Vec<u8> = items.iter().flat_map(|item| item.iter()).copied().collect();
     let expected_len = expected_result.len();
 
     // one single allocation / no re-allocations
     let bin = mem_scoped(
-        &GLOBAL,
+        GLOBAL,
         &MaAnd(&[
             &MaExactNumberOfAllocations(1),
             &MaExactNumberOfReAllocations(0),
@@ -124,8 +119,8 @@ fn from_multiple_parts<T: BinFactory>() {
             assert_eq!(expected_len, iterator.exact_number_of_bytes().unwrap());
 
             // one single allocation here
-            let bin = T::from_segments(iterator);
-            bin
+
+            T::from_segments(iterator)
         },
     );
 
@@ -0,0 +1,305 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AlwaysCopy, AlwaysShare, AnyBin, BinFactory, BinSegment, DefaultExcessShrink,
+    DefaultSlicePolicy, GivenVecConfig, GivenVecOptimization, NewBin, NewSBin, NewStr,
+    SegmentsSlice, SlicePolicy, StorageKind, StrFactory, StrSegment,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn compact() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        compact_small_view_of_large_buffer::<NewBin>();
+        compact_small_view_of_large_buffer::<NewSBin>();
+        compact_does_nothing::<NewBin>();
+        compact_does_nothing::<NewSBin>();
+        compact_with_policies::<NewBin>();
+        compact_with_policies::<NewSBin>();
+    });
+}
+
+#[test]
+fn factories_with_policy() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        segments_with_policy::<NewBin>();
+        segments_with_policy::<NewSBin>();
+
+        let content = "🗻∈🌏".repeat(5000);
+        let string = NewStr::copy_from_str(content.as_str());
+        let copied =
+            NewStr::from_segment_with_policy::<DefaultSlicePolicy>(string.slice(4..11).unwrap());
+        assert_eq!("∈🌏", copied.as_str());
+        assert_eq!(Some(1), string.as_bin().strong_count());
+        let segments = &mut [StrSegment::Str(string.slice(4..11).unwrap())];
+        let shared = NewStr::from_segments_with_policy::<AlwaysShare>(SegmentsSlice::new(segments));
+        assert_eq!("∈🌏", shared.as_str());
+        assert_eq!(Some(2), string.as_bin().strong_count());
+    });
+}
+
+#[test]
+fn slice_with_policy() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        slice_with_policies::<NewBin>();
+        slice_with_policies::<NewSBin>();
+        vec_slice_with_policy::<NewBin>();
+        vec_slice_with_policy::<NewSBin>();
+        custom_policy::<NewBin>();
+        custom_policy::<NewSBin>();
+    });
+}
+
+#[test]
+fn strings() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = "🗻∈🌏".repeat(5000);
+        let string = NewStr::copy_from_str(content.as_str());
+        let small = string
+            .slice_with_policy::<DefaultSlicePolicy, _>(4..11)
+            .unwrap();
+        assert_eq!("∈🌏", small.as_str());
+        assert_eq!(Some(1), string.as_bin().strong_count());
+        // not on a char boundary
+        assert!(string
+            .slice_with_policy::<DefaultSlicePolicy, _>(5..11)
+            .is_none());
+
+        let mut shared = string.slice(4..11).unwrap();
+        drop(string);
+        assert!(shared.compact());
+        assert_eq!("∈🌏", shared.as_str());
+        assert!(!shared.compact());
+    });
+}
+
+fn compact_small_view_of_large_buffer<T: BinFactory>() {
+    let content = BinGen::new(0, 100_000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let mut small = bin.slice(1000..1100).unwrap();
+    assert!(small.retained_bytes() >= 100_000);
+    assert_eq!(Some(2), bin.strong_count());
+
+    // exactly one allocation (exact fit).
+    let compacted = mem_scoped(
+        GLOBAL,
+        &MaAnd(&[
+            &MaExactNumberOfAllocations(1),
+            &MaExactNumberOfReAllocations(0),
+        ]),
+        || small.compact(),
+    );
+    assert!(compacted);
+    assert_eq!(&content[1000..1100], small.as_slice());
+    assert!(small.retained_bytes() < 200);
+    assert_eq!(Some(1), bin.strong_count());
+    assert_eq!(Some(1), small.strong_count());
+}
+
+fn compact_does_nothing<T: BinFactory>() {
+    let content = BinGen::new(0, 100_000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        // large view of a buffer
+        let mut large = bin.slice(100..90_000).unwrap();
+        assert!(!large.compact());
+        // no buffer at all
+        assert!(!T::from_static(&[1u8; 1000][10..20]).compact());
+        assert!(!T::copy_from_slice(&[1, 2, 3]).compact());
+        assert!(!T::empty().compact());
+    });
+    // exact fit already
+    let mut exact = T::copy_from_slice(&content[0..1000]);
+    assert!(!exact.compact());
+}
+
+fn slice_with_policies<T: BinFactory>() {
+    let content = BinGen::new(0, 100_000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+
+    let shared = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        bin.slice_with_policy::<AlwaysShare, _>(10..20).unwrap()
+    });
+    assert_eq!(&content[10..20], shared.as_slice());
+    assert_eq!(Some(2), bin.strong_count());
+    drop(shared);
+
+    let copied = bin.slice_with_policy::<AlwaysCopy, _>(10..90_000).unwrap();
+    assert_eq!(&content[10..90_000], copied.as_slice());
+    assert_eq!(Some(1), bin.strong_count());
+
+    // default: small views are copied, large ones are shared
+    let small = bin
+        .slice_with_policy::<DefaultSlicePolicy, _>(1000..1100)
+        .unwrap();
+    assert_eq!(&content[1000..1100], small.as_slice());
+    assert_eq!(Some(1), bin.strong_count());
+    let large = bin
+        .slice_with_policy::<DefaultSlicePolicy, _>(1000..90_000)
+        .unwrap();
+    assert_eq!(&content[1000..90_000], large.as_slice());
+    assert_eq!(Some(2), bin.strong_count());
+
+    // static: always shared
+    let static_bin = T::from_static(&[1u8; 100_000]);
+    let static_slice = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        static_bin
+            .slice_with_policy::<DefaultSlicePolicy, _>(10..110)
+            .unwrap()
+    });
+    assert_eq!(StorageKind::Static, static_slice.storage_kind());
+
+    // out of bounds
+    assert!(bin.slice_with_policy::<AlwaysCopy, _>(0..100_001).is_none());
+}
+
+struct ConstructionConfig;
+
+impl GivenVecConfig for ConstructionConfig {
+    type TExcessShrink = DefaultExcessShrink;
+
+    fn optimization() -> GivenVecOptimization {
+        GivenVecOptimization::Construction
+    }
+}
+
+fn compact_with_policies<T: BinFactory>() {
+    let content = BinGen::new(0, 1000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let mut slice = bin.slice(10..900).unwrap();
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        assert!(!slice.compact_with_policy::<AlwaysShare>());
+        // a large view: the default policy does not copy
+        assert!(!slice.compact_with_policy::<DefaultSlicePolicy>());
+    });
+    assert_eq!(Some(2), bin.strong_count());
+    assert!(slice.compact_with_policy::<AlwaysCopy>());
+    assert_eq!(Some(1), bin.strong_count());
+    assert_eq!(&content[10..900], slice.as_slice());
+}
+
+fn segments_with_policy<T: BinFactory>() {
+    let content = BinGen::new(0, 100_000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+
+    // single binary: compacted
+    let copied = T::from_segment_with_policy::<DefaultSlicePolicy>(BinSegment::Bin(
+        bin.slice(1000..1100).unwrap(),
+    ));
+    assert_eq!(&content[1000..1100], copied.as_slice());
+    assert!(copied.retained_bytes() < 200);
+    let segments = &mut [
+        BinSegment::Empty,
+        BinSegment::Bin(bin.slice(10..20).unwrap()),
+    ];
+    let copied = T::from_segments_with_policy::<AlwaysCopy>(SegmentsSlice::new(segments));
+    assert_eq!(&content[10..20], copied.as_slice());
+    assert_eq!(Some(1), bin.strong_count());
+
+    // large views are shared
+    let segments = &mut [BinSegment::Bin(bin.slice(1000..90_000).unwrap())];
+    let shared = T::from_segments_with_policy::<DefaultSlicePolicy>(SegmentsSlice::new(segments));
+    assert_eq!(&content[1000..90_000], shared.as_slice());
+    assert_eq!(Some(2), bin.strong_count());
+    drop(shared);
+
+    // other segments and multiple segments: same as without policy
+    let segments = &mut [
+        BinSegment::Bin(bin.slice(0..10).unwrap()),
+        BinSegment::Static(&[1, 2, 3]),
+    ];
+    let joined = T::from_segments_with_policy::<AlwaysShare>(SegmentsSlice::new(segments));
+    assert_eq!(13, joined.len());
+    assert_eq!(Some(1), bin.strong_count());
+    let empty = T::from_segments_with_policy::<AlwaysShare>(SegmentsSlice::new(&mut []));
+    assert!(empty.is_empty());
+}
+
+/// The vector backend can't share: copying does not copy twice.
+fn vec_slice_with_policy<T: BinFactory>() {
+    let content = BinGen::new(0, 10_000).generate_to_vec();
+    let mut vec = content.clone();
+    vec.shrink_to_fit();
+    let bin = T::from_given_vec_with_config::<ConstructionConfig>(vec);
+    assert_eq!(StorageKind::Vec, bin.storage_kind());
+    let copied = mem_scoped(
+        GLOBAL,
+        &MaAnd(&[
+            &MaExactNumberOfAllocations(1),
+            &MaExactNumberOfReAllocations(0),
+        ]),
+        || {
+            bin.slice_with_policy::<DefaultSlicePolicy, _>(100..200)
+                .unwrap()
+        },
+    );
+    assert_eq!(&content[100..200], copied.as_slice());
+}
+
+/// Copies everything that's less than half of the buffer.
+struct HalfPolicy;
+
+impl SlicePolicy for HalfPolicy {
+    fn copy(_storage_kind: StorageKind, slice_len: usize, retained_bytes: usize) -> bool {
+        slice_len < retained_bytes / 2
+    }
+}
+
+fn custom_policy<T: BinFactory>() {
+    let content = BinGen::new(0, 1000).generate_to_vec();
+    let bin = T::copy_from_slice(&content);
+    let copied = bin.slice_with_policy::<HalfPolicy, _>(0..400).unwrap();
+    assert_eq!(Some(1), bin.strong_count());
+    let shared = bin.slice_with_policy::<HalfPolicy, _>(0..600).unwrap();
+    assert_eq!(Some(2), bin.strong_count());
+    assert_eq!(&content[0..400], copied.as_slice());
+    assert_eq!(&content[0..600], shared.as_slice());
+}
+
+/// The serde re-integration path consults the policy.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_re_integration_with_policy() {
+    use abin::{DefaultScopes, SBin, SStr};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize)]
+    struct Message {
+        #[serde(deserialize_with = "abin::ri_deserialize_sstr")]
+        name: SStr,
+        #[serde(deserialize_with = "abin::ri_deserialize_sbin")]
+        payload: SBin,
+    }
+
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let name = "A name that's too long to be stored on the stack";
+        let payload = BinGen::new(0, 50_000).generate_to_vec();
+        let serialized = serde_cbor::to_vec(&Message {
+            name: abin::NewSStr::from_static(name),
+            payload: NewSBin::copy_from_slice(&payload),
+        })
+        .unwrap();
+        let msg = NewSBin::copy_from_slice(&serialized);
+
+        // always share: both keep the message alive
+        let message = DefaultScopes::sync(&msg)
+            .scoped(|| serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(Some(3), msg.strong_count());
+        drop(message);
+
+        // default policy: the small name is copied; the large payload is shared.
+        let message = DefaultScopes::sync_with_policy::<DefaultSlicePolicy>(&msg)
+            .scoped(|| serde_cbor::from_slice::<Message>(msg.as_slice()).unwrap());
+        assert_eq!(name, message.name.as_str());
+        assert_eq!(payload.as_slice(), message.payload.as_slice());
+        assert_eq!(Some(2), msg.strong_count());
+        assert_eq!(Some(1), message.name.as_bin().strong_count());
+    });
+}
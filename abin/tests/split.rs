@@ -0,0 +1,204 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AnyBin, BinFactory, DefaultExcessShrink, GivenVecConfig, GivenVecOptimization, NewBin, NewSBin,
+    NewSStr, NewStr, StrFactory,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn split_binaries() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        split_all_types::<NewBin>();
+        split_all_types::<NewSBin>();
+        split_rc_does_not_allocate::<NewBin>();
+        split_rc_does_not_allocate::<NewSBin>();
+        split_vec_converts_once::<NewBin>();
+        split_vec_converts_once::<NewSBin>();
+        peel_frames::<NewBin>();
+        peel_frames::<NewSBin>();
+    });
+}
+
+#[test]
+fn split_strings() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        split_str::<NewStr>();
+        split_str::<NewSStr>();
+    });
+}
+
+struct ConstructionConfig;
+
+impl GivenVecConfig for ConstructionConfig {
+    type TExcessShrink = DefaultExcessShrink;
+
+    fn optimization() -> GivenVecOptimization {
+        GivenVecOptimization::Construction
+    }
+}
+
+/// creates the same content using all binary types (static, stack, rc, vec).
+fn all_types<T: BinFactory>(content: &'static [u8]) -> Vec<T::T> {
+    let mut vec_without_excess = content.to_vec();
+    vec_without_excess.shrink_to_fit();
+    vec![
+        T::from_static(content),
+        T::copy_from_slice(content),
+        T::from_given_vec(content.to_vec()),
+        T::from_given_vec_with_config::<ConstructionConfig>(vec_without_excess),
+    ]
+}
+
+fn split_all_types<T: BinFactory>() {
+    const SHORT: &[u8] = "HeaderPayload".as_bytes();
+    const LONG: &[u8] =
+        "A header that's long enough|and a payload that's long enough too (heap)".as_bytes();
+
+    for content in &[SHORT, LONG] {
+        let len = content.len();
+        for at in 0..=len {
+            for bin in all_types::<T>(content) {
+                let mut bin_to = bin.clone();
+                let head = bin_to.split_to(at).unwrap();
+                assert_eq!(&content[..at], head.as_slice());
+                assert_eq!(&content[at..], bin_to.as_slice());
+
+                let mut bin_off = bin.clone();
+                let tail = bin_off.split_off(at).unwrap();
+                assert_eq!(&content[..at], bin_off.as_slice());
+                assert_eq!(&content[at..], tail.as_slice());
+
+                let mut bin_adv = bin.clone();
+                assert!(bin_adv.advance(at));
+                assert_eq!(&content[at..], bin_adv.as_slice());
+            }
+        }
+
+        // out of bounds: unchanged
+        for mut bin in all_types::<T>(content) {
+            assert!(bin.split_to(len + 1).is_none());
+            assert!(bin.split_off(len + 1).is_none());
+            assert!(!bin.advance(len + 1));
+            assert_eq!(*content, bin.as_slice());
+        }
+    }
+
+    // empty
+    let mut empty = T::empty();
+    assert!(empty.split_to(0).unwrap().is_empty());
+    assert!(empty.split_off(0).unwrap().is_empty());
+    assert!(empty.advance(0));
+    assert!(empty.split_to(1).is_none());
+}
+
+/// For reference-counted binaries, splitting just adjusts the pointers.
+fn split_rc_does_not_allocate<T: BinFactory>() {
+    let content = BinGen::new(0, 1000).generate_to_vec();
+    let mut bin = T::copy_from_slice(&content);
+    let ptr = bin.as_slice().as_ptr();
+    let (head, tail) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let head = bin.split_to(100).unwrap();
+        let tail = bin.split_off(800).unwrap();
+        assert!(bin.advance(50));
+        (head, tail)
+    });
+    assert_eq!(ptr, head.as_slice().as_ptr());
+    assert_eq!(&content[0..100], head.as_slice());
+    assert_eq!(&content[150..900], bin.as_slice());
+    assert_eq!(&content[900..], tail.as_slice());
+}
+
+/// The vector binary is converted to a reference-counted binary once; all further operations
+/// do not allocate.
+fn split_vec_converts_once<T: BinFactory>() {
+    let content = BinGen::new(0, 1000).generate_to_vec();
+    let mut vec = content.clone();
+    vec.shrink_to_fit();
+    let mut bin = T::from_given_vec_with_config::<ConstructionConfig>(vec);
+
+    let head = bin.split_to(100).unwrap();
+    let (head2, tail) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+        let head2 = bin.split_to(100).unwrap();
+        let tail = bin.split_off(600).unwrap();
+        assert!(bin.advance(10));
+        (head2, tail)
+    });
+    assert_eq!(&content[0..100], head.as_slice());
+    assert_eq!(&content[100..200], head2.as_slice());
+    assert_eq!(&content[210..800], bin.as_slice());
+    assert_eq!(&content[800..], tail.as_slice());
+}
+
+/// Typical use case: peel length-prefixed frames off a buffer.
+fn peel_frames<T: BinFactory>() {
+    let mut buffer = Vec::new();
+    for frame_len in 0u8..60 {
+        buffer.push(frame_len);
+        buffer.extend(BinGen::new(frame_len, frame_len as usize).generate());
+    }
+    let mut bin = T::copy_from_slice(&buffer);
+    let mut frame_len_expected = 0u8;
+    while !bin.is_empty() {
+        let frame_len = bin.as_slice()[0];
+        assert!(bin.advance(1));
+        let frame = bin.split_to(frame_len as usize).unwrap();
+        assert_eq!(frame_len_expected, frame_len);
+        assert_eq!(
+            BinGen::new(frame_len, frame_len as usize).generate_to_vec(),
+            frame.as_slice()
+        );
+        frame_len_expected += 1;
+    }
+    assert_eq!(60, frame_len_expected);
+}
+
+fn split_str<T: StrFactory>() {
+    const CONTENT: &str = "🗻∈🌏 and some longer text (so it's stored on the heap)";
+    for string in [
+        T::from_static(CONTENT),
+        T::copy_from_str(CONTENT),
+        T::from_given_string(CONTENT.to_owned()),
+    ]
+    .iter()
+    {
+        for at in 0..=CONTENT.len() + 1 {
+            let boundary = CONTENT.is_char_boundary(at);
+
+            let mut str_to = string.clone();
+            let head = str_to.split_to(at);
+            assert_eq!(boundary, head.is_some());
+            if let Some(head) = head {
+                assert_eq!(&CONTENT[..at], head.as_str());
+                assert_eq!(&CONTENT[at..], str_to.as_str());
+            } else {
+                assert_eq!(CONTENT, str_to.as_str());
+            }
+
+            let mut str_off = string.clone();
+            let tail = str_off.split_off(at);
+            assert_eq!(boundary, tail.is_some());
+            if let Some(tail) = tail {
+                assert_eq!(&CONTENT[..at], str_off.as_str());
+                assert_eq!(&CONTENT[at..], tail.as_str());
+            } else {
+                assert_eq!(CONTENT, str_off.as_str());
+            }
+
+            let mut str_adv = string.clone();
+            assert_eq!(boundary, str_adv.advance(at));
+            if boundary {
+                assert_eq!(&CONTENT[at..], str_adv.as_str());
+            } else {
+                assert_eq!(CONTENT, str_adv.as_str());
+            }
+        }
+    }
+}
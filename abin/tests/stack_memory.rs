@@ -15,7 +15,7 @@ const FITS_STACK: usize = 3 * core::mem::size_of::<usize>() - 1;
 
 #[test]
 fn stack_memory() {
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         copy_from_slice::<NewBin>();
         copy_from_slice::<NewSBin>();
         from_vec::<NewBin>();
@@ -28,7 +28,7 @@ fn stack_memory() {
 /// rc also uses stack and does not allocate for small binaries.
 fn copy_from_slice<T: BinFactory>() {
     let slice = [15u8; FITS_STACK];
-    mem_scoped(&GLOBAL, &MaNoAllocNoDealloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoDealloc, || {
         T::copy_from_slice(&slice);
     });
 }
@@ -37,7 +37,7 @@ fn copy_from_slice<T: BinFactory>() {
 fn from_vec<T: BinFactory>() {
     let slice = [15u8; FITS_STACK];
     let vec = slice.to_vec();
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         T::from_given_vec(vec);
     });
 }
@@ -46,7 +46,7 @@ fn from_vec<T: BinFactory>() {
 fn from_iter<T: BinFactory>() {
     let slice = [15u8; FITS_STACK];
     let vec = Vec::from(&slice as &[u8]);
-    mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
         T::from_iter(vec);
     });
 }
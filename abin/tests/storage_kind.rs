@@ -0,0 +1,133 @@
+use std::alloc::System;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::spi::{BinData, FnTable, UnsafeBin};
+use abin::{AnyBin, Bin, BinFactory, IntoUnSyncView, NewBin, NewSBin, StorageKind};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn storage_kind_of_all_backends() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let large = BinGen::new(0, 200).generate_to_vec();
+        assert_eq!(StorageKind::Empty, NewBin::empty().storage_kind());
+        assert_eq!(
+            StorageKind::Stack,
+            NewBin::copy_from_slice(&[1, 2]).storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Static,
+            NewBin::from_static(&[1, 2]).storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Rc,
+            NewBin::copy_from_slice(&large).storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Arc,
+            NewSBin::copy_from_slice(&large).storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Vec,
+            NewBin::from_boxed(large.clone().into_boxed_slice()).storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Rc,
+            NewBin::from_rc(Rc::from(large.as_slice())).storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Arc,
+            NewSBin::from_arc(Arc::from(large.as_slice())).storage_kind()
+        );
+        // the synchronized view reports the same
+        assert_eq!(
+            StorageKind::Arc,
+            NewSBin::copy_from_slice(&large).un_sync().storage_kind()
+        );
+    });
+}
+
+#[test]
+fn strong_count() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let large = BinGen::new(0, 200).generate_to_vec();
+        for bin in [
+            NewBin::copy_from_slice(&large),
+            NewSBin::copy_from_slice(&large).un_sync(),
+            NewBin::from_rc(Rc::from(large.as_slice())),
+            NewBin::from_arc(Arc::from(large.as_slice())),
+        ] {
+            assert_eq!(Some(1), bin.strong_count());
+            let clone = bin.clone();
+            let slice = bin.slice(10..20).unwrap();
+            assert_eq!(Some(3), bin.strong_count());
+            assert_eq!(Some(3), slice.strong_count());
+            drop(clone);
+            assert_eq!(Some(2), slice.strong_count());
+            drop(bin);
+            assert_eq!(Some(1), slice.strong_count());
+        }
+
+        // not reference-counted
+        assert_eq!(None, NewBin::empty().strong_count());
+        assert_eq!(None, NewBin::copy_from_slice(&[1, 2]).strong_count());
+        assert_eq!(None, NewBin::from_static(&[1, 2]).strong_count());
+        assert_eq!(
+            None,
+            NewBin::from_boxed(large.into_boxed_slice()).strong_count()
+        );
+    });
+}
+
+#[test]
+fn retained_bytes() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let large = BinGen::new(0, 10_000).generate_to_vec();
+
+        // a small slice keeps the entire buffer alive.
+        for bin in [
+            NewBin::copy_from_slice(&large),
+            NewSBin::copy_from_slice(&large).un_sync(),
+        ] {
+            let slice = bin.slice(100..130).unwrap();
+            drop(bin);
+            assert_eq!(30, slice.len());
+            assert!(slice.retained_bytes() >= 10_000);
+        }
+        let bin = NewBin::from_arc(Arc::from(large.as_slice()));
+        assert_eq!(10_000, bin.slice(0..100).unwrap().retained_bytes());
+
+        // vector: the capacity
+        let bin = NewBin::from_boxed(large.clone().into_boxed_slice());
+        assert_eq!(StorageKind::Vec, bin.storage_kind());
+        assert_eq!(10_000, bin.retained_bytes());
+
+        // no buffer
+        assert_eq!(0, NewBin::empty().retained_bytes());
+        assert_eq!(0, NewBin::copy_from_slice(&[1, 2]).retained_bytes());
+        assert_eq!(0, NewBin::from_static(&[0u8; 100]).retained_bytes());
+    });
+}
+
+/// Custom implementations that do not implement the hooks.
+#[test]
+fn custom_without_hooks() {
+    let bin = unsafe { Bin::_new(BinData::empty(), &CUSTOM_FN_TABLE) };
+    assert_eq!(StorageKind::Custom, bin.storage_kind());
+    assert_eq!(None, bin.strong_count());
+    // reports the length
+    assert_eq!(0, bin.retained_bytes());
+}
+
+const CUSTOM_FN_TABLE: FnTable = FnTable::new(
+    |_| unsafe { Bin::_new(BinData::empty(), &CUSTOM_FN_TABLE) },
+    |_| Vec::new(),
+    |_, _, _| None,
+);
@@ -0,0 +1,114 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, AnyStr, NewSStr, NewStr, StorageKind, StrFactory, TransformBin};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+const INPUTS: &[&str] = &[
+    "",
+    "a",
+    "Hello",
+    "Grüße, Jürgen ❤",
+    "🗻∈🌏",
+    "\u{FFFF}\u{10000}",
+];
+
+/// `encode_utf16_bin` can be used in generic code.
+fn encode_utf16<T: TransformBin>(string: &AnyStr<T>) -> T {
+    string.encode_utf16_bin()
+}
+
+/// Compares the results with the results of the standard library.
+#[test]
+fn utf16_same_as_std() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for input in INPUTS {
+            let large = input.repeat(20);
+            for input in &[*input, large.as_str()] {
+                let units: Vec<u16> = input.encode_utf16().collect();
+                assert_eq!(*input, NewStr::from_utf16(&units).unwrap().as_str());
+                assert_eq!(*input, NewSStr::from_utf16(&units).unwrap().as_str());
+                assert_eq!(*input, NewStr::from_utf16_lossy(&units).as_str());
+
+                let string = NewStr::copy_from_str(*input);
+                let le_bytes: Vec<u8> = units.iter().flat_map(|unit| unit.to_le_bytes()).collect();
+                assert_eq!(le_bytes.as_slice(), encode_utf16(&string).as_slice());
+                let string = NewSStr::copy_from_str(*input);
+                assert_eq!(le_bytes.as_slice(), encode_utf16(&string).as_slice());
+            }
+        }
+        let invalid: &[(&[u16], usize)] = &[
+            (&[0xD800], 0),
+            (&[0x61, 0xDC00, 0x62], 1),
+            (&[0xD83D, 0xD83D, 0xDE00], 0),
+            (&[0xD83D, 0xDE00, 0x62, 0xD800], 3),
+        ];
+        for (units, index) in invalid {
+            assert_eq!(*index, NewStr::from_utf16(units).unwrap_err().index());
+            assert_eq!(*index, NewSStr::from_utf16(units).unwrap_err().index());
+            assert_eq!(
+                String::from_utf16_lossy(units),
+                NewStr::from_utf16_lossy(units).as_str()
+            );
+            assert_eq!(
+                String::from_utf16_lossy(units),
+                NewSStr::from_utf16_lossy(units).as_str()
+            );
+        }
+    });
+}
+
+#[test]
+fn latin1() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let all: Vec<u8> = (0..=255).collect();
+        let expected: String = all.iter().map(|byte| char::from(*byte)).collect();
+        assert_eq!(expected, NewStr::from_latin1(&all).as_str());
+        assert_eq!(expected, NewSStr::from_latin1(&all).as_str());
+        assert_eq!("", NewStr::from_latin1(&[]).as_str());
+        assert_eq!("caf\u{E9}", NewStr::from_latin1(b"caf\xE9").as_str());
+        assert_eq!("ASCII only", NewStr::from_latin1(b"ASCII only").as_str());
+    });
+}
+
+/// Small outputs are stored on the stack; large outputs are sized exactly (one allocation, no
+/// re-allocation).
+#[test]
+fn sized_exactly() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let small: Vec<u16> = "Grüße".encode_utf16().collect();
+        let string = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            NewStr::from_utf16(&small).unwrap()
+        });
+        assert_eq!(StorageKind::Stack, string.as_bin().storage_kind());
+        let bin = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || string.encode_utf16_bin());
+        assert_eq!(StorageKind::Stack, bin.storage_kind());
+
+        let large_str = "Grüße, Jürgen ❤ 🗻∈🌏 ".repeat(50);
+        let large: Vec<u16> = large_str.encode_utf16().collect();
+        let large_latin1: Vec<u8> = (0..1000).map(|index| (index % 256) as u8).collect();
+        let one_allocation = MaAnd(&[
+            &MaExactNumberOfAllocations(1),
+            &MaExactNumberOfReAllocations(0),
+        ]);
+        let string = mem_scoped(GLOBAL, &one_allocation, || {
+            NewStr::from_utf16(&large).unwrap()
+        });
+        assert_eq!(StorageKind::Rc, string.as_bin().storage_kind());
+        let string = mem_scoped(GLOBAL, &one_allocation, || {
+            NewSStr::from_utf16_lossy(&large)
+        });
+        assert_eq!(StorageKind::Arc, string.as_bin().storage_kind());
+        let bin = mem_scoped(GLOBAL, &one_allocation, || string.encode_utf16_bin());
+        assert_eq!(large.len() * 2, bin.len());
+        mem_scoped(GLOBAL, &one_allocation, || {
+            NewStr::from_latin1(&large_latin1)
+        });
+    });
+}
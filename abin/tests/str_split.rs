@@ -0,0 +1,214 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, AnyStr, NewSStr, NewStr, StrFactory};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+const INPUTS: &[&str] = &[
+    "",
+    " ",
+    ",",
+    ",,",
+    "a",
+    "a,b,c",
+    ",a,b,",
+    "🗻∈🌏,🗻∈🌏",
+    "key: value\r\nother: value 2\r\n\r\nbody",
+    "first\nsecond\n\nlast\n",
+    "\r\n",
+    "line\r",
+    "  Mary   had\ta little  \n lamb ",
+    "\u{2003}unicode\u{2003}whitespace\u{3000}",
+    "xxyHelloxyxx",
+    "abababab",
+];
+
+const STR_PATTERNS: &[&str] = &[",", ", ", ": ", "\r\n", "\n", "ab", "aba", "∈", "x", ""];
+
+const CHAR_PATTERNS: &[char] = &[',', ' ', '\n', 'x', '∈', '🌏'];
+
+/// Compares the results with the results of the standard library (`str`).
+#[test]
+fn same_as_str() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for input in INPUTS {
+            same_as_str_for::<NewStr>(input);
+            same_as_str_for::<NewSStr>(input);
+        }
+    });
+}
+
+/// Parts are slices of the original string (no allocation, no copy).
+/// The `str` methods (see `Deref`) are not shadowed.
+#[test]
+fn str_methods_still_resolve() {
+    let string = NewStr::from_static(" a,b\nc ");
+    let trimmed: &str = string.trim();
+    assert_eq!("a,b\nc", trimmed);
+    let parts: Vec<&str> = string.split(',').collect();
+    assert_eq!(vec![" a", "b\nc "], parts);
+    let lines: Vec<&str> = string.lines().collect();
+    assert_eq!(vec![" a,b", "c "], lines);
+    assert_eq!(Some("a,b\nc "), string.strip_prefix(' '));
+    assert_eq!(Some((" a", "b\nc ")), string.split_once(','));
+}
+
+#[test]
+fn zero_copy() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        zero_copy_for::<NewStr>();
+        zero_copy_for::<NewSStr>();
+    });
+}
+
+#[test]
+fn patterns() {
+    let string = NewStr::from_static("a1b2c3");
+    assert_same(
+        vec!["a", "b", "c", ""].into_iter(),
+        string.split_str(char::is_numeric),
+    );
+    let mut count = 0;
+    assert_same(
+        vec!["a", "b", "c", ""].into_iter(),
+        string.split_str(|_| {
+            count += 1;
+            count % 2 == 0
+        }),
+    );
+    let pattern = String::from("2");
+    assert_eq!("a1b", string.split_once_str(&pattern).unwrap().0.as_str());
+    assert_same(
+        vec!["a", "b", "c", ""].into_iter(),
+        string.split_str(&['1', '2', '3'][..]),
+    );
+}
+
+fn same_as_str_for<T: StrFactory>(input: &str) {
+    let string = T::copy_from_str(input);
+    for pattern in STR_PATTERNS {
+        assert_same(input.split(pattern), string.split_str(*pattern));
+        for n in 0..4 {
+            assert_same(input.splitn(n, pattern), string.splitn_str(n, *pattern));
+        }
+        assert_same_pair(input.split_once(pattern), string.split_once_str(*pattern));
+        assert_same_pair(input.rsplit_once(pattern), string.rsplit_once_str(*pattern));
+        assert_same_opt(
+            input.strip_prefix(pattern),
+            string.strip_prefix_str(*pattern),
+        );
+        assert_same_opt(
+            input.strip_suffix(pattern),
+            string.strip_suffix_str(*pattern),
+        );
+        // not supported by `str` for string patterns (ambiguous for overlapping matches).
+        assert_eq!(
+            input.trim_start_matches(pattern).trim_end_matches(pattern),
+            string.trim_matches_str(*pattern).as_str()
+        );
+        assert_eq!(
+            input.trim_start_matches(pattern),
+            string.trim_start_matches_str(*pattern).as_str()
+        );
+        assert_eq!(
+            input.trim_end_matches(pattern),
+            string.trim_end_matches_str(*pattern).as_str()
+        );
+    }
+    for pattern in CHAR_PATTERNS {
+        assert_same(input.split(*pattern), string.split_str(*pattern));
+        for n in 0..4 {
+            assert_same(input.splitn(n, *pattern), string.splitn_str(n, *pattern));
+        }
+        assert_same_pair(input.split_once(*pattern), string.split_once_str(*pattern));
+        assert_same_pair(
+            input.rsplit_once(*pattern),
+            string.rsplit_once_str(*pattern),
+        );
+        assert_same_opt(
+            input.strip_prefix(*pattern),
+            string.strip_prefix_str(*pattern),
+        );
+        assert_same_opt(
+            input.strip_suffix(*pattern),
+            string.strip_suffix_str(*pattern),
+        );
+        assert_eq!(
+            input.trim_matches(*pattern),
+            string.trim_matches_str(*pattern).as_str()
+        );
+        let closure = |chr: char| chr == *pattern || chr == 'y';
+        assert_same(input.split(closure), string.split_str(closure));
+        assert_eq!(
+            input.trim_matches(closure),
+            string.trim_matches_str(closure).as_str()
+        );
+    }
+    assert_same(input.lines(), string.lines_str());
+    assert_same(input.split_whitespace(), string.split_whitespace_str());
+    assert_eq!(input.trim(), string.trim_str().as_str());
+    assert_eq!(input.trim_start(), string.trim_start_str().as_str());
+    assert_eq!(input.trim_end(), string.trim_end_str().as_str());
+}
+
+fn zero_copy_for<T: StrFactory>() {
+    let mut content = String::new();
+    for index in 0..100 {
+        content.push_str(&format!(" line number {}, 🗻∈🌏 text \r\n", index));
+    }
+    for string in [
+        T::copy_from_str(content.as_str()),
+        T::from_static("  first line\nsecond line \n third line"),
+    ] {
+        let range = string.as_str().as_bytes().as_ptr_range();
+        let strong_count = string.as_bin().strong_count();
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            for line in string.lines_str() {
+                let line = line.trim_str();
+                assert!(range.contains(&line.as_ptr()));
+                for part in line.split_str(", ") {
+                    assert!(range.contains(&part.as_ptr()));
+                }
+                for word in line.split_whitespace_str() {
+                    assert!(range.contains(&word.as_ptr()));
+                }
+                let (first, _) = line.split_once_str(' ').unwrap();
+                assert!(range.contains(&first.as_ptr()));
+                let stripped = line.strip_prefix_str("line ").unwrap_or(line);
+                assert!(range.contains(&stripped.as_ptr()));
+            }
+        });
+        // all parts have been dropped
+        assert_eq!(strong_count, string.as_bin().strong_count());
+    }
+}
+
+fn assert_same<'a, TBin: AnyBin>(
+    expected: impl Iterator<Item = &'a str>,
+    actual: impl Iterator<Item = AnyStr<TBin>>,
+) {
+    let expected: Vec<&str> = expected.collect();
+    let actual: Vec<AnyStr<TBin>> = actual.collect();
+    let actual: Vec<&str> = actual.iter().map(AnyStr::as_str).collect();
+    assert_eq!(expected, actual);
+}
+
+fn assert_same_pair<TBin: AnyBin>(
+    expected: Option<(&str, &str)>,
+    actual: Option<(AnyStr<TBin>, AnyStr<TBin>)>,
+) {
+    assert_eq!(
+        expected,
+        actual.as_ref().map(|(a, b)| (a.as_str(), b.as_str()))
+    );
+}
+
+fn assert_same_opt<TBin: AnyBin>(expected: Option<&str>, actual: Option<AnyStr<TBin>>) {
+    assert_eq!(expected, actual.as_ref().map(|string| string.as_str()));
+}
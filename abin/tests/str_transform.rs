@@ -0,0 +1,195 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, NewSStr, NewStr, StorageKind, StrFactory};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+const INPUTS: &[&str] = &[
+    "",
+    "a",
+    "A",
+    "content-type",
+    "Content-Type",
+    "CONTENT-TYPE",
+    "123 - 456",
+    "Grüße, Jürgen ❤",
+    "GRÜSSE, JÜRGEN",
+    "straße",
+    "İstanbul",
+    "ὈΔΥΣΣΕΎΣ",
+    "ΟΔΟΣ ΣΑΣ",
+    "Σ",
+    "aΣ",
+    "🗻∈🌏",
+    "ǅ ǆ Ǆ",
+];
+
+/// Compares the results with the results of the standard library (`str`).
+#[test]
+fn same_as_str() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for input in INPUTS {
+            for string in &[NewStr::from_static(input), NewStr::copy_from_str(*input)] {
+                assert_eq!(input.to_lowercase(), string.to_lowercase_str().as_str());
+                assert_eq!(input.to_uppercase(), string.to_uppercase_str().as_str());
+                assert_eq!(
+                    input.to_ascii_lowercase(),
+                    string.to_ascii_lowercase_str().as_str()
+                );
+                assert_eq!(
+                    input.to_ascii_uppercase(),
+                    string.to_ascii_uppercase_str().as_str()
+                );
+            }
+            let string = NewSStr::copy_from_str(*input);
+            assert_eq!(input.to_lowercase(), string.to_lowercase_str().as_str());
+            assert_eq!(input.to_uppercase(), string.to_uppercase_str().as_str());
+            assert_eq!(
+                input.to_ascii_lowercase(),
+                string.to_ascii_lowercase_str().as_str()
+            );
+            assert_eq!(
+                input.to_ascii_uppercase(),
+                string.to_ascii_uppercase_str().as_str()
+            );
+        }
+    });
+}
+
+/// The `str` methods (see `Deref`) are not shadowed.
+#[test]
+fn str_methods_still_resolve() {
+    let string = NewStr::from_static("Hello-World");
+    let lowercase: String = string.to_lowercase();
+    assert_eq!("hello-world", lowercase);
+    let uppercase: String = string.to_ascii_uppercase();
+    assert_eq!("HELLO-WORLD", uppercase);
+    let replaced: String = string.replace('-', " ");
+    assert_eq!("Hello World", replaced);
+    let sstring = NewSStr::from_static("a-b-c");
+    let replaced: String = sstring.replacen("-", "+", 1);
+    assert_eq!("a+b-c", replaced);
+}
+
+#[test]
+fn replace_same_as_str() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let inputs = &["", "a", "aaa", "abcabc", "a-b-c", "🗻∈🌏∈", "x x x x"];
+        let patterns = &[("a", "b"), ("a", "aa"), ("", "-"), ("∈", ""), ("x ", "y")];
+        for input in inputs {
+            for (from, to) in patterns {
+                let string = NewStr::copy_from_str(*input);
+                let sstring = NewSStr::copy_from_str(*input);
+                assert_eq!(
+                    input.replace(from, to),
+                    string.replace_str(from, to).as_str()
+                );
+                assert_eq!(
+                    input.replace(from, to),
+                    sstring.replace_str(from, to).as_str()
+                );
+                for count in 0..4 {
+                    assert_eq!(
+                        input.replacen(from, to, count),
+                        string.replacen_str(from, to, count).as_str()
+                    );
+                    assert_eq!(
+                        input.replacen(from, to, count),
+                        sstring.replacen_str(from, to, count).as_str()
+                    );
+                }
+            }
+        }
+    });
+}
+
+/// If nothing changes, the result is the same string (same backend, no allocation).
+#[test]
+fn unchanged() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let large = "some lowercase text that's too large for the stack ".repeat(10);
+        let static_str = NewStr::from_static("content-type");
+        let stack_str = NewStr::copy_from_str("ab");
+        let rc_str = NewStr::copy_from_str(large.as_str());
+        let arc_str = NewSStr::copy_from_str(large.as_str());
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            for string in &[&static_str, &stack_str, &rc_str] {
+                for result in &[
+                    string.to_lowercase_str(),
+                    string.to_ascii_lowercase_str(),
+                    string.replace_str("X", "y"),
+                    string.replace_str("a", "a"),
+                    string.replacen_str("a", "b", 0),
+                ] {
+                    assert_eq!(string.as_str(), result.as_str());
+                    if string.as_bin().storage_kind() != StorageKind::Stack {
+                        // shares the buffer
+                        assert_eq!(string.as_str().as_ptr(), result.as_str().as_ptr());
+                    }
+                    assert_eq!(
+                        string.as_bin().storage_kind(),
+                        result.as_bin().storage_kind()
+                    );
+                }
+            }
+            let result = arc_str.to_lowercase_str();
+            assert_eq!(arc_str.as_str().as_ptr(), result.as_str().as_ptr());
+            assert_eq!(Some(2), arc_str.as_bin().strong_count());
+        });
+        assert_eq!(
+            StorageKind::Static,
+            static_str.to_ascii_lowercase_str().as_bin().storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Stack,
+            stack_str.to_uppercase_str().as_bin().storage_kind()
+        );
+        let upper = NewStr::from_static("ABC");
+        assert_eq!(
+            StorageKind::Static,
+            upper.to_uppercase_str().as_bin().storage_kind()
+        );
+        assert_eq!(
+            StorageKind::Static,
+            upper.to_ascii_uppercase_str().as_bin().storage_kind()
+        );
+    });
+}
+
+/// The result is built using the builder: small results are stored on the stack; large
+/// results need exactly one allocation.
+#[test]
+fn changed() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let small = NewStr::from_static("Content-Type");
+        let lowercase = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || small.to_lowercase_str());
+        assert_eq!("content-type", lowercase.as_str());
+        assert_eq!(StorageKind::Stack, lowercase.as_bin().storage_kind());
+
+        let large = NewStr::copy_from_str(
+            "Some text that's too large for the stack; and one more Word."
+                .repeat(10)
+                .as_str(),
+        );
+        let replaced = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(1),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || large.replacen_str("Word", "word", 2),
+        );
+        assert_eq!(
+            large.as_str().replacen("Word", "word", 2),
+            replaced.as_str()
+        );
+        // the original string is still intact
+        assert!(large.as_str().starts_with("Some text"));
+    });
+}
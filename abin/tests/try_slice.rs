@@ -0,0 +1,287 @@
+// inverted ranges are tested on purpose
+#![allow(clippy::reversed_empty_ranges)]
+
+use std::alloc::System;
+use std::ops::Bound;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{
+    AnyBin, Bin, BinFactory, IntoUnSyncView, NewBin, NewSBin, NewSStr, NewStr, SliceError, Str,
+    StrFactory,
+};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+#[test]
+fn try_slice_all_backends() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for bin in all_backends() {
+            exhaustive(&bin);
+            // the synchronized version behaves the same
+            let content = bin.as_slice().to_vec();
+            exhaustive(&NewSBin::copy_from_slice(&content).un_sync());
+        }
+        exhaustive(&NewBin::empty());
+        exhaustive(&NewSBin::empty().un_sync());
+    });
+}
+
+#[test]
+fn inclusive_ranges() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for bin in all_backends() {
+            let len = bin.len();
+            if len >= 3 {
+                assert_eq!(&bin.as_slice()[0..=2], bin.slice(0..=2).unwrap().as_slice());
+            }
+            assert_eq!(Some(bin.clone()), bin.slice(0..=len - 1));
+            assert!(bin.slice(0..=len).is_none());
+            // used to underflow
+            assert_eq!(&bin.as_slice()[..=0], bin.slice(..=0).unwrap().as_slice());
+        }
+        assert_eq!(
+            Err(SliceError::OutOfBounds {
+                len: 0,
+                start: 0,
+                end: 1
+            }),
+            NewBin::empty().try_slice(..=0)
+        );
+    });
+}
+
+#[test]
+fn errors() {
+    let bin = NewBin::from_static(&[1, 2, 3, 4, 5]);
+    assert_eq!(
+        Err(SliceError::OutOfBounds {
+            len: 5,
+            start: 3,
+            end: 6
+        }),
+        bin.try_slice(3..6)
+    );
+    assert_eq!(
+        Err(SliceError::OutOfBounds {
+            len: 5,
+            start: 6,
+            end: 5
+        }),
+        bin.try_slice(6..)
+    );
+    assert_eq!(Err(SliceError::InvertedRange), bin.try_slice(4..2));
+    // out of bounds is reported first
+    assert_eq!(
+        Err(SliceError::OutOfBounds {
+            len: 5,
+            start: 10,
+            end: 8
+        }),
+        bin.try_slice(10..8)
+    );
+    // overflow
+    assert_eq!(
+        Err(SliceError::OutOfBounds {
+            len: 5,
+            start: 0,
+            end: usize::MAX
+        }),
+        bin.try_slice(..=usize::MAX)
+    );
+    assert_eq!(
+        Err(SliceError::OutOfBounds {
+            len: 5,
+            start: usize::MAX,
+            end: 5
+        }),
+        bin.try_slice((Bound::Excluded(usize::MAX), Bound::Unbounded))
+    );
+    // empty slices at the end are ok
+    assert!(bin.try_slice(5..5).unwrap().is_empty());
+    assert!(bin.try_slice(5..).unwrap().is_empty());
+
+    // synchronized binary
+    let sbin = NewSBin::from_static(&[1, 2, 3]);
+    assert_eq!(Err(SliceError::InvertedRange), sbin.try_slice(2..1));
+    assert_eq!(&[2, 3], sbin.try_slice(1..=2).unwrap().as_slice());
+
+    // display
+    assert_eq!(
+        "Range 3..6 is out of bounds (length is 5).",
+        SliceError::OutOfBounds {
+            len: 5,
+            start: 3,
+            end: 6
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn try_slice_strings() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = "🗻∈🌏 some text that's too long for the stack";
+        exhaustive_str(&NewStr::from_static(content));
+        exhaustive_str(&NewStr::copy_from_str(content));
+        exhaustive_str(&NewSStr::copy_from_str(content).un_sync());
+        exhaustive_str(&NewStr::from_rc(Rc::from(content)));
+        exhaustive_str(&NewStr::from_boxed(Box::from(content)));
+        exhaustive_str(&NewStr::copy_from_str("∈"));
+        exhaustive_str(&NewStr::empty());
+
+        let string = NewSStr::from_static("🗻∈🌏");
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 5 }),
+            string.try_slice(4..5)
+        );
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 2 }),
+            string.try_slice(..2)
+        );
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 1 }),
+            string.try_slice(..=0)
+        );
+        assert_eq!("🗻", string.try_slice(..=3).unwrap().as_str());
+        assert_eq!(Err(SliceError::InvertedRange), string.try_slice(7..4));
+    });
+}
+
+fn all_backends() -> Vec<Bin> {
+    let large = BinGen::new(0, 200).generate_to_vec();
+    let backends = vec![
+        NewBin::from_static(&[1, 2, 3, 4, 5, 6, 7, 8, 9]),
+        NewBin::copy_from_slice(&[1, 2, 3]),
+        NewBin::copy_from_slice(&large),
+        NewSBin::copy_from_slice(&large).un_sync(),
+        NewBin::from_given_vec(large.clone()),
+        NewBin::from_boxed(large.clone().into_boxed_slice()),
+        NewBin::from_rc(Rc::from(large.as_slice())),
+        NewSBin::from_arc(Arc::from(large.as_slice())).un_sync(),
+        // slice of a slice
+        NewBin::from_rc(Rc::from(large.as_slice()))
+            .slice(10..190)
+            .unwrap(),
+    ];
+    #[cfg(feature = "bytes")]
+    let backends = {
+        let mut backends = backends;
+        backends.push(Bin::from(bytes::Bytes::from(large)));
+        backends
+    };
+    backends
+}
+
+/// Indices to test: around the start and around the end (and the maximum).
+fn indices(len: usize) -> Vec<usize> {
+    let mut indices = vec![0, 1, 2, len / 2, usize::MAX - 1, usize::MAX];
+    indices.extend(len.saturating_sub(2)..len + 3);
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+fn bounds(len: usize) -> Vec<Bound<usize>> {
+    let mut bounds = vec![Bound::Unbounded];
+    for index in indices(len) {
+        bounds.push(Bound::Included(index));
+        bounds.push(Bound::Excluded(index));
+    }
+    bounds
+}
+
+/// The expected outcome (reference implementation).
+fn expected(
+    start: Bound<usize>,
+    end: Bound<usize>,
+    len: usize,
+) -> Result<(usize, usize), SliceError> {
+    let start = match start {
+        Bound::Included(start) => Some(start),
+        Bound::Excluded(start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match end {
+        Bound::Included(end) => end.checked_add(1),
+        Bound::Excluded(end) => Some(end),
+        Bound::Unbounded => Some(len),
+    };
+    match (start, end) {
+        (Some(start), Some(end)) if start <= len && end <= len && start > end => {
+            Err(SliceError::InvertedRange)
+        }
+        (Some(start), Some(end)) if start <= len && end <= len => Ok((start, end)),
+        (start, end) => Err(SliceError::OutOfBounds {
+            len,
+            start: start.unwrap_or(usize::MAX),
+            end: end.unwrap_or(usize::MAX),
+        }),
+    }
+}
+
+fn exhaustive(bin: &Bin) {
+    let content = bin.as_slice();
+    let len = content.len();
+    for start in bounds(len) {
+        for end in bounds(len) {
+            let result = bin.try_slice((start, end));
+            // must be consistent with the standard library
+            assert_eq!(
+                content.get((start, end)),
+                result.as_ref().ok().map(|slice| slice.as_slice())
+            );
+            assert_eq!(
+                content.get((start, end)),
+                bin.slice((start, end))
+                    .as_ref()
+                    .map(|slice| slice.as_slice())
+            );
+            match expected(start, end, len) {
+                Ok((start, end)) => {
+                    assert_eq!(&content[start..end], result.unwrap().as_slice());
+                }
+                Err(err) => assert_eq!(Err(err), result),
+            }
+        }
+    }
+}
+
+fn exhaustive_str(string: &Str) {
+    let content = string.as_str();
+    let len = content.len();
+    for start in bounds(len) {
+        for end in bounds(len) {
+            let result = string.try_slice((start, end));
+            assert_eq!(
+                content.get((start, end)),
+                result.as_ref().ok().map(|slice| slice.as_str())
+            );
+            assert_eq!(
+                content.get((start, end)),
+                string
+                    .slice((start, end))
+                    .as_ref()
+                    .map(|slice| slice.as_str())
+            );
+            match expected(start, end, len) {
+                Ok((start, end)) => {
+                    if !content.is_char_boundary(start) {
+                        assert_eq!(Err(SliceError::NotCharBoundary { index: start }), result);
+                    } else if !content.is_char_boundary(end) {
+                        assert_eq!(Err(SliceError::NotCharBoundary { index: end }), result);
+                    } else {
+                        assert_eq!(&content[start..end], result.unwrap().as_str());
+                    }
+                }
+                Err(err) => assert_eq!(Err(err), result),
+            }
+        }
+    }
+}
@@ -22,8 +22,8 @@ fn usage_basics() {
     assert_eq!(5, hello_bin.len());
     assert_eq!(5, hello_str.len());
     // is_empty
-    assert_eq!(false, hello_bin.is_empty());
-    assert_eq!(false, hello_str.is_empty());
+    assert!(!hello_bin.is_empty());
+    assert!(!hello_str.is_empty());
     // as_slice / as_str / deref / as_bin
     assert_eq!(&[72u8, 101u8, 108u8, 108u8, 111u8], hello_bin.as_slice());
     assert_eq!("Hello", hello_str.as_str());
@@ -4,8 +4,7 @@ use abin::{
     AnyBin, Bin, BinFactory, BinSegment, NewBin, NewStr, SegmentsSlice, Str, StrFactory, StrSegment,
 };
 
-const MY_STATIC_STR: &'static str =
-    "This is some static string; this is the content of the binary.";
+const MY_STATIC_STR: &str = "This is some static string; this is the content of the binary.";
 
 #[test]
 fn create_from_static() {
@@ -46,14 +45,13 @@ fn create_from_iterator() {
 
     let iter_bin_1 = NewBin::from_iter(non_static_src.as_bytes().iter().cloned());
     assert_eq!(non_static_src.as_bytes(), iter_bin_1.deref());
-    let iter_bin_2 = NewBin::from_iter((0..50).into_iter().map(|i| i as u8));
+    let iter_bin_2 = NewBin::from_iter((0..50).map(|i| i as u8));
     assert_eq!(50, iter_bin_2.len());
 
     let iter_str_1 =
         NewStr::from_utf8_iter(non_static_src.as_bytes().iter().cloned()).expect("invalid utf-8");
     assert_eq!(non_static_src, iter_str_1.deref());
-    let iter_str_2 =
-        NewStr::from_utf8_iter((97..103).into_iter().map(|i| i as u8)).expect("invalid utf-8");
+    let iter_str_2 = NewStr::from_utf8_iter((97..103).map(|i| i as u8)).expect("invalid utf-8");
     assert_eq!("abcdef", iter_str_2.deref());
 }
 
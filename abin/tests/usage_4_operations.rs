@@ -1,6 +1,8 @@
 use abin::{AnyBin, BinFactory, NewBin, NewStr, Str, StrFactory};
 
 #[test]
+// reversed ranges are intentional (`slice` returns `None`).
+#[allow(clippy::reversed_empty_ranges)]
 fn slice() {
     let str1 = NewStr::from_static("Some text.");
     assert_eq!(
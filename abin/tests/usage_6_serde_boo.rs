@@ -8,7 +8,7 @@ use abin::BooStr;
 
 /// Serialize/de-serialize using serde with Boo (like Cow).
 #[test]
-fn serialize_deserialize_using_serde<'a>() {
+fn serialize_deserialize_using_serde() {
     let entity = create_entity();
     let as_vec = serde_cbor::to_vec(&entity).unwrap();
     step_1_server_got_data_from_network(as_vec);
@@ -33,7 +33,7 @@ fn step_3_store_in_database(person: Person) {
     assert!(first_name == "Elbort" || first_name == "Lutz" || first_name == "Franz");
 }
 
-fn de_serialize_from_slice(slice: &[u8]) -> ServerCmdAddPeopleToAddressBook {
+fn de_serialize_from_slice(slice: &[u8]) -> ServerCmdAddPeopleToAddressBook<'_> {
     serde_cbor::from_slice(slice).unwrap()
 }
 
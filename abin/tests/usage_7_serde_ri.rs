@@ -23,7 +23,7 @@ pub mod utils;
 #[test]
 fn zero_allocation_deserialization() {
     // no memory-leak is allowed of course
-    mem_scoped(&GLOBAL, &MaNoLeak, || {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
         // serialization (this allocates) -> this happens on the client-side
         let sbin = {
             let request = create_server_request();
@@ -33,7 +33,7 @@ fn zero_allocation_deserialization() {
         };
 
         // this is the de-serialization (it does not allocate).
-        mem_scoped(&GLOBAL, &MaNoAllocNoReAlloc, || {
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
             server_process_message(sbin);
         });
     });
@@ -30,14 +30,14 @@ fn non_static_re_integration<T: BinFactory>() {
     let bin_a = T::copy_from_slice(some_demo_slice);
 
     // this MUST NOT allocate
-    mem_scoped(&GLOBAL, &MaNoAllocNoDealloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoDealloc, || {
         rc_re_integration_stage_2::<T>(&bin_a, bin_a.as_slice(), some_demo_slice);
         rc_re_integration_stage_2::<T>(&bin_a, &bin_a.as_slice()[1..], &some_demo_slice[1..]);
         rc_re_integration_stage_2::<T>(&bin_a, &bin_a.as_slice()[5..20], &some_demo_slice[5..20]);
     });
 
     let something_unrelated = "unrelated binary".as_bytes();
-    assert_eq!(None, bin_a.try_to_re_integrate(&something_unrelated));
+    assert_eq!(None, bin_a.try_to_re_integrate(something_unrelated));
 }
 
 fn rc_re_integration_stage_2<T: BinFactory>(bin: &T::T, sub_item: &[u8], expected: &[u8]) {
@@ -50,7 +50,7 @@ fn static_re_integration() {
     let static_bin = NewBin::from_static(binary);
 
     // must not allocate
-    mem_scoped(&GLOBAL, &MaNoAllocNoDealloc, || {
+    mem_scoped(GLOBAL, &MaNoAllocNoDealloc, || {
         let sub_item_1 = binary;
         static_re_integration_stage_2(&static_bin, sub_item_1, binary);
         let sub_item_2 = &binary[1..];
@@ -0,0 +1,141 @@
+use std::alloc::System;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, BinFactory, NewBin, NewSBin, NewSStr, NewStr, Utf8StrBuilder};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+const INPUTS: &[&[u8]] = &[
+    b"",
+    b"Hello",
+    "Grüße, Jürgen ❤ 🗻∈🌏".as_bytes(),
+    b"\xFF",
+    b"Hello \xF0\x90\x80World",
+    b"\xC3",
+    b"abc\xE2\x82",
+    b"\xED\xA0\x80 surrogate",
+    b"\xC0\xAF overlong",
+    b"a\x80b\xBFc",
+    b"\xF4\x90\x80\x80 too large",
+];
+
+/// Compares the results with the results of the standard library (`String::from_utf8_lossy`).
+#[test]
+fn lossy_same_as_std() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        for input in INPUTS {
+            let expected = String::from_utf8_lossy(input);
+            let large = input.repeat(20);
+            let expected_large = String::from_utf8_lossy(&large);
+            assert_eq!(
+                expected,
+                NewStr::from_utf8_lossy(NewBin::copy_from_slice(input)).as_str()
+            );
+            assert_eq!(
+                expected,
+                NewSStr::from_utf8_lossy(NewSBin::copy_from_slice(input)).as_str()
+            );
+            assert_eq!(
+                expected_large,
+                NewStr::from_utf8_lossy(NewBin::copy_from_slice(&large)).as_str()
+            );
+            assert_eq!(
+                expected_large,
+                NewSStr::from_utf8_lossy(NewSBin::copy_from_slice(&large)).as_str()
+            );
+        }
+    });
+}
+
+/// Valid UTF-8 is converted without copying.
+#[test]
+fn lossy_zero_copy() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let large = "Some valid UTF-8 text: 🗻∈🌏. ".repeat(10);
+        let bin = NewBin::copy_from_slice(large.as_bytes());
+        let ptr = bin.as_slice().as_ptr();
+        let string = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || NewStr::from_utf8_lossy(bin));
+        assert_eq!(large, string.as_str());
+        assert_eq!(ptr, string.as_str().as_ptr());
+    });
+}
+
+/// Decodes the input split into two or three chunks (at every possible position).
+#[test]
+fn chunked_every_split_point() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let input = "Grüße, Jürgen ❤ 🗻∈🌏".as_bytes();
+        for first in 0..=input.len() {
+            for second in first..=input.len() {
+                let chunks = [&input[..first], &input[first..second], &input[second..]];
+
+                let mut builder = Utf8StrBuilder::new(NewStr::builder());
+                for chunk in &chunks {
+                    builder.push_slice(chunk).unwrap();
+                }
+                assert_eq!(input.len(), builder.len());
+                assert_eq!(input, builder.build().unwrap().as_str().as_bytes());
+
+                let mut builder = Utf8StrBuilder::new(NewSStr::builder());
+                for chunk in &chunks {
+                    builder.push_bin(NewSBin::copy_from_slice(chunk)).unwrap();
+                }
+                assert_eq!(input, builder.build().unwrap().as_str().as_bytes());
+            }
+        }
+    });
+}
+
+/// Chunks are pushed as slices of the binary (no copy) if possible.
+#[test]
+fn chunked_zero_copy() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let large = "Some large text that does not fit on the stack 🗻∈🌏. ".repeat(10);
+        let bin = NewBin::copy_from_slice(large.as_bytes());
+        let ptr = bin.as_slice().as_ptr();
+        let string = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let mut builder = Utf8StrBuilder::new(NewStr::builder());
+            builder.push_bin(bin).unwrap();
+            builder.build().unwrap()
+        });
+        assert_eq!(large, string.as_str());
+        assert_eq!(ptr, string.as_str().as_ptr());
+    });
+}
+
+/// Offsets are relative to the start of the string; the builder is still usable after an error.
+#[test]
+fn chunked_errors() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let mut builder = Utf8StrBuilder::new(NewStr::builder());
+        builder.push_slice(b"abc").unwrap();
+        let err = builder.push_slice(b"de\xFFf").unwrap_err();
+        assert_eq!(5, err.valid_up_to());
+        assert_eq!(Some(1), err.error_len());
+        assert_eq!(3, builder.len());
+
+        // "€" = E2 82 AC
+        builder.push_slice(b"\xE2").unwrap();
+        let err = builder.push_slice(b"\x41").unwrap_err();
+        assert_eq!(3, err.valid_up_to());
+        assert_eq!(Some(1), err.error_len());
+
+        builder.push_slice(b"\x82").unwrap();
+        let err = builder.build().unwrap_err();
+        assert_eq!(3, err.valid_up_to());
+        assert_eq!(None, err.error_len());
+
+        builder.push_slice(b"\xAC!").unwrap();
+        assert_eq!("abc€!", builder.build().unwrap().as_str());
+
+        // can be re-used
+        assert!(builder.is_empty());
+        builder.push_slice(b"next").unwrap();
+        assert_eq!("next", builder.build().unwrap().as_str());
+    });
+}
@@ -25,14 +25,10 @@ impl BinGen {
     pub fn generate_to_vec_shrink(&self, additional_capacity: usize) -> Vec<u8> {
         let mut vec: Vec<u8> = self.generate_to_vec();
         let len = vec.len();
-        vec.reserve(additional_capacity);
-        unsafe {
-            vec.set_len(len + additional_capacity);
-        }
+        vec.resize(len + additional_capacity, 0);
         vec.shrink_to_fit();
-        unsafe {
-            vec.set_len(len);
-        }
+        // truncate keeps the capacity.
+        vec.truncate(len);
         vec
     }
 }
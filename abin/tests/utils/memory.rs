@@ -151,6 +151,23 @@ impl MemAssert for MaExactNumberOfAllocations {
     }
 }
 
+/// Must not have more than the number of given allocations.
+pub struct MaMaxNumberOfAllocations(pub usize);
+
+impl MemAssert for MaMaxNumberOfAllocations {
+    fn assert(&self, change: Stats) -> Result<(), String> {
+        let num_allocations = change.allocations;
+        if num_allocations > self.0 {
+            Err(format!(
+                "Expected to have at most {} allocations (got {} allocations instead)",
+                self.0, num_allocations
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// Must have exactly the number of given de-allocations.
 pub struct MaExactNumberOfDeAllocations(pub usize);
 
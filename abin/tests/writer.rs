@@ -0,0 +1,173 @@
+use std::alloc::System;
+use std::fmt::Write as FmtWrite;
+use std::io::Write as IoWrite;
+
+use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
+
+use abin::{AnyBin, BinWriter, NewBin, NewSBin, NewSStr, NewStr, StrWriter};
+use utils::*;
+
+#[global_allocator]
+static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
+
+pub mod utils;
+
+/// Conservative length (to make sure this fits on the stack on every platform).
+const STACK_LEN: usize = 3;
+
+#[test]
+fn bin_writer_small_output_does_not_allocate() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let (bin, sbin) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let mut writer = BinWriter::new(NewBin::builder());
+            writer.write_all(&[1]).unwrap();
+            writer.write_all(&[2, 3]).unwrap();
+            assert_eq!(STACK_LEN, writer.len());
+            let bin = writer.build();
+
+            let mut writer = BinWriter::new(NewSBin::builder());
+            writer.write_all(&[1, 2, 3]).unwrap();
+            (bin, writer.build())
+        });
+        assert_eq!(&[1, 2, 3], bin.as_slice());
+        assert_eq!(&[1, 2, 3], sbin.as_slice());
+    });
+}
+
+#[test]
+fn bin_writer_large_output() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = BinGen::new(0, 1000).generate_to_vec();
+        // one single large write: the builder adopts the vector (single allocation).
+        let bin = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(1),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || {
+                let mut writer = BinWriter::new(NewBin::builder());
+                writer.write_all(&content).unwrap();
+                writer.build()
+            },
+        );
+        assert_eq!(content.as_slice(), bin.as_slice());
+        // reference-counted: clone does not allocate
+        mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            assert_eq!(bin, bin.clone());
+        });
+
+        // starts small (on the stack), then gets large: the builder knows the entire length
+        // when building (no re-allocation).
+        let bin = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(2),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || {
+                let mut writer = BinWriter::new(NewBin::builder());
+                writer.write_all(&content[0..STACK_LEN]).unwrap();
+                writer.write_all(&content[STACK_LEN..]).unwrap();
+                writer.build()
+            },
+        );
+        assert_eq!(content.as_slice(), bin.as_slice());
+    });
+}
+
+#[test]
+fn bin_writer_many_writes() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let mut expected = Vec::new();
+        let mut writer = BinWriter::new(NewSBin::builder());
+        for index in 0..500u32 {
+            writer.write_all(&index.to_be_bytes()).unwrap();
+            write!(writer, "[{}]", index).unwrap();
+            expected.extend_from_slice(&index.to_be_bytes());
+            expected.extend_from_slice(format!("[{}]", index).as_bytes());
+            assert_eq!(expected.len(), writer.len());
+        }
+        writer.flush().unwrap();
+        let bin = writer.build();
+        assert_eq!(expected.as_slice(), bin.as_slice());
+
+        // the writer is empty again and can be re-used.
+        assert!(writer.is_empty());
+        writer.write_all(&[1, 2]).unwrap();
+        assert_eq!(&[1, 2], writer.build().as_slice());
+        assert!(writer.build().is_empty());
+    });
+}
+
+#[test]
+fn str_writer_small_output_does_not_allocate() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let (string, sstring) = mem_scoped(GLOBAL, &MaNoAllocNoReAlloc, || {
+            let mut writer = StrWriter::new(NewStr::builder());
+            writer.write_str("a").unwrap();
+            writer.write_char('b').unwrap();
+            write!(writer, "{}", 1 + 1).unwrap();
+            let string = writer.build();
+
+            let mut writer = StrWriter::new(NewSStr::builder());
+            writer.write_str("abc").unwrap();
+            (string, writer.build())
+        });
+        assert_eq!("ab2", string.as_str());
+        assert_eq!("abc", sstring.as_str());
+    });
+}
+
+#[test]
+fn str_writer_large_output() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let content = "∈🌏 some text that's too large for the stack; ".repeat(20);
+        // one single large write: the builder adopts the string (single allocation).
+        let string = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(1),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || {
+                let mut writer = StrWriter::new(NewStr::builder());
+                writer.write_str(&content).unwrap();
+                writer.build()
+            },
+        );
+        assert_eq!(content.as_str(), string.as_str());
+
+        let string = mem_scoped(
+            GLOBAL,
+            &MaAnd(&[
+                &MaExactNumberOfAllocations(2),
+                &MaExactNumberOfReAllocations(0),
+            ]),
+            || {
+                let mut writer = StrWriter::new(NewStr::builder());
+                writer.write_str(&content[0..STACK_LEN]).unwrap();
+                writer.write_str(&content[STACK_LEN..]).unwrap();
+                writer.build()
+            },
+        );
+        assert_eq!(content.as_str(), string.as_str());
+    });
+}
+
+#[test]
+fn str_writer_many_writes() {
+    mem_scoped(GLOBAL, &MaNoLeak, || {
+        let mut expected = String::new();
+        let mut writer = StrWriter::new(NewSStr::builder());
+        for index in 0..500u32 {
+            write!(writer, "{}: 🗻∈🌏, ", index).unwrap();
+            write!(expected, "{}: 🗻∈🌏, ", index).unwrap();
+            assert_eq!(expected.len(), writer.len());
+        }
+        let string = writer.build();
+        assert_eq!(expected.as_str(), string.as_str());
+        assert!(writer.is_empty());
+        assert!(writer.build().is_empty());
+    });
+}
@@ -11,22 +11,22 @@ fn perform<TBenchStr: BenchStr>() {
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("Implementation: abin (SStr)", |b| {
-        b.iter(|| perform::<SStrBenchStr>())
+        b.iter(perform::<SStrBenchStr>)
     });
     c.bench_function("Implementation: abin (Str)", |b| {
-        b.iter(|| perform::<StrBenchStr>())
+        b.iter(perform::<StrBenchStr>)
     });
     c.bench_function("Implementation: StdLibOptimized", |b| {
-        b.iter(|| perform::<StdLibOptimized>())
+        b.iter(perform::<StdLibOptimized>)
     });
     c.bench_function("Implementation: BytesBenchStr", |b| {
-        b.iter(|| perform::<BytesBenchStr>())
+        b.iter(perform::<BytesBenchStr>)
     });
     c.bench_function("Implementation: StdLibStringOnly", |b| {
-        b.iter(|| perform::<StdLibStringOnly>())
+        b.iter(perform::<StdLibStringOnly>)
     });
     c.bench_function("Implementation: StdLibArcStrOnly", |b| {
-        b.iter(|| perform::<StdLibArcStrOnly>())
+        b.iter(perform::<StdLibArcStrOnly>)
     });
 }
 
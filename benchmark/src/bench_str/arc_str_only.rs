@@ -43,11 +43,7 @@ impl BenchStr for StdLibArcStrOnly {
     where
         Self: Sized,
     {
-        if let Some(slice) = self.0.get(start..end) {
-            Some(Self(slice.into()))
-        } else {
-            None
-        }
+        self.0.get(start..end).map(|slice| Self(slice.into()))
     }
 
     fn into_string(self) -> String {
@@ -41,11 +41,7 @@ impl BenchStr for StdLibStringOnly {
     where
         Self: Sized,
     {
-        if let Some(slice) = self.0.get(start..end) {
-            Some(Self(slice.to_owned()))
-        } else {
-            None
-        }
+        self.0.get(start..end).map(|slice| Self(slice.to_owned()))
     }
 
     fn into_string(self) -> String {